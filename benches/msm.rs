@@ -0,0 +1,57 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use modular_math::curves::{Curve, ECPoint, BN128};
+use primitive_types::U256;
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+fn inputs(curve: &Curve, n: usize) -> (Vec<U256>, Vec<ECPoint>) {
+    let mut rng = StdRng::seed_from_u64(n as u64);
+    let mut random_scalar = || {
+        let mut bytes = [0u8; 32];
+        rng.fill_bytes(&mut bytes);
+        U256::from_big_endian(&bytes) % curve.curve_order
+    };
+    let scalars = (0..n).map(|_| random_scalar()).collect();
+
+    // consecutive multiples of a random point are much cheaper to build than n scalar multiplications
+    let step = curve.scalar_multiply_generator(random_scalar());
+    let mut points = Vec::with_capacity(n);
+    let mut point = step;
+    for _ in 0..n {
+        points.push(point);
+        point = curve.add_points(&point, &step);
+    }
+    (scalars, points)
+}
+
+fn bench_msm(c: &mut Criterion) {
+    let bn128 = BN128();
+    let mut group = c.benchmark_group("bn128 msm");
+    group.sample_size(10);
+    for n in [16, 256, 4096, 65536] {
+        let (scalars, points) = inputs(&bn128, n);
+        group.bench_with_input(BenchmarkId::from_parameter(n), &n, |b, _| {
+            b.iter(|| bn128.msm(black_box(&scalars), black_box(&points)))
+        });
+    }
+    group.finish();
+}
+
+// Sweeps the window around the `msm_window_size` choice, to keep the table honest
+fn bench_msm_window(c: &mut Criterion) {
+    let bn128 = BN128();
+    for n in [256, 4096] {
+        let (scalars, points) = inputs(&bn128, n);
+        let mut group = c.benchmark_group(format!("bn128 msm window, n = {}", n));
+        group.sample_size(10);
+        let chosen = Curve::msm_window_size(n);
+        for window in chosen - 2..=chosen + 2 {
+            group.bench_with_input(BenchmarkId::from_parameter(window), &window, |b, &window| {
+                b.iter(|| bn128.msm_with_window(black_box(&scalars), black_box(&points), window))
+            });
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_msm, bench_msm_window);
+criterion_main!(benches);
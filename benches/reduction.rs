@@ -0,0 +1,126 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use modular_math::curves::{Secp256k1, BN128};
+use modular_math::mod_math::{ModMath, ModMathCache, RootConvention};
+use primitive_types::{U256, U512};
+
+fn bench_reduce_wide(c: &mut Criterion) {
+    let secp256k1 = Secp256k1();
+    let math = ModMath::new(secp256k1.field_modulus);
+    let modulus = U512::from(secp256k1.field_modulus);
+    let x = U512::from(secp256k1.G.x) * U512::from(secp256k1.G.y);
+
+    c.bench_function("secp256k1 p special-form reduction", |b| b.iter(|| math.reduce_wide(black_box(x))));
+    c.bench_function("secp256k1 p generic reduction", |b| b.iter(|| black_box(x) % modulus));
+
+    let bn128 = BN128();
+    let math = ModMath::new(bn128.field_modulus);
+    let modulus = U512::from(bn128.field_modulus);
+    let x = U512::from(bn128.G.x + 1) * U512::from(bn128.field_modulus - 1);
+
+    c.bench_function("bn128 p Barrett reduction", |b| b.iter(|| math.reduce_wide(black_box(x))));
+    c.bench_function("bn128 p generic reduction", |b| b.iter(|| black_box(x) % modulus));
+}
+
+fn bench_mul(c: &mut Criterion) {
+    let secp256k1 = Secp256k1();
+    let bn128 = BN128();
+    let secp256k1_math = ModMath::new(secp256k1.field_modulus);
+    let bn128_math = ModMath::new(bn128.field_modulus);
+    let small_math = ModMath::new(U256::from(18446744073709551557_u64));
+    let (x, y) = (secp256k1.G.x, secp256k1.G.y);
+
+    c.bench_function("secp256k1 p mul", |b| b.iter(|| secp256k1_math.mul(black_box(x), black_box(y))));
+    c.bench_function("bn128 p mul", |b| b.iter(|| bn128_math.mul(black_box(x), black_box(y))));
+    c.bench_function("64-bit modulus mul", |b| {
+        b.iter(|| small_math.mul(black_box(U256::from(0xdead_beef_u64)), black_box(U256::from(0xfeed_face_u64))))
+    });
+    c.bench_function("secp256k1 p square", |b| b.iter(|| secp256k1_math.square(black_box(x))));
+    c.bench_function("bn128 p square", |b| b.iter(|| bn128_math.square(black_box(x))));
+    c.bench_function("64-bit modulus square", |b| b.iter(|| small_math.square(black_box(U256::from(0xdead_beef_u64)))));
+}
+
+fn bench_exp(c: &mut Criterion) {
+    let secp256k1 = Secp256k1();
+    let math = ModMath::new(secp256k1.field_modulus);
+    let exponent = secp256k1.field_modulus - U256::from(2);
+
+    c.bench_function("secp256k1 p exp", |b| b.iter(|| math.exp(black_box(secp256k1.G.x), black_box(exponent))));
+}
+
+fn bench_exp_many(c: &mut Criterion) {
+    let secp256k1 = Secp256k1();
+    let math = ModMath::new(secp256k1.field_modulus);
+    let exponent = secp256k1.field_modulus - U256::from(2);
+    let bases: Vec<U256> = (1..=100u64).map(|i| math.mul(secp256k1.G.x, U256::from(i))).collect();
+
+    c.bench_function("secp256k1 p exp of 100 bases", |b| {
+        b.iter(|| bases.iter().map(|&base| math.exp(base, black_box(exponent))).collect::<Vec<_>>())
+    });
+    c.bench_function("secp256k1 p exp_many of 100 bases", |b| b.iter(|| math.exp_many(&bases, black_box(exponent))));
+}
+
+fn bench_scalar_multiplication(c: &mut Criterion) {
+    let scalar = U256::from_dec_str("98765432109876543210987654321098765432109876543210").unwrap();
+    let secp256k1 = Secp256k1();
+    let bn128 = BN128();
+
+    c.bench_function("secp256k1 scalar multiplication (special-form)", |b| {
+        b.iter(|| secp256k1.scalar_multiply_generator(black_box(scalar)))
+    });
+    c.bench_function("bn128 scalar multiplication (Barrett)", |b| {
+        b.iter(|| bn128.scalar_multiply_generator(black_box(scalar)))
+    });
+}
+
+fn bench_decompression(c: &mut Criterion) {
+    let secp256k1 = Secp256k1();
+    let mut point = secp256k1.G;
+    let mut compressed = Vec::new();
+    for _ in 0..100 {
+        let convention = if point.y.bit(0) { RootConvention::OddY } else { RootConvention::EvenY };
+        compressed.push((point.x, convention));
+        point = secp256k1.add_points(&point, &secp256k1.G);
+    }
+
+    c.bench_function("secp256k1 decompress 100 points", |b| {
+        b.iter(|| {
+            for &(x, convention) in &compressed {
+                black_box(secp256k1.lift_x(black_box(x), convention));
+            }
+        })
+    });
+}
+
+fn bench_construction(c: &mut Criterion) {
+    let moduli: Vec<U256> = (0..10_000u64).map(|i| U256::from(0xffff_ffff_0000_0001_u64 - 2 * i)).collect();
+    let base = ModMath::new(moduli[0]);
+    let mut cache = ModMathCache::new();
+    for &m in &moduli {
+        cache.get(m);
+    }
+
+    c.bench_function("construct 10k 64-bit ModMath", |b| {
+        b.iter(|| {
+            for &m in &moduli {
+                black_box(ModMath::new(black_box(m)));
+            }
+        })
+    });
+    c.bench_function("with_modulus 10k 64-bit moduli", |b| {
+        b.iter(|| {
+            for &m in &moduli {
+                black_box(base.with_modulus(black_box(m)));
+            }
+        })
+    });
+    c.bench_function("warm ModMathCache 10k 64-bit moduli", |b| {
+        b.iter(|| {
+            for &m in &moduli {
+                black_box(cache.get(black_box(m)));
+            }
+        })
+    });
+}
+
+criterion_group!(benches, bench_reduce_wide, bench_mul, bench_exp, bench_exp_many, bench_scalar_multiplication, bench_decompression, bench_construction);
+criterion_main!(benches);
@@ -0,0 +1,119 @@
+#!/usr/bin/env python3
+"""Generates src/kat_vectors.rs, the known-answer vectors checked by src/kat_test.rs.
+
+The expected values come from Python's own integers (`pow` with a modulus, affine
+double-and-add), independently of the crate. The vectors are drawn from a fixed seed,
+so running the script again produces the same file.
+
+    python3 scripts/kat_vectors.py > src/kat_vectors.rs
+"""
+
+import random
+
+COUNT = 25
+
+CURVES = {
+    "BN128": {
+        "p": 21888242871839275222246405745257275088696311157297823662689037894645226208583,
+        "n": 21888242871839275222246405745257275088548364400416034343698204186575808495617,
+        "b": 3,
+        "g": (1, 2),
+    },
+    "SECP256K1": {
+        "p": 2**256 - 2**32 - 977,
+        "n": 0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141,
+        "b": 7,
+        "g": (
+            55066263022277343669578718895168534326250603453777594175500187360389116729240,
+            32670510020758816978083085130507043184471273380659243275938904335757337482424,
+        ),
+    },
+}
+
+
+def inv(a, p):
+    return pow(a, -1, p)
+
+
+def sqrt_smaller(a, p):
+    # both primes are 3 mod 4
+    assert p % 4 == 3
+    r = pow(a, (p + 1) // 4, p)
+    if r * r % p != a % p:
+        return None
+    return min(r, p - r)
+
+
+def add(p1, p2, p):
+    # None is the point at infinity; both curves have a = 0
+    if p1 is None:
+        return p2
+    if p2 is None:
+        return p1
+    (x1, y1), (x2, y2) = p1, p2
+    if x1 == x2 and (y1 + y2) % p == 0:
+        return None
+    if p1 == p2:
+        slope = 3 * x1 * x1 * inv(2 * y1, p) % p
+    else:
+        slope = (y2 - y1) * inv(x2 - x1, p) % p
+    x3 = (slope * slope - x1 - x2) % p
+    return (x3, (slope * (x1 - x3) - y1) % p)
+
+
+def multiply(k, point, p):
+    result = None
+    while k:
+        if k & 1:
+            result = add(result, point, p)
+        point = add(point, point, p)
+        k >>= 1
+    return result
+
+
+def h(x):
+    return '"%x"' % x
+
+
+def emit(name, kind, rows):
+    print("pub(crate) const %s: [%s; %d] = [" % (name, kind, len(rows)))
+    for row in rows:
+        print("  (%s)," % ", ".join(row))
+    print("];")
+    print()
+
+
+def main():
+    rng = random.Random(954)
+    print("// Generated by scripts/kat_vectors.py, do not edit.")
+    print("//")
+    print("// Numbers are hex strings. An empty expected value means no answer (no inverse, no")
+    print("// square root) and the point at infinity is (0, 0).")
+    print()
+    for name, curve in CURVES.items():
+        p, n = curve["p"], curve["n"]
+        # operands include the edges 0, 1 and p - 1 next to random values
+        operands = [0, 1, p - 1] + [rng.randrange(p) for _ in range(COUNT - 3)]
+
+        emit(name + "_MUL", "(&str, &str, &str)",
+             [(h(a), h(b), h(a * b % p)) for a, b in zip(operands, rng.sample(operands, COUNT))])
+        exponents = [0, 1, p - 2] + [rng.randrange(2**256) for _ in range(COUNT - 3)]
+        emit(name + "_EXP", "(&str, &str, &str)",
+             [(h(a), h(e), h(pow(a, e, p))) for a, e in zip(rng.sample(operands, COUNT), exponents)])
+        emit(name + "_INV", "(&str, &str)",
+             [(h(a), h(inv(a, p)) if a else '""') for a in operands])
+        squares = [rng.randrange(p) for _ in range(COUNT)]
+        squares[::2] = [a * a % p for a in squares[::2]]
+        emit(name + "_SQRT", "(&str, &str)",
+             [(h(a), h(r) if (r := sqrt_smaller(a, p)) is not None else '""') for a in squares])
+
+        scalars = [0, 1, 2, n - 1, n] + [rng.randrange(2**256) for _ in range(COUNT - 5)]
+        rows = []
+        for k in scalars:
+            point = multiply(k, curve["g"], p) or (0, 0)
+            rows.append((h(k), h(point[0]), h(point[1])))
+        emit(name + "_SCALAR_MUL", "(&str, &str, &str)", rows)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,187 @@
+//! Conformance suite for the range invariant shared by the public API.
+//!
+//! Every constructor and arithmetic method accepts values that are not reduced
+//! (equal to the modulus, above it, up to `U256::MAX`) and either reduces them into
+//! `[0, modulus)`, giving the same answer as for the reduced value, or rejects them
+//! with `Err`/`None`. Nothing returns a value outside `[0, modulus)` or a silently
+//! wrong answer.
+//!
+//! - `ModMath`: every method reduces its operands.
+//! - `NumberUnderMod::new` and `num_mod!` reduce the value.
+//! - `GaloisField::gf` and `GaloisField::poly` reduce, `GaloisField::gf_strict` rejects.
+//! - `ECPoint::new` stores raw coordinates. `Curve::point` reduces them and rejects
+//!   points off the curve, `Curve::new` reduces `a`, `b` and the generator and
+//!   `Curve::try_new` also rejects a generator off the curve. Curve arithmetic
+//!   reduces its inputs through `ModMath`.
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use primitive_types::U256;
+
+    use crate::curves::{Curve, CurveError, ECPoint, Secp256k1, BN128};
+    use crate::galois_field::GaloisField;
+    use crate::mod_math::ModMath;
+    use crate::number_mod::{ConvertPolicy, NumberUnderMod};
+    use crate::num_mod;
+
+    fn moduli() -> Vec<U256> {
+        vec![
+            U256::from(97),
+            U256::from(101),
+            U256::from(18446744073709551557_u64), // 2^64 - 59
+            Secp256k1().field_modulus,
+        ]
+    }
+
+    // modulus, 2 * modulus + 3 and U256::MAX, skipping values that overflow
+    fn out_of_range(modulus: U256) -> Vec<U256> {
+        let mut values = vec![modulus];
+        if let Some(v) = modulus.checked_mul(U256::from(2)).and_then(|v| v.checked_add(U256::from(3))) {
+            values.push(v);
+        }
+        values.push(U256::max_value());
+        values
+    }
+
+    #[test]
+    fn test_mod_math_reduces_operands() {
+        for m in moduli() {
+            let math = ModMath::new(m);
+            let y = U256::from(5);
+            for x in out_of_range(m) {
+                let r = x % m;
+                assert_eq!(math.reduce(x), r);
+
+                for (result, expected) in [
+                    (math.add(x, y), math.add(r, y)),
+                    (math.add(y, x), math.add(y, r)),
+                    (math.sub(x, y), math.sub(r, y)),
+                    (math.sub(y, x), math.sub(y, r)),
+                    (math.mul(x, y), math.mul(r, y)),
+                    (math.mul(y, x), math.mul(y, r)),
+                    (math.exp(x, U256::from(3)), math.exp(r, U256::from(3))),
+                    (math.add_inv(x), math.add_inv(r)),
+                    (math.square(x), math.square(r)),
+                ] {
+                    assert!(result < m);
+                    assert_eq!(result, expected);
+                }
+
+                assert!(math.eq(x, r));
+                assert_eq!(math.inv(x), math.inv(r));
+                if math.inv(x).is_some() {
+                    assert_eq!(math.div(y, x), math.div(y, r));
+                }
+
+                let root = math.sqrt_opt(x);
+                assert_eq!(root, math.sqrt_opt(r));
+                if let Some(root) = root {
+                    assert!(root < m);
+                    assert_eq!(math.square(root), r);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_sqrt_never_returns_wrong_root() {
+        for m in [97_u32, 101, 103] {
+            let math = ModMath::new(m);
+            for a in 0..m {
+                if let Ok(root) = math.sqrt(a) {
+                    assert_eq!(math.square(root), U256::from(a));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_number_under_mod_reduces() {
+        for m in moduli() {
+            for x in out_of_range(m) {
+                let r = x % m;
+                assert_eq!(NumberUnderMod::new(x, m).value(), r);
+                assert_eq!(num_mod!(x, m).value(), r);
+                assert_eq!(num_mod!(x, m), num_mod!(r, m));
+
+                let sum = (num_mod!(x, m) + num_mod!(U256::from(5), m)).unwrap();
+                assert_eq!(sum.value(), ModMath::new(m).add(r, U256::from(5)));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Modulus Cannot be Zero")]
+    fn test_number_under_mod_rejects_zero_modulus() {
+        let _ = NumberUnderMod::new(5, 0);
+    }
+
+    #[test]
+    fn test_galois_field_reduces() {
+        // GaloisField::new factors the modulus by trial division, so stay small
+        for m in [U256::from(97), U256::from(101), U256::from(65521)] {
+            let field = GaloisField::new(m).unwrap();
+            for x in out_of_range(m) {
+                assert_eq!(field.gf(x).value(), x % m);
+                assert!(field.gf_strict(x).is_err());
+                assert_eq!(field.poly(vec![x]).coefficients(), &[x % m]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_curve_point_reduces_or_rejects() {
+        for curve in [BN128(), Secp256k1()] {
+            let p = curve.field_modulus;
+            let G = curve.G;
+            assert!(curve.is_on_curve(&G));
+
+            for offset in out_of_range(p) {
+                if let Some(x) = G.x.checked_add(offset - offset % p) {
+                    assert_eq!(curve.point(x, G.y, ConvertPolicy::Reduce), Ok(G));
+                    assert_eq!(curve.point(x, G.y, ConvertPolicy::Strict), Err(CurveError::NotCanonical));
+                    assert!(curve.is_on_curve(&ECPoint::new(x, G.y)));
+                }
+            }
+
+            assert_eq!(curve.point(G.x, G.y + U256::one(), ConvertPolicy::Strict), Err(CurveError::NotOnCurve));
+        }
+    }
+
+    #[test]
+    fn test_curve_constructors_reduce() {
+        let bn128 = BN128();
+        let p = bn128.field_modulus;
+        let unreduced_G = ECPoint::new(bn128.G.x + p, bn128.G.y + p);
+        let curve = Curve::new(p, U256::from(3) + p, p, bn128.curve_order, unreduced_G);
+        assert_eq!(curve.G, bn128.G);
+        assert_eq!(curve.point_doubling(&curve.G), bn128.point_doubling(&bn128.G));
+
+        assert!(Curve::try_new(U256::zero(), U256::from(3), p, bn128.curve_order, unreduced_G).is_ok());
+        assert_eq!(
+            Curve::try_new(U256::zero(), U256::from(4), p, bn128.curve_order, bn128.G).err(),
+            Some(CurveError::NotOnCurve)
+        );
+        assert_eq!(
+            Curve::try_new(U256::zero(), U256::from(3), U256::zero(), bn128.curve_order, bn128.G).err(),
+            Some(CurveError::InvalidModulus)
+        );
+    }
+
+    #[test]
+    fn test_curve_arithmetic_reduces_coordinates() {
+        let bn128 = BN128();
+        let p = bn128.field_modulus;
+        let G = bn128.G;
+        let unreduced_G = ECPoint::new(G.x + p, G.y + p + p);
+
+        let double_G = bn128.point_doubling(&G);
+        assert_eq!(bn128.point_doubling(&unreduced_G), double_G);
+        assert_eq!(bn128.point_addition(&double_G, &unreduced_G), bn128.point_addition(&double_G, &G));
+        assert_eq!(
+            bn128.point_multiplication_scalar(U256::from(7), unreduced_G),
+            bn128.scalar_multiply_generator(U256::from(7))
+        );
+    }
+}
@@ -1,7 +1,11 @@
 #[cfg(test)]
+#[allow(non_snake_case)]
 mod tests {
-  use crate::curves::BN128;
-  use primitive_types::U256;
+  use crate::curves::{toy, Curve, CurveError, ECPoint, JacobianPoint, Scalar, Secp256k1, BN128};
+  use crate::number_mod::{ConvertPolicy, NumModError, NumberUnderMod};
+  use crate::mod_math::{ModMath, RootConvention, NON_RESIDUE_SEARCHES};
+  use primitive_types::{U256, U512};
+  use rand::{rngs::StdRng, RngCore, SeedableRng};
 
   #[test]
   fn test_point_addition() {
@@ -11,12 +15,36 @@ mod tests {
     let double_G = bn128.add_points(&G, &G);
     let triple_G = bn128.add_points(&double_G, &G);
 
-    dbg!(triple_G);
+    assert!(bn128.is_on_curve(&triple_G));
   }
 
   #[test]
   fn test_point_doubling() {
-    
+    let bn128 = BN128();
+    let G = bn128.G;
+
+    let double_G = bn128.point_doubling(&G);
+
+    assert!(bn128.is_on_curve(&double_G));
+    assert_eq!(double_G, bn128.add_points(&G, &G));
+  }
+
+  #[test]
+  fn test_add_congruent_points() {
+    let bn128 = BN128();
+    let p = bn128.field_modulus;
+    let G = bn128.G;
+    let double_G = bn128.point_doubling(&G);
+
+    let congruent_G = ECPoint::new(G.x + p, G.y);
+    assert_ne!(G, congruent_G);
+    assert_eq!(bn128.add_points(&G, &congruent_G), double_G);
+    assert_eq!(bn128.add_points(&congruent_G, &ECPoint::new(G.x, G.y + p)), double_G);
+    assert_eq!(bn128.point_addition(&G, &congruent_G), double_G);
+
+    let minus_G = ECPoint::new(G.x + p, p - G.y);
+    assert!(bn128.add_points(&G, &minus_G).is_identity());
+    assert!(bn128.point_addition(&G, &minus_G).is_identity());
   }
 
   #[test]
@@ -25,7 +53,930 @@ mod tests {
     let G = bn128.G;
 
     let double_G = bn128.scalar_multiply_generator(U256::from(2));
+    assert_eq!(double_G, bn128.point_doubling(&G));
+
+    let five_G = bn128.scalar_multiply_generator(U256::from(5));
+    let expected = bn128.point_addition(&bn128.point_doubling(&double_G), &G);
+    assert_eq!(five_G, expected);
+  }
+
+  #[test]
+  fn test_scalar_multiplication_bit_patterns() {
+    let secp256k1 = Secp256k1();
+    let G = secp256k1.G;
+
+    // leading zeros
+    let mut five_G = G;
+    for _ in 0..4 {
+      five_G = secp256k1.add_points(&five_G, &G);
+    }
+    assert_eq!(secp256k1.scalar_multiply_generator(U256::from(5)), five_G);
+
+    // only bit 255 set
+    let mut high_bit_G = G;
+    for _ in 0..255 {
+      high_bit_G = secp256k1.point_doubling(&high_bit_G);
+    }
+    assert_eq!(secp256k1.scalar_multiply_generator(U256::one() << 255), high_bit_G);
+
+    // all bits set: (2^256 - 1)G = 2 * 2^255 G - G
+    let minus_G = ECPoint::new(G.x, secp256k1.field_modulus - G.y);
+    let all_bits_G = secp256k1.add_points(&secp256k1.point_doubling(&high_bit_G), &minus_G);
+    assert_eq!(secp256k1.scalar_multiply_generator(U256::max_value()), all_bits_G);
+  }
+
+  #[test]
+  fn test_msm_window_sizes_agree() {
+    let bn128 = BN128();
+    let mut rng = StdRng::seed_from_u64(38);
+    // 64-bit scalars keep 16 full MSMs affordable in debug builds
+    let mut scalars: Vec<U256> = (0..5).map(|_| U256::from(rng.next_u64())).collect();
+    let mut points: Vec<_> = (0..5).map(|_| bn128.scalar_multiply_generator(U256::from(rng.next_u64()))).collect();
+    // repeated points, a point and its negation, the identity and a zero scalar
+    points.push(points[0]);
+    scalars.push(scalars[0]);
+    points.push(ECPoint::new(points[1].x, bn128.field_modulus - points[1].y));
+    scalars.push(scalars[1]);
+    points.push(ECPoint::identity());
+    scalars.push(U256::from(5));
+    points.push(bn128.G);
+    scalars.push(U256::zero());
+
+    let expected = scalars
+      .iter()
+      .zip(&points)
+      .map(|(k, p)| bn128.point_multiplication_scalar(*k, *p))
+      .fold(ECPoint::identity(), |acc, p| bn128.add_points(&acc, &p));
+
+    assert_eq!(bn128.msm(&scalars, &points), expected);
+    for window in 1..=16 {
+      assert_eq!(bn128.msm_with_window(&scalars, &points, window), expected, "window {}", window);
+    }
+    assert!(bn128.msm(&[], &[]).is_identity());
+  }
+
+  #[test]
+  fn test_msm_window_size_table() {
+    assert_eq!(Curve::msm_window_size(0), 3);
+    assert_eq!(Curve::msm_window_size(16), 3);
+    assert_eq!(Curve::msm_window_size(256), 7);
+    assert_eq!(Curve::msm_window_size(4096), 8);
+    assert_eq!(Curve::msm_window_size(65536), 10);
+  }
+
+  #[test]
+  fn test_point_bytes_round_trip() {
+    let bn128 = BN128();
+    let point = bn128.scalar_multiply_generator(U256::from(12345));
+    let bytes = point.to_bytes();
+    assert_eq!(ECPoint::from_bytes(&bytes), Ok(point));
+    assert_eq!(bn128.point_from_bytes(&bytes), Ok(point));
+    assert_eq!(U256::from_big_endian(&bytes[..32]), point.x);
+
+    let off_curve = ECPoint::new(point.x, point.y + U256::one()).to_bytes();
+    assert!(ECPoint::from_bytes(&off_curve).is_ok());
+    assert_eq!(bn128.point_from_bytes(&off_curve), Err(CurveError::NotOnCurve));
+
+    assert_eq!(ECPoint::from_bytes(&bytes[..32]), Err(CurveError::InvalidLength));
+    assert_eq!(bn128.point_from_bytes(&[bytes.as_slice(), &[0]].concat()), Err(CurveError::InvalidLength));
+  }
+
+  #[test]
+  fn test_checked_arithmetic_rejects_off_curve_points() {
+    let bn128 = BN128();
+    let G = bn128.G;
+    let off_curve = ECPoint::new(U256::from(1), U256::from(3));
+    assert!(!bn128.is_on_curve(&off_curve));
+
+    assert_eq!(bn128.checked_add_points(&G, &off_curve), Err(CurveError::NotOnCurve));
+    assert_eq!(bn128.checked_add_points(&off_curve, &G), Err(CurveError::NotOnCurve));
+    assert_eq!(bn128.checked_scalar_mul(U256::from(3), off_curve), Err(CurveError::NotOnCurve));
+
+    let double_G = bn128.point_doubling(&G);
+    assert_eq!(bn128.checked_add_points(&G, &G), Ok(double_G));
+    assert_eq!(bn128.checked_add_points(&ECPoint::identity(), &G), Ok(G));
+    assert_eq!(bn128.checked_scalar_mul(U256::from(2), G), Ok(double_G));
+  }
+
+  #[test]
+  fn test_unchecked_arithmetic_accepts_off_curve_points() {
+    let bn128 = BN128();
+    let off_curve = ECPoint::new(U256::from(1), U256::from(3));
+    // the result is meaningless, but the unchecked path must not assert
+    bn128.unchecked_add_points(&bn128.G, &off_curve);
+    bn128.unchecked_point_addition(&bn128.G, &off_curve);
+    bn128.unchecked_point_doubling(&off_curve);
+    bn128.unchecked_point_multiplication_scalar(U256::from(3), off_curve);
+  }
+
+  #[test]
+  #[cfg(debug_assertions)]
+  #[should_panic(expected = "is not on the curve")]
+  fn test_add_points_asserts_on_curve_in_debug() {
+    let bn128 = BN128();
+    bn128.add_points(&bn128.G, &ECPoint::new(U256::from(1), U256::from(3)));
+  }
+
+  #[test]
+  #[cfg(debug_assertions)]
+  #[should_panic(expected = "is not on the curve")]
+  fn test_point_doubling_asserts_on_curve_in_debug() {
+    let bn128 = BN128();
+    bn128.point_doubling(&ECPoint::new(U256::from(1), U256::from(3)));
+  }
+
+  #[test]
+  fn test_point_with_unreduced_coordinates() {
+    let bn128 = BN128();
+    let p = bn128.field_modulus;
+    let three_G = bn128.scalar_multiply_generator(U256::from(3));
+    for base in [bn128.G, three_G] {
+      let Some(x) = base.x.checked_add(p) else { continue };
+      let raw = ECPoint::new(x, base.y);
+      // the check reduces first, so the raw point is on the curve
+      assert!(bn128.is_on_curve(&raw));
+      assert_eq!(bn128.is_on_curve_batch(&[raw]), vec![true]);
+      // canonicalized, it behaves exactly like the reduced point
+      let point = bn128.point(raw.x, raw.y, ConvertPolicy::Reduce).unwrap();
+      assert_eq!(point, base);
+      assert_eq!(bn128.point_doubling(&point), bn128.point_doubling(&base));
+      assert_eq!(bn128.add_points(&point, &bn128.G), bn128.add_points(&base, &bn128.G));
+      assert_eq!(bn128.point_multiplication_scalar(U256::from(7), point), bn128.point_multiplication_scalar(U256::from(7), base));
+      // and the strict policy rejects it, for either coordinate
+      assert_eq!(bn128.point(raw.x, raw.y, ConvertPolicy::Strict), Err(CurveError::NotCanonical));
+      assert_eq!(bn128.point(base.x, base.y + p, ConvertPolicy::Strict), Err(CurveError::NotCanonical));
+      assert_eq!(bn128.point(base.x, base.y, ConvertPolicy::Strict), Ok(base));
+    }
+  }
+
+  #[test]
+  fn test_scalar_from_field_element() {
+    let bn128 = BN128();
+    let (p, r) = (bn128.field_modulus, bn128.curve_order);
+    let largest = NumberUnderMod::new(p - U256::one(), p);
+    let k = Scalar::from_field_element(&bn128, &largest, ConvertPolicy::Reduce).unwrap();
+    assert_eq!(k.value(), (p - U256::one()) % r);
+    assert_eq!(k, Scalar::new(&bn128, p - U256::one()));
+    assert_eq!(Scalar::from_field_element(&bn128, &largest, ConvertPolicy::Strict), Err(NumModError::ValueOutOfRange));
+
+    let small = NumberUnderMod::new(U256::from(12345), p);
+    let reduced = Scalar::from_field_element(&bn128, &small, ConvertPolicy::Reduce);
+    assert_eq!(reduced.clone().map(|k| k.value()), Ok(U256::from(12345)));
+    assert_eq!(Scalar::from_field_element(&bn128, &small, ConvertPolicy::Strict), reduced);
+
+    let other_field = NumberUnderMod::new(U256::from(12345), r);
+    assert_eq!(Scalar::from_field_element(&bn128, &other_field, ConvertPolicy::Reduce), Err(NumModError::ModulusMismatch));
+  }
+
+  #[test]
+  fn test_negate() {
+    let bn128 = BN128();
+    let G = bn128.G;
+    let minus_G = bn128.negate(&G);
+    assert_eq!(minus_G, ECPoint::new(G.x, bn128.field_modulus - G.y));
+    assert!(bn128.add_points(&G, &minus_G).is_identity());
+    assert_eq!(bn128.negate(&minus_G), G);
+    assert!(bn128.negate(&ECPoint::identity()).is_identity());
+  }
+
+  #[test]
+  fn test_curve_point_operators() {
+    let secp256k1 = Secp256k1();
+    let G = secp256k1.wrap(secp256k1.G);
+    let two_G = G + G;
+    assert_eq!(two_G.point(), secp256k1.point_doubling(&secp256k1.G));
+
+    let five_G = G * U256::from(5);
+    assert_eq!(five_G.point(), secp256k1.scalar_multiply_generator(U256::from(5)));
+    assert_eq!(five_G - two_G, G * U256::from(3));
+    assert_eq!(two_G - five_G, -(G * U256::from(3)));
+    assert_eq!(-G, secp256k1.wrap(secp256k1.negate(&secp256k1.G)));
+    assert!((G - G).point().is_identity());
+    assert!((G * secp256k1.curve_order).point().is_identity());
+  }
+
+  #[test]
+  #[should_panic(expected = "Cannot combine points on different curves")]
+  fn test_curve_point_operators_reject_other_curves() {
+    let (secp256k1, bn128) = (Secp256k1(), BN128());
+    let _ = secp256k1.wrap(ECPoint::identity()) + bn128.wrap(ECPoint::identity());
+  }
+
+  #[test]
+  fn test_lift_x() {
+    let secp256k1 = Secp256k1();
+    for k in 1..6u64 {
+      let point = secp256k1.scalar_multiply_generator(U256::from(k));
+      let parity = if point.y.bit(0) { RootConvention::OddY } else { RootConvention::EvenY };
+      assert_eq!(secp256k1.lift_x(point.x, parity), Some(point));
+      let flipped = if point.y.bit(0) { RootConvention::EvenY } else { RootConvention::OddY };
+      assert_eq!(secp256k1.lift_x(point.x, flipped), Some(secp256k1.negate(&point)));
+    }
+    // 5^3 + 7 = 132 is not a square mod p
+    assert_eq!(secp256k1.lift_x(U256::from(5), RootConvention::EvenY), None);
+  }
+
+  #[test]
+  fn test_rhs_and_y_candidates() {
+    let secp256k1 = Secp256k1();
+    let G = secp256k1.G;
+    let p = secp256k1.field_modulus;
+    assert_eq!(secp256k1.rhs(G.x), ModMath::new(p).square(G.y));
+
+    let (y, minus_y) = secp256k1.y_candidates(G.x).unwrap();
+    assert!(y < minus_y);
+    let mut roots = [y, minus_y];
+    roots.sort();
+    let mut expected = [G.y, p - G.y];
+    expected.sort();
+    assert_eq!(roots, expected);
+    assert_eq!(secp256k1.y_candidates(U256::from(5)), None);
+
+    // (5, 0) has order two on y^2 = x^3 + 7 mod 11
+    let curve = Curve::new(U256::zero(), U256::from(7), U256::from(11), U256::from(12), ECPoint::new(U256::from(2), U256::from(2)));
+    assert_eq!(curve.y_candidates(U256::from(5)), Some((U256::zero(), U256::zero())));
+  }
+
+  #[test]
+  fn test_naf_generator_multiplication() {
+    let mut rng = StdRng::seed_from_u64(54);
+    for curve in [BN128(), Secp256k1()] {
+      let mut scalars: Vec<U256> = (0..6u64).map(U256::from).collect();
+      scalars.extend([curve.curve_order - U256::one(), curve.curve_order + U256::one(), U256::MAX]);
+      scalars.extend((0..3).map(|_| {
+        let mut bytes = [0u8; 32];
+        rng.fill_bytes(&mut bytes);
+        U256::from_big_endian(&bytes)
+      }));
+      for k in scalars {
+        assert_eq!(curve.scalar_multiply_generator(k), curve.point_multiplication_scalar(k, curve.G), "{}", k);
+      }
+    }
+    let curve = toy::curve_17();
+    for k in 0..60u64 {
+      let k = U256::from(k);
+      assert_eq!(curve.scalar_multiply_generator(k), curve.point_multiplication_scalar(k, curve.G), "{}", k);
+    }
+  }
+
+  #[test]
+  fn test_scalar_ecdsa_formula() {
+    let secp256k1 = Secp256k1();
+    let hex = |s: &str| U256::from_str_radix(s, 16).unwrap();
+    let scalar = |s: &str| Scalar::new(&secp256k1, hex(s));
+    let d = scalar("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+    let k = scalar("a6e3c57dd01abe90086538398355dd4c3b17aa873382b0f24d6129493d8aad60");
+    let z = scalar("af2bdbe1aa9b6ec1e2ade1d694f41fc71a831d0268e9891562113d8a62add1bf");
+
+    // s = k^-1 (z + r d), reference values computed with Python integers
+    let r = Scalar::new(&secp256k1, secp256k1.scalar_multiply_generator(k.value()).x);
+    assert_eq!(r.value(), hex("432310e32cb80eb6503a26ce83cc165c783b870845fb8aad6d970889fcd7a6c8"));
+    let s = k.inv_mul(&r.mul_add(&d, &z)).unwrap();
+    assert_eq!(s.value(), hex("530128b6b81c548874a6305d93ed071ca6e05074d85863d4056ce89b02bfab69"));
+
+    let n = ModMath::new(secp256k1.curve_order);
+    assert_eq!(r.mul_add(&d, &z).value(), n.add(n.mul(r.value(), d.value()), z.value()));
+    let zero = Scalar::new(&secp256k1, U256::zero());
+    assert_eq!(zero.inv_mul(&z), None);
+    assert_eq!(zero.mul_add(&z, &d), d);
+  }
+
+  #[test]
+  #[should_panic(expected = "Cannot combine scalars of different curves")]
+  fn test_scalar_different_curves() {
+    let (bn128, secp256k1) = (BN128(), Secp256k1());
+    Scalar::new(&bn128, U256::one()).mul_add(&Scalar::new(&secp256k1, U256::one()), &Scalar::new(&bn128, U256::one()));
+  }
+
+  #[test]
+  fn test_jacobian_scalar_multiplication() {
+    let secp256k1 = Secp256k1();
+    let G = secp256k1.G;
+    for k in [0u64, 1, 2, 3, 7, 100, 0xdead_beef] {
+      let p = secp256k1.jacobian_scalar_mul(U256::from(k), &G);
+      assert_eq!(secp256k1.to_affine(&p), secp256k1.scalar_multiply_generator(U256::from(k)), "{}", k);
+    }
+    let jacobian_G = secp256k1.to_jacobian(&G);
+    let minus_G = secp256k1.to_jacobian(&secp256k1.negate(&G));
+    assert!(secp256k1.jacobian_add(&jacobian_G, &minus_G).is_identity());
+    let double_G = secp256k1.jacobian_add(&jacobian_G, &jacobian_G);
+    assert_eq!(secp256k1.to_affine(&double_G), secp256k1.point_doubling(&G));
+    assert!(secp256k1.to_affine(&JacobianPoint::identity()).is_identity());
+    assert!(secp256k1.jacobian_scalar_mul(secp256k1.curve_order, &G).is_identity());
+
+    // a != 0
+    let curve = toy::curve_17();
+    for k in 0..20u64 {
+      let p = curve.jacobian_scalar_mul(U256::from(k), &curve.G);
+      assert_eq!(curve.to_affine(&p), curve.scalar_multiply_generator(U256::from(k)), "{}", k);
+    }
+  }
+
+  #[test]
+  fn test_jacobian_equality() {
+    let secp256k1 = Secp256k1();
+    let math = ModMath::new(secp256k1.field_modulus);
+    let mut rng = StdRng::seed_from_u64(41);
+    let G = secp256k1.G;
+    let double_G = secp256k1.point_doubling(&G);
+
+    // (X, Y, Z) and (l^2 X, l^3 Y, l Z) are the same point
+    let p = secp256k1.to_jacobian(&G);
+    let l = math.reduce(U256::from(rng.next_u64()) + U256::one());
+    let randomized = JacobianPoint { x: math.mul(p.x, math.square(l)), y: math.mul(p.y, math.exp(l, U256::from(3))), z: l };
+    assert!(randomized.eq_affine(&G, &math));
+    assert!(randomized.eq_jacobian(&p, &math));
+    assert!(p.eq_jacobian(&randomized, &math));
+
+    let q = secp256k1.jacobian_add(&randomized, &randomized);
+    assert!(q.eq_affine(&double_G, &math));
+    assert!(!q.eq_affine(&G, &math));
+    assert!(!q.eq_jacobian(&randomized, &math));
+    assert!(!randomized.eq_affine(&secp256k1.negate(&G), &math));
+
+    let infinity = JacobianPoint { x: l, y: math.square(l), z: U256::zero() };
+    assert!(infinity.eq_affine(&ECPoint::identity(), &math));
+    assert!(infinity.eq_jacobian(&JacobianPoint::identity(), &math));
+    assert!(!infinity.eq_affine(&G, &math));
+    assert!(!randomized.eq_affine(&ECPoint::identity(), &math));
+    assert!(!randomized.eq_jacobian(&infinity, &math));
+  }
+
+  #[test]
+  #[cfg(feature = "instrumentation")]
+  fn test_jacobian_scalar_multiplication_needs_no_inversions() {
+    let bn128 = BN128();
+    bn128.reset_counters();
+    let p = bn128.jacobian_scalar_mul(U256::from(0xdead_beef_u64), &bn128.G);
+    assert_eq!(bn128.counters().inversions, 0);
+    assert!(bn128.counters().muls > 0);
+    bn128.to_affine(&p);
+    assert_eq!(bn128.counters().inversions, 1);
+
+    bn128.reset_counters();
+    bn128.scalar_multiply_generator(U256::from(0xdead_beef_u64));
+    // one inversion per affine addition and doubling
+    assert!(bn128.counters().inversions > 32);
+    bn128.reset_counters();
+    assert_eq!(bn128.counters(), Default::default());
+  }
+
+  #[test]
+  fn test_scalar_multiplication_identity_cases() {
+    for curve in [BN128(), Secp256k1(), toy::curve_97()] {
+      assert!(curve.scalar_multiply_generator(U256::zero()).is_identity());
+      assert!(curve.point_multiplication_scalar(U256::zero(), curve.G).is_identity());
+      assert_eq!(curve.scalar_multiply_generator(U256::one()), curve.G);
+      assert!(curve.scalar_multiply_generator(curve.curve_order).is_identity());
+      let minus_G = curve.scalar_multiply_generator(curve.curve_order - U256::one());
+      assert_eq!(minus_G, curve.negate(&curve.G));
+      assert_eq!(curve.scalar_multiply_generator(curve.curve_order + U256::one()), curve.G);
+    }
+    // the generic path agrees with the explicit check
+    let curve = toy::curve_97();
+    assert!(curve.point_multiplication_scalar(curve.curve_order, curve.G).is_identity());
+  }
+
+  #[test]
+  fn test_const_curve_parameters() {
+    use crate::curves::{
+      BN128_CURVE_ORDER, BN128_FIELD_MODULUS, BN128_G, SECP256K1_CURVE_ORDER, SECP256K1_FIELD_MODULUS, SECP256K1_G,
+    };
+    let dec = |s: &str| U256::from_dec_str(s).unwrap();
+    let hex = |s: &str| U256::from_str_radix(s, 16).unwrap();
+
+    assert_eq!(BN128_FIELD_MODULUS, dec("21888242871839275222246405745257275088696311157297823662689037894645226208583"));
+    assert_eq!(BN128_CURVE_ORDER, dec("21888242871839275222246405745257275088548364400416034343698204186575808495617"));
+    assert_eq!(BN128_G, ECPoint::new(U256::from(1), U256::from(2)));
+    assert_eq!(SECP256K1_FIELD_MODULUS, hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F"));
+    assert_eq!(SECP256K1_CURVE_ORDER, hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141"));
+    assert_eq!(SECP256K1_G.x, dec("55066263022277343669578718895168534326250603453777594175500187360389116729240"));
+    assert_eq!(SECP256K1_G.y, dec("32670510020758816978083085130507043184471273380659243275938904335757337482424"));
+
+    let bn128 = BN128();
+    assert!(bn128.is_on_curve(&BN128_G));
+    assert!(Secp256k1().is_on_curve(&SECP256K1_G));
+    let mod_math = crate::mod_math::ModMath::new(BN128_FIELD_MODULUS);
+    assert_eq!(mod_math.add(BN128_FIELD_MODULUS, U256::one()), U256::one());
+  }
+
+  #[test]
+  fn test_new_signed_p256() {
+    use crate::curves::{P256, P256_CURVE_ORDER, P256_FIELD_MODULUS, P256_G};
+    let hex = |s: &str| U256::from_str_radix(s, 16).unwrap();
+
+    assert_eq!(P256_FIELD_MODULUS, hex("FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFF"));
+    assert_eq!(P256_CURVE_ORDER, hex("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551"));
+    assert_eq!(P256_G.x, hex("6B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296"));
+    assert_eq!(P256_G.y, hex("4FE342E2FE1A7F9B8EE7EB4A7C0F9E162BCE33576B315ECECBB6406837BF51F5"));
+
+    // the standard a is p - 3
+    let standard_a = hex("FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFC");
+    let curve = Curve::new_signed(-3, 7, P256_FIELD_MODULUS, P256_CURVE_ORDER, P256_G);
+    assert_eq!(curve.a, standard_a);
+    assert_eq!(curve.b, U256::from(7));
+
+    let p256 = P256();
+    assert_eq!(p256.a, standard_a);
+    assert_eq!(p256.b, hex("5AC635D8AA3A93E7B3EBBD55769886BC651D06B0CC53B0F63BCE3C3E27D2604B"));
+    assert!(p256.is_on_curve(&P256_G));
+    assert!(p256.scalar_multiply_generator(P256_CURVE_ORDER).is_identity());
+  }
+
+  #[test]
+  fn test_sort_points() {
+    let curve = toy::curve_97();
+    let mut points: Vec<ECPoint> = (1..6u32).map(|k| curve.scalar_multiply_generator(U256::from(k))).collect();
+    points.push(ECPoint::identity());
+    points.sort();
+
+    assert_eq!(points[0], ECPoint::identity());
+    assert!(points.windows(2).all(|w| (w[0].x, w[0].y) < (w[1].x, w[1].y)));
+
+    // equal x is ordered by y, so a point comes next to its negation
+    let p = curve.scalar_multiply_generator(U256::from(3));
+    let mut pair = [curve.negate(&p), p];
+    pair.sort();
+    assert_eq!(pair[0].x, pair[1].x);
+    assert!(pair[0].y < pair[1].y);
+
+    // cmp_points reduces first, unlike Ord
+    let unreduced = ECPoint::new(p.x + curve.field_modulus, p.y);
+    assert_eq!(curve.cmp_points(&unreduced, &p), std::cmp::Ordering::Equal);
+    assert_eq!(unreduced.cmp(&p), std::cmp::Ordering::Greater);
+  }
+
+  #[test]
+  fn test_triple() {
+    let mut rng = StdRng::seed_from_u64(972);
+    for curve in [BN128(), Secp256k1()] {
+      for _ in 0..10 {
+        let p = curve.scalar_multiply_generator(curve.random_scalar(&mut rng));
+        assert_eq!(curve.triple(&p), curve.add_points(&curve.point_doubling(&p), &p));
+      }
+      assert!(curve.triple(&ECPoint::identity()).is_identity());
+    }
+
+    // points of order 3 and 19 on toy curves
+    let curve = toy::curve_17();
+    for p in toy::points(&toy::CURVE_17_POINTS) {
+      assert_eq!(curve.triple(&p), curve.point_multiplication_scalar(U256::from(3), p));
+    }
+    let order_3 = Curve::new(U256::zero(), U256::from(1), U256::from(7), U256::from(3), ECPoint::new(U256::zero(), U256::from(1)));
+    assert!(order_3.triple(&order_3.G).is_identity());
+    let order_2 = ECPoint::new(U256::from(3), U256::zero());
+    assert_eq!(order_3.triple(&order_2), order_2);
+  }
+
+  #[test]
+  fn test_small_multiples() {
+    for curve in [BN128(), Secp256k1()] {
+      let p = curve.scalar_multiply_generator(U256::from(0xdead_beef_u64));
+      let multiples = curve.small_multiples(&p, 16);
+      let mut expected = Vec::new();
+      let mut current = ECPoint::identity();
+      for _ in 0..16 {
+        current = curve.add_points(&current, &p);
+        expected.push(current);
+      }
+      assert_eq!(multiples, expected);
+      assert!(curve.small_multiples(&p, 0).is_empty());
+    }
+
+    // the multiples wrap around through the identity on a curve of order 19
+    let curve = toy::curve_17();
+    let multiples = curve.small_multiples(&curve.G, 25);
+    for (k, multiple) in multiples.iter().enumerate() {
+      assert_eq!(*multiple, curve.point_multiplication_scalar(U256::from(k + 1), curve.G));
+    }
+    assert!(multiples[18].is_identity());
+  }
+
+  #[test]
+  fn test_curve_equation_sides() {
+    for curve in [BN128(), Secp256k1()] {
+      let (lhs, rhs) = curve.curve_equation_sides(&curve.G);
+      assert_eq!(lhs, rhs);
+      assert_eq!(rhs, curve.rhs(curve.G.x));
+
+      // moving y by one changes y^2 by 2y + 1
+      let off_curve = ECPoint::new(curve.G.x, curve.G.y + 1);
+      let (lhs, rhs) = curve.curve_equation_sides(&off_curve);
+      let math = ModMath::new(curve.field_modulus);
+      assert_eq!(math.sub(lhs, rhs), math.add(math.add(curve.G.y, curve.G.y), 1));
+      assert!(!curve.is_on_curve(&off_curve));
+    }
+  }
+
+  #[test]
+  fn test_walk_from() {
+    let bn128 = BN128();
+    let start = U256::from(0xdead_beef_u64);
+    let walked: Vec<(U256, ECPoint)> = bn128.walk_from(start).take(100).collect();
+    for (i, (k, point)) in walked.iter().enumerate() {
+      assert_eq!(*k, start + i);
+      assert_eq!(*point, bn128.scalar_multiply_generator(*k));
+    }
+    assert_eq!(bn128.walk_from_batched(start, 7).take(100).collect::<Vec<_>>(), walked);
+
+    // order - 1, then the identity at 0, then G
+    let n = bn128.curve_order;
+    let expected = vec![
+      (n - 2, bn128.scalar_multiply_generator(n - 2)),
+      (n - 1, bn128.negate(&bn128.G)),
+      (U256::zero(), ECPoint::identity()),
+      (U256::one(), bn128.G),
+      (U256::from(2), bn128.point_doubling(&bn128.G)),
+    ];
+    assert_eq!(bn128.walk_from(n - 2).take(5).collect::<Vec<_>>(), expected);
+    assert_eq!(bn128.walk_from_batched(n - 2, 2).take(5).collect::<Vec<_>>(), expected);
+    // a start at or above the order is reduced
+    assert_eq!(bn128.walk_from(n + 1).next(), Some((U256::one(), bn128.G)));
+
+    // more than a full turn around a small group
+    let curve = toy::curve_17();
+    let turns: Vec<_> = curve.walk_from_batched(U256::from(3), 5).take(45).collect();
+    for (i, (k, point)) in turns.iter().enumerate() {
+      assert_eq!(*k, U256::from((3 + i) % 19));
+      assert_eq!(*point, curve.point_multiplication_scalar(*k, curve.G));
+    }
+  }
+
+  #[test]
+  fn test_unsupported_characteristic() {
+    // y^2 = x^3 + 1 over GF(2) and y^2 = x^3 + x + 1 over GF(3)
+    let gf2_g = ECPoint::new(U256::one(), U256::zero());
+    let gf3_g = ECPoint::new(U256::zero(), U256::one());
+    assert_eq!(
+      Curve::try_new(U256::zero(), U256::one(), U256::from(2), U256::from(3), gf2_g).err(),
+      Some(CurveError::UnsupportedCharacteristic)
+    );
+    assert_eq!(
+      Curve::try_new(U256::one(), U256::one(), U256::from(3), U256::from(4), gf3_g).err(),
+      Some(CurveError::UnsupportedCharacteristic)
+    );
+
+    // the checked operations report the field instead of panicking in the doubling slope
+    let gf2_curve = Curve::new(U256::zero(), U256::one(), U256::from(2), U256::from(3), gf2_g);
+    assert!(gf2_curve.is_on_curve(&gf2_g));
+    assert_eq!(gf2_curve.checked_add_points(&gf2_g, &gf2_g), Err(CurveError::UnsupportedCharacteristic));
+    assert_eq!(gf2_curve.checked_scalar_mul(U256::from(2), gf2_g), Err(CurveError::UnsupportedCharacteristic));
+    let gf3_curve = Curve::new(U256::one(), U256::one(), U256::from(3), U256::from(4), gf3_g);
+    assert_eq!(gf3_curve.checked_add_points(&gf3_g, &gf3_g), Err(CurveError::UnsupportedCharacteristic));
+
+    // GF(5) is the smallest supported field
+    assert!(Curve::try_new(U256::one(), U256::one(), U256::from(5), U256::from(9), ECPoint::new(U256::zero(), U256::one())).is_ok());
+  }
+
+  #[test]
+  fn test_vector_commitment() {
+    use crate::curves::VectorCommitment;
+    use crate::mod_math::ArithError;
+
+    let bn128 = BN128();
+    let scheme = VectorCommitment::new(&bn128, 16).unwrap();
+    // each verification is a multi-scalar multiplication, slow in debug builds
+    let mut rng = StdRng::seed_from_u64(977);
+    let values: Vec<U256> = (0..16).map(|_| bn128.random_scalar(&mut rng)).collect();
+    let blinding = bn128.random_scalar(&mut rng);
+    let commitment = scheme.commit(&values, blinding).unwrap();
+    for (i, &expected) in values.iter().enumerate() {
+      let (value, proof) = scheme.open(&values, blinding, i).unwrap();
+      assert_eq!(value, expected);
+      assert!(scheme.verify_open(&commitment, i, value, &proof));
+    }
+
+    // a tampered value, position, proof or commitment does not verify
+    for i in [0, 11] {
+      let (value, proof) = scheme.open(&values, blinding, i).unwrap();
+      assert!(!scheme.verify_open(&commitment, i, value + 1, &proof));
+      assert!(!scheme.verify_open(&commitment, (i + 1) % 16, value, &proof));
+      let mut tampered = proof.clone();
+      tampered.quotient[i % 15] += U256::one();
+      assert!(!scheme.verify_open(&commitment, i, value, &tampered));
+      tampered = proof.clone();
+      tampered.blinding += U256::one();
+      assert!(!scheme.verify_open(&commitment, i, value, &tampered));
+      assert!(!scheme.verify_open(&bn128.G, i, value, &proof));
+      assert!(!scheme.verify_open(&commitment, 16, value, &proof));
+    }
+
+    // the basis commitments commit to the unit vectors
+    let mut unit = vec![U256::zero(); 16];
+    unit[5] = U256::one();
+    assert_eq!(scheme.commit(&unit, U256::zero()), Ok(scheme.basis_commitments()[5]));
+    assert_eq!(scheme.commit(&unit[1..], U256::zero()), Err(ArithError::LengthMismatch));
+    assert_eq!(scheme.domain().size(), 16);
+    // the BN128 group order has 2^28 dividing r - 1, but not 2^29
+    assert!(VectorCommitment::new(&bn128, 1 << 29).is_none());
+  }
+
+  #[test]
+  fn test_from_j_invariant() {
+    let bn128_p = BN128().field_modulus;
+    for p in [U256::from(101), U256::from(10007), bn128_p] {
+      let mut rng = StdRng::seed_from_u64(977);
+      let js = [U256::zero(), U256::from(1728), U256::one(), U256::from(8000), p - 1, U256::from(rng.next_u64()) % p];
+      for j in js {
+        let curve = Curve::from_j_invariant(j, p);
+        assert_eq!(curve.j_invariant(), j % p, "j = {}, p = {}", j, p);
+        assert_eq!(curve.curve_order, U256::zero());
+        assert!(curve.G.is_identity());
+        // the curve has points, e.g. among the first few x
+        let x = (0..100u32).map(U256::from).find(|&x| curve.lift_x(x, RootConvention::EvenY).is_some()).unwrap();
+        assert!(curve.is_on_curve(&curve.lift_x(x, RootConvention::EvenY).unwrap()));
+      }
+      // j is taken modulo p
+      assert_eq!(Curve::from_j_invariant(p + 5, p).j_invariant(), U256::from(5));
+    }
+    let special = Curve::from_j_invariant(U256::from(1728), U256::from(101));
+    assert_eq!((special.a, special.b), (U256::one(), U256::zero()));
+  }
+
+  #[test]
+  fn test_derive_glv_parameters() {
+    let u256 = |s: &str| U256::from_str_radix(s, 16).unwrap();
+    let secp256k1 = Secp256k1();
+    let glv = secp256k1.derive_glv_parameters().unwrap();
+    assert_eq!(glv.beta, u256("7ae96a2b657c07106e64479eac3434e99cf0497512f58995c1396c28719501ee"));
+    assert_eq!(glv.lambda, u256("5363ad4cc05c30e0a5261c028812645a122e22ea20816678df02967c1b23bd72"));
+
+    let bn128 = BN128();
+    let glv = bn128.derive_glv_parameters().unwrap();
+    assert_eq!(glv.beta, U256::from_dec_str("2203960485148121921418603742825762020974279258880205651966").unwrap());
+    assert_eq!(glv.lambda, U256::from_dec_str("4407920970296243842393367215006156084916469457145843978461").unwrap());
+
+    // the endomorphism acts as lambda on every point, not just G
+    let field = ModMath::new(bn128.field_modulus);
+    let point = bn128.scalar_multiply_generator(U256::from(978));
+    let image = ECPoint::new(field.mul(glv.beta, point.x), point.y);
+    assert_eq!(bn128.point_multiplication_scalar(glv.lambda, point), image);
+
+    // cached, and shared with clones
+    assert_eq!(bn128.clone().derive_glv_parameters(), Some(glv));
+
+    // y^2 = x^3 + 5 over GF(97), of order 79: both moduli are 1 mod 3
+    let curve_97 = toy::curve_97();
+    let glv = curve_97.derive_glv_parameters().unwrap();
+    let field = ModMath::new(97);
+    assert_eq!(field.cube(glv.beta), U256::one());
+    assert_ne!(glv.beta, U256::one());
+    for k in 1..79u64 {
+      let point = curve_97.scalar_multiply_generator(U256::from(k));
+      let image = ECPoint::new(field.mul(glv.beta, point.x), point.y);
+      assert_eq!(curve_97.point_multiplication_scalar(glv.lambda, point), image);
+    }
+
+    // a != 0, and a field modulus of 2 mod 3 where 1 is the only cube root of unity
+    assert!(crate::curves::P256().derive_glv_parameters().is_none());
+    assert!(toy::curve_17().derive_glv_parameters().is_none());
+    let G = ECPoint::new(U256::from(2), U256::from(2));
+    assert!(Curve::new(U256::zero(), U256::from(7), U256::from(11), U256::from(12), G).derive_glv_parameters().is_none());
+  }
+
+  #[test]
+  fn test_discriminant_and_j_invariant() {
+    let u256 = |s: &str| U256::from_str_radix(s, 16).unwrap();
+    // a = 0: the discriminant is -16 * 27 * b^2 and the j-invariant is 0
+    let secp256k1 = Secp256k1();
+    assert_eq!(secp256k1.discriminant(), u256("fffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffa97f"));
+    assert_eq!(secp256k1.j_invariant(), U256::zero());
+    assert_eq!(BN128().j_invariant(), U256::zero());
+
+    let p256 = crate::curves::P256();
+    assert_eq!(p256.discriminant(), u256("680d8cb6fbc0a4201dd499d851c1ae23e501d15636a856b19e4ce86d8da606e5"));
+    assert_eq!(p256.j_invariant(), u256("1198954424ebb0f8479de43131caece8ee0a9b13a558c21e0b2f74e3fcd36aa3"));
+
+    // y^2 = x^3 + 2x + 2 over GF(17), and the same curve scaled by u = 3: (a u^4, b u^6)
+    let curve_17 = toy::curve_17();
+    assert_eq!((curve_17.discriminant(), curve_17.j_invariant()), (U256::from(4), U256::from(3)));
+    let twisted = Curve::new(U256::from(2 * 81), U256::from(2 * 729), U256::from(17), U256::zero(), ECPoint::identity());
+    assert_eq!(twisted.j_invariant(), U256::from(3));
+
+    // y^2 = x^3 - 3x + 2 = (x - 1)^2 (x + 2) has a node
+    let node = Curve::new_signed(-3, 2, U256::from(17), U256::zero(), ECPoint::identity());
+    assert!(node.discriminant().is_zero());
+  }
+
+  #[test]
+  #[should_panic(expected = "0 is not invertible mod 17")]
+  fn test_j_invariant_singular() {
+    Curve::new(U256::zero(), U256::zero(), U256::from(17), U256::zero(), ECPoint::identity()).j_invariant();
+  }
+
+  fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+  }
+
+  #[test]
+  fn test_fingerprint() {
+    let (bn128, secp256k1) = (BN128(), Secp256k1());
+    // pinned, and checked against an independent implementation of the mix
+    assert_eq!(hex(&bn128.fingerprint()), "e195a2fddcf8d21372be39075211c62d0e2f02a355ce228ba341277619dc29f4");
+    assert_eq!(hex(&secp256k1.fingerprint()), "a9fa3b6f4de021853d7fa5b39bf45ee2723105be9b808bbf8107b49dbe731a84");
+    let field = ModMath::new(secp256k1.field_modulus);
+    assert_eq!(hex(&field.fingerprint()), "40047425eb8e8e565e9a7fb233e65022bdce9655d6fc5143c1750dfd569c4c64");
+
+    let G = secp256k1.G;
+    let b_plus_one = Curve::new(U256::zero(), U256::from(8), secp256k1.field_modulus, secp256k1.curve_order, G);
+    assert_ne!(b_plus_one.fingerprint(), secp256k1.fingerprint());
+    let other_order = Curve::new(U256::zero(), U256::from(7), secp256k1.field_modulus, secp256k1.curve_order - U256::one(), G);
+    assert_ne!(other_order.fingerprint(), secp256k1.fingerprint());
+    assert_eq!(Secp256k1().fingerprint(), secp256k1.fingerprint());
+    assert_ne!(ModMath::new(bn128.field_modulus).fingerprint(), field.fingerprint());
+  }
+
+  #[test]
+  fn test_is_on_curve_batch() {
+    let bn128 = BN128();
+    let mut points = Vec::new();
+    for k in 1u64..20 {
+      let p = bn128.scalar_multiply_generator(U256::from(k));
+      points.push(p);
+      points.push(ECPoint::new(p.x, p.y + 1));
+    }
+    points.push(ECPoint::identity());
+    let batch = bn128.is_on_curve_batch(&points);
+    let single: Vec<bool> = points.iter().map(|p| bn128.is_on_curve(p)).collect();
+    assert_eq!(batch, single);
+    assert_eq!(batch.iter().filter(|&&on_curve| on_curve).count(), 19);
+    // a curve with a != 0 over GF(97): y^2 = x^3 + 2x + 3
+    let curve = Curve::new(U256::from(2), U256::from(3), U256::from(97), U256::from(5), ECPoint::new(U256::from(3), U256::from(6)));
+    let points = [curve.G, ECPoint::new(U256::from(3), U256::from(91)), ECPoint::new(U256::from(3), U256::from(7))];
+    assert_eq!(curve.is_on_curve_batch(&points), vec![true, true, false]);
+    assert!(curve.is_on_curve_batch(&[]).is_empty());
+  }
+
+  #[test]
+  fn test_decompress_secp256k1_points() {
+    let secp256k1 = Secp256k1();
+    let mut point = secp256k1.G;
+    for _ in 0..1000 {
+      let convention = if point.y.bit(0) { RootConvention::OddY } else { RootConvention::EvenY };
+      let decompressed = secp256k1.lift_x(point.x, convention).unwrap();
+      assert!(secp256k1.is_on_curve(&decompressed));
+      assert_eq!(decompressed, point);
+      point = secp256k1.add_points(&point, &secp256k1.G);
+    }
+  }
+
+  #[test]
+  fn test_non_residue_search_once_per_curve() {
+    // y^2 = x^3 + 5 over a field with p = 9 (mod 16), where sqrt uses Tonelli-Shanks
+    let p = U256::from(18446744073709551337_u64);
+    let curve = Curve::new(U256::zero(), U256::from(5), p, p, ECPoint::identity());
+    let searches = || NON_RESIDUE_SEARCHES.with(|count| count.get());
+    let before = searches();
+    let clone = curve.clone();
+    let mut lifted = 0;
+    for x in 1u64..12 {
+      for c in [&curve, &clone] {
+        if let Some(point) = c.lift_x(U256::from(x), RootConvention::EvenY) {
+          assert!(c.is_on_curve(&point));
+          lifted += 1;
+        }
+      }
+    }
+    assert!(lifted > 0);
+    assert_eq!(searches(), before + 1);
+    // a new curve over the same field sets up its own field arithmetic
+    let other = Curve::new(U256::zero(), U256::from(5), p, p, ECPoint::identity());
+    other.lift_x(U256::from(2), RootConvention::EvenY);
+    other.lift_x(U256::from(3), RootConvention::EvenY);
+    assert_eq!(searches(), before + 2);
+  }
+
+  #[test]
+  fn test_subgroup_order() {
+    for curve in [BN128(), Secp256k1()] {
+      assert_eq!(curve.subgroup_order(&curve.G), curve.curve_order);
+      assert_eq!(curve.subgroup_order(&curve.scalar_multiply_generator(U256::from(12345))), curve.curve_order);
+      assert_eq!(curve.subgroup_order(&ECPoint::identity()), U256::one());
+    }
+    // y^2 = x^3 + 7 over GF(11), a cyclic group of order 12 generated by (4, 4)
+    let curve = Curve::new(U256::zero(), U256::from(7), U256::from(11), U256::from(12), ECPoint::new(U256::from(4), U256::from(4)));
+    for ((x, y), order) in [((4, 4), 12), ((7, 8), 12), ((6, 5), 6), ((2, 9), 4), ((3, 1), 3), ((5, 0), 2)] {
+      let p = ECPoint::new(U256::from(x), U256::from(y));
+      assert_eq!(curve.subgroup_order(&p), U256::from(order), "({}, {})", x, y);
+      // the order is the smallest positive multiple reaching the identity
+      assert!(curve.point_multiplication_scalar(U256::from(order), p).is_identity());
+      for k in 1..order {
+        assert!(!curve.point_multiplication_scalar(U256::from(k), p).is_identity());
+      }
+    }
+  }
+
+  #[test]
+  #[should_panic(expected = "is not in the subgroup of order 4")]
+  fn test_subgroup_order_outside_subgroup() {
+    // (2, 2) has order 4, so (3, 1) of order 3 is outside its subgroup
+    let curve = Curve::new(U256::zero(), U256::from(7), U256::from(11), U256::from(4), ECPoint::new(U256::from(2), U256::from(2)));
+    curve.subgroup_order(&ECPoint::new(U256::from(3), U256::from(1)));
+  }
+
+  // y^2 = x^3 + 5 over GF(103) has 97 points, a prime
+  fn toy_curve() -> Curve {
+    Curve::try_new(U256::zero(), U256::from(5), U256::from(103), U256::from(97), ECPoint::new(U256::from(2), U256::from(42))).unwrap()
+  }
+
+  #[test]
+  fn test_hash_to_scalar_is_unbiased() {
+    let curve = toy_curve();
+    let mut rng = StdRng::seed_from_u64(39);
+    let mut counts = [0u32; 97];
+    for _ in 0..97 * 1000 {
+      let mut bytes = [0u8; 64];
+      rng.fill_bytes(&mut bytes);
+      counts[curve.hash_to_scalar(&bytes).as_usize()] += 1;
+    }
+    assert!(counts.iter().all(|&c| (850..1150).contains(&c)), "{:?}", counts);
+
+    // naively reducing a value not much wider than the order, here a byte, favours
+    // the residues below 256 % 97 = 62 by a factor 3 / 2
+    let mut naive = [0u32; 97];
+    for byte in 0..=255_u32 {
+      naive[curve.reduce_to_scalar(U256::from(byte)).as_usize()] += 1;
+    }
+    assert!(naive[..62].iter().all(|&c| c == 3));
+    assert!(naive[62..].iter().all(|&c| c == 2));
+  }
+
+  #[test]
+  fn test_hash_to_scalar_deterministic() {
+    let secp256k1 = Secp256k1();
+    let bytes = [0xa5; 64];
+    let scalar = secp256k1.hash_to_scalar(&bytes);
+    assert_eq!(scalar, secp256k1.hash_to_scalar(&bytes));
+    assert!(scalar < secp256k1.curve_order);
+    assert_ne!(scalar, secp256k1.hash_to_scalar(&[0x5a; 64]));
+
+    let wide = U512::from_big_endian(&bytes) % U512::from(secp256k1.curve_order);
+    assert_eq!(U512::from(scalar), wide);
+  }
+
+  #[test]
+  fn test_reduce_to_scalar() {
+    let curve = toy_curve();
+    assert_eq!(curve.reduce_to_scalar(U256::from(97 + 5)), U256::from(5));
+    assert_eq!(curve.reduce_to_scalar(U256::from(96)), U256::from(96));
+  }
+
+  #[test]
+  fn test_random_scalar_range() {
+    let curve = toy_curve();
+    let mut rng = StdRng::seed_from_u64(15);
+    let mut seen = [false; 97];
+    for _ in 0..3000 {
+      let k = curve.random_scalar(&mut rng);
+      assert!(k > U256::zero() && k < curve.curve_order);
+      seen[k.as_usize()] = true;
+    }
+    assert!(seen[1..].iter().all(|&s| s));
+  }
+
+  #[test]
+  #[should_panic(expected = "Curve order must be greater than 1")]
+  fn test_random_scalar_without_order() {
+    let curve = Curve::from_j_invariant(U256::zero(), U256::from(103));
+    curve.random_scalar(&mut StdRng::seed_from_u64(15));
+  }
+
+  fn u512(value: &str) -> U512 {
+    U512::from_dec_str(value).unwrap()
+  }
+
+  // NIST P-384, whose 384-bit field does not fit in U256
+  fn p384() -> Curve<U512> {
+    let p = u512("39402006196394479212279040100143613805079739270465446667948293404245721771496870329047266088258938001861606973112319");
+    let b = u512("27580193559959705877849011840389048093056905856361568521428707301988689241309860865136260764883745107765439761230575");
+    let n = u512("39402006196394479212279040100143613805079739270465446667946905279627659399113263569398956308152294913554433653942643");
+    let G = ECPoint::new(
+      u512("26247035095799689268623156744566981891852923491109213387815615900925518854738050089022388053975719786650872476732087"),
+      u512("8325710961489029985546751289520108179287853048861315594709205902480503199884419224438643760392947333078086511627871"),
+    );
+    Curve::try_new(p - U512::from(3), b, p, n, G).unwrap()
+  }
+
+  #[test]
+  fn test_curve_over_u512_field() {
+    let p384 = p384();
+    let G = p384.G;
+
+    let double_G = p384.point_doubling(&G);
+    assert_eq!(double_G, ECPoint::new(
+      u512("1362138308511466522361153706999924933599454966107597910086607881313301390679204654798639248640660900363360053616481"),
+      u512("21933325650940841369538204578070064804451893403314136885642470114978241170633179043576249504748352841115137159204480"),
+    ));
+
+    let five_G = p384.scalar_multiply_generator(U512::from(5));
+    assert!(p384.is_on_curve(&five_G));
+    assert_eq!(five_G, ECPoint::new(
+      u512("2750097886727221852871437489830375535902219297598792969027200942344246907638882141082920203987932105239591664846012"),
+      u512("22109872175543161315473274033501382113217372000035529196852092508530348707813916169793617815529384830733840621334842"),
+    ));
 
-    dbg!(double_G);
+    // (n - 1)G is -G
+    let minus_G = p384.scalar_multiply_generator(p384.curve_order - U512::one());
+    assert_eq!(minus_G, ECPoint::new(G.x, p384.field_modulus - G.y));
   }
-}
\ No newline at end of file
+}
@@ -0,0 +1,103 @@
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use super::{Curve, ECPoint};
+use crate::mod_math::{FieldInt, FieldMath};
+
+/// `CurvePoint` is a point together with the curve it lies on, so that points can be
+/// combined with the arithmetic operators. It is the curve analog of `NumberUnderMod`.
+///
+/// # Panics
+///
+/// The binary operators panic if the operands belong to different curves.
+///
+/// # Examples
+///
+/// ```
+/// use modular_math::curves::BN128;
+/// use primitive_types::U256;
+///
+/// let bn128 = BN128();
+/// let g = bn128.wrap(bn128.G);
+/// let three_g = g * U256::from(3);
+/// assert_eq!(three_g - g, g + g);
+/// assert!((-g + g).point().is_identity());
+/// ```
+#[derive(Clone, Copy)]
+pub struct CurvePoint<'a, T: FieldInt = primitive_types::U256> {
+  curve: &'a Curve<T>,
+  point: ECPoint<T>,
+}
+
+impl<'a, T: FieldInt> CurvePoint<'a, T> {
+  /// Wraps a point of the curve, see `Curve::wrap`.
+  pub fn new(curve: &'a Curve<T>, point: ECPoint<T>) -> Self {
+    CurvePoint { curve, point }
+  }
+
+  /// Returns the underlying point.
+  pub fn point(&self) -> ECPoint<T> {
+    self.point
+  }
+
+  /// Returns the curve the point lies on.
+  pub fn curve(&self) -> &'a Curve<T> {
+    self.curve
+  }
+
+  fn assert_same_curve(&self, other: &Self) {
+    let (c1, c2) = (self.curve, other.curve);
+    assert!(
+      std::ptr::eq(c1, c2) || (c1.a == c2.a && c1.b == c2.b && c1.field_modulus == c2.field_modulus),
+      "Cannot combine points on different curves"
+    );
+  }
+}
+
+impl<T: FieldInt> Add for CurvePoint<'_, T> {
+  type Output = Self;
+
+  fn add(self, other: Self) -> Self {
+    self.assert_same_curve(&other);
+    CurvePoint { curve: self.curve, point: self.curve.add_points(&self.point, &other.point) }
+  }
+}
+
+impl<T: FieldInt> Sub for CurvePoint<'_, T> {
+  type Output = Self;
+
+  fn sub(self, other: Self) -> Self {
+    self + -other
+  }
+}
+
+impl<T: FieldInt> Neg for CurvePoint<'_, T> {
+  type Output = Self;
+
+  fn neg(self) -> Self {
+    CurvePoint { curve: self.curve, point: self.curve.negate(&self.point) }
+  }
+}
+
+impl<T: FieldInt> Mul<T> for CurvePoint<'_, T> {
+  type Output = Self;
+
+  fn mul(self, scalar: T) -> Self {
+    CurvePoint { curve: self.curve, point: self.curve.point_multiplication_scalar(scalar, self.point) }
+  }
+}
+
+// Points compare by their reduced coordinates, like `Curve::add_points`
+impl<T: FieldInt> PartialEq for CurvePoint<'_, T> {
+  fn eq(&self, other: &Self) -> bool {
+    let mod_math = &self.curve.math;
+    let (p1, p2) = (self.point, other.point);
+    mod_math.reduce(p1.x) == mod_math.reduce(p2.x) && mod_math.reduce(p1.y) == mod_math.reduce(p2.y)
+  }
+}
+
+impl<T: FieldInt> fmt::Debug for CurvePoint<'_, T> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    self.point.fmt(f)
+  }
+}
@@ -1,31 +1,70 @@
 use primitive_types::U256;
 
 use super::{Curve, ECPoint};
+use crate::number_mod::NumberUnderMod;
+
+// The curve parameters below are spelled out as little-endian limbs so they can be built
+// in a `const` context. `bn128_test` checks each of them against its decimal or hex form.
+
+/// Field modulus of BN128, `21888242871839275222246405745257275088696311157297823662689037894645226208583`.
+pub const BN128_FIELD_MODULUS: U256 = U256([0x3c208c16d87cfd47, 0x97816a916871ca8d, 0xb85045b68181585d, 0x30644e72e131a029]);
+
+/// Order of the BN128 group, `21888242871839275222246405745257275088548364400416034343698204186575808495617`.
+pub const BN128_CURVE_ORDER: U256 = U256([0x43e1f593f0000001, 0x2833e84879b97091, 0xb85045b68181585d, 0x30644e72e131a029]);
+
+/// Generator of BN128, `(1, 2)`.
+pub const BN128_G: ECPoint = ECPoint::new(U256([1, 0, 0, 0]), U256([2, 0, 0, 0]));
+
+/// Field modulus of Secp256k1, `2^256 - 2^32 - 977`.
+pub const SECP256K1_FIELD_MODULUS: U256 = U256([0xfffffffefffffc2f, 0xffffffffffffffff, 0xffffffffffffffff, 0xffffffffffffffff]);
+
+/// Order of the Secp256k1 group, `0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141`.
+pub const SECP256K1_CURVE_ORDER: U256 = U256([0xbfd25e8cd0364141, 0xbaaedce6af48a03b, 0xfffffffffffffffe, 0xffffffffffffffff]);
+
+/// Generator of Secp256k1.
+pub const SECP256K1_G: ECPoint = ECPoint::new(
+  U256([0x59f2815b16f81798, 0x029bfcdb2dce28d9, 0x55a06295ce870b07, 0x79be667ef9dcbbac]),
+  U256([0x9c47d08ffb10d4b8, 0xfd17b448a6855419, 0x5da4fbfc0e1108a8, 0x483ada7726a3c465]),
+);
+
+/// Field modulus of P-256, `2^256 - 2^224 + 2^192 + 2^96 - 1`.
+pub const P256_FIELD_MODULUS: U256 = U256([0xffffffffffffffff, 0x00000000ffffffff, 0x0000000000000000, 0xffffffff00000001]);
+
+/// Order of the P-256 group, `0xFFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551`.
+pub const P256_CURVE_ORDER: U256 = U256([0xf3b9cac2fc632551, 0xbce6faada7179e84, 0xffffffffffffffff, 0xffffffff00000000]);
+
+/// Generator of P-256.
+pub const P256_G: ECPoint = ECPoint::new(
+  U256([0xf4a13945d898c296, 0x77037d812deb33a0, 0xf8bce6e563a440f2, 0x6b17d1f2e12c4247]),
+  U256([0xcbb6406837bf51f5, 0x2bce33576b315ece, 0x8ee7eb4a7c0f9e16, 0x4fe342e2fe1a7f9b]),
+);
+
+// The P-256 coefficient b, `0x5AC635D8AA3A93E7B3EBBD55769886BC651D06B0CC53B0F63BCE3C3E27D2604B`
+const P256_B: U256 = U256([0x3bce3c3e27d2604b, 0x651d06b0cc53b0f6, 0xb3ebbd55769886bc, 0x5ac635d8aa3a93e7]);
 
 /// BN128 Elliptical Curve
+#[allow(non_snake_case)]
 pub fn BN128() -> Curve {
   let a = U256::zero();
   let b = U256::from(3);
-  let field_modulus = U256::from_dec_str("21888242871839275222246405745257275088696311157297823662689037894645226208583").unwrap();
-  let curve_order = U256::from_dec_str("21888242871839275222246405745257275088548364400416034343698204186575808495617").unwrap();
-  let G = ECPoint::new(U256::from(1), U256::from(2));
-  
-  let bn128 = Curve::new(a, b, field_modulus, curve_order, G);
 
-  bn128
+  Curve::new(a, b, BN128_FIELD_MODULUS, BN128_CURVE_ORDER, BN128_G)
 }
 
 /// Secp256k1 Elliptical Curve
+#[allow(non_snake_case)]
 pub fn Secp256k1() -> Curve {
   let a: U256 = U256::from(0_u32);
   let b: U256 = U256::from(7_u32);
-    
-  let field_modulus: U256 = U256::from_str_radix("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F", 16).unwrap(); // p = 2^256 −2^32 −977
-  let curve_order = U256::from_str_radix("0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141", 16).unwrap();
-  let G = ECPoint::new(U256::from_dec_str("55066263022277343669578718895168534326250603453777594175500187360389116729240").unwrap(), 
-                    U256::from_dec_str("32670510020758816978083085130507043184471273380659243275938904335757337482424").unwrap());
-   
-  let secp256k1 = Curve::new(a, b, field_modulus, curve_order, G);
-
-  secp256k1
-}
\ No newline at end of file
+
+  Curve::new(a, b, SECP256K1_FIELD_MODULUS, SECP256K1_CURVE_ORDER, SECP256K1_G)
+}
+
+/// P-256 (secp256r1) Elliptical Curve, `y^2 = x^3 - 3x + b`
+#[allow(non_snake_case)]
+pub fn P256() -> Curve {
+  let a = NumberUnderMod::from_signed(-3, P256_FIELD_MODULUS);
+  let b = NumberUnderMod::new(P256_B, P256_FIELD_MODULUS);
+
+  Curve::from_coefficients(&a, &b, P256_CURVE_ORDER, P256_G).expect("a and b share the P-256 field modulus")
+}
@@ -0,0 +1,74 @@
+use primitive_types::U256;
+
+use super::{ECPoint, EllipticCurve};
+use crate::mod_math::ModMath;
+
+/// `EdwardsCurve` is a twisted Edwards curve of form
+/// a x^2 + y^2 = 1 + d x^2 y^2 mod(p)
+///
+/// The identity is `(0, 1)` and the negation of `(x, y)` is `(-x, y)`. The addition law is
+/// complete when `a` is a square and `d` is not, as for Ed25519: it needs no special cases
+/// for doubling or the identity.
+///
+/// # Examples
+///
+/// ```
+/// use modular_math::curves::{ECPoint, EdwardsCurve, EllipticCurve};
+/// use primitive_types::U256;
+///
+/// // x^2 + y^2 = 1 + 2 x^2 y^2 mod 13
+/// let curve = EdwardsCurve::new(U256::from(1), U256::from(2), U256::from(13));
+/// let p = ECPoint::new(U256::from(1), U256::from(0));
+/// assert!(curve.is_on_curve(&p));
+/// assert_eq!(curve.scalar_mul(&U256::from(4), &p), curve.identity());
+/// ```
+pub struct EdwardsCurve {
+  pub a: U256,
+  pub d: U256,
+  pub field_modulus: U256,
+  math: ModMath,
+}
+
+impl EdwardsCurve {
+  /// Creates a new twisted Edwards curve, reducing `a` and `d` modulo the field modulus.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the field modulus is zero.
+  pub fn new(a: U256, d: U256, field_modulus: U256) -> Self {
+    assert!(!field_modulus.is_zero(), "Modulus Cannot be Zero");
+    let math = ModMath::new(field_modulus);
+    Self { a: math.reduce(a), d: math.reduce(d), field_modulus, math }
+  }
+}
+
+impl EllipticCurve for EdwardsCurve {
+  type Point = ECPoint;
+
+  fn identity(&self) -> ECPoint {
+    ECPoint::new(U256::zero(), U256::one())
+  }
+
+  fn is_on_curve(&self, p: &ECPoint) -> bool {
+    let m = &self.math;
+    let xx = m.square(p.x);
+    let yy = m.square(p.y);
+    let lhs = m.add(m.mul(self.a, xx), yy);
+    let rhs = m.add(U256::one(), m.mul(self.d, m.mul(xx, yy)));
+    lhs == rhs
+  }
+
+  fn add_points(&self, p: &ECPoint, q: &ECPoint) -> ECPoint {
+    let m = &self.math;
+    let x1x2 = m.mul(p.x, q.x);
+    let y1y2 = m.mul(p.y, q.y);
+    let dxy = m.mul(self.d, m.mul(x1x2, y1y2));
+    let x = m.div(m.add(m.mul(p.x, q.y), m.mul(p.y, q.x)), m.add(U256::one(), dxy));
+    let y = m.div(m.sub(y1y2, m.mul(self.a, x1x2)), m.sub(U256::one(), dxy));
+    ECPoint::new(x, y)
+  }
+
+  fn negate(&self, p: &ECPoint) -> ECPoint {
+    ECPoint::new(self.math.add_inv(p.x), self.math.reduce(p.y))
+  }
+}
@@ -0,0 +1,64 @@
+use primitive_types::U256;
+
+use super::{Curve, ECPoint};
+
+/// `EllipticCurve` is the point arithmetic shared by every curve model in this crate:
+/// short Weierstrass (`Curve`), twisted Edwards (`EdwardsCurve`) and Montgomery
+/// (`MontgomeryCurve`).
+///
+/// It lets protocols that only add and multiply points, such as Diffie-Hellman, be written
+/// once for all three. Each model has its own representation of the identity, so points
+/// are an associated type.
+pub trait EllipticCurve {
+  /// Points of the curve
+  type Point: Copy + PartialEq + std::fmt::Debug;
+
+  /// Returns the identity of the curve group.
+  fn identity(&self) -> Self::Point;
+
+  /// Checks if the point satisfies the curve equation.
+  fn is_on_curve(&self, p: &Self::Point) -> bool;
+
+  /// Adds two points of the curve.
+  fn add_points(&self, p: &Self::Point, q: &Self::Point) -> Self::Point;
+
+  /// Returns the inverse of the point, so that `p + negate(p)` is the identity.
+  fn negate(&self, p: &Self::Point) -> Self::Point;
+
+  /// Multiplies the point by `k`. The default is a left-to-right double-and-add over
+  /// `add_points`.
+  fn scalar_mul(&self, k: &U256, p: &Self::Point) -> Self::Point {
+    let mut result = self.identity();
+    for i in (0..k.bits()).rev() {
+      result = self.add_points(&result, &result);
+      if k.bit(i) {
+        result = self.add_points(&result, p);
+      }
+    }
+    result
+  }
+}
+
+impl EllipticCurve for Curve {
+  type Point = ECPoint;
+
+  fn identity(&self) -> ECPoint {
+    ECPoint::identity()
+  }
+
+  fn is_on_curve(&self, p: &ECPoint) -> bool {
+    p.is_identity() || Curve::is_on_curve(self, p)
+  }
+
+  fn add_points(&self, p: &ECPoint, q: &ECPoint) -> ECPoint {
+    Curve::add_points(self, p, q)
+  }
+
+  fn negate(&self, p: &ECPoint) -> ECPoint {
+    Curve::negate(self, p)
+  }
+
+  fn scalar_mul(&self, k: &U256, p: &ECPoint) -> ECPoint {
+    self.point_multiplication_scalar(*k, *p)
+  }
+}
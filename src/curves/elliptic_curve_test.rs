@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+  use crate::curves::{ECPoint, EdwardsCurve, EllipticCurve, MontgomeryCurve, Secp256k1};
+  use primitive_types::U256;
+
+  fn dec(s: &str) -> U256 {
+    U256::from_dec_str(s).unwrap()
+  }
+
+  fn p25519() -> U256 {
+    (U256::one() << 255) - U256::from(19)
+  }
+
+  // Ed25519: -x^2 + y^2 = 1 - (121665 / 121666) x^2 y^2
+  fn ed25519() -> (EdwardsCurve, ECPoint) {
+    let p = p25519();
+    let d = dec("37095705934669439343138083508754565189542113879843219016388785533085940283555");
+    let base = ECPoint::new(
+      dec("15112221349535400772501151409588531511454012693041857206046113283949847762202"),
+      dec("46316835694926478169428394003475163141307993866256225615783033603165251855960"),
+    );
+    (EdwardsCurve::new(p - U256::one(), d, p), base)
+  }
+
+  // Curve25519: y^2 = x^3 + 486662 x^2 + x
+  fn curve25519() -> (MontgomeryCurve, Option<ECPoint>) {
+    let base = ECPoint::new(
+      U256::from(9),
+      dec("14781619447589544791020593568409986887264606134616475288964881837755586237401"),
+    );
+    (MontgomeryCurve::new(U256::from(486662), U256::one(), p25519()), Some(base))
+  }
+
+  // Both parties of a Diffie-Hellman exchange end up with the same shared point
+  fn dh<C: EllipticCurve>(curve: &C, base: &C::Point) {
+    assert!(curve.is_on_curve(base));
+    let alice = dec("80985214324875920582367420965301937826539752350987256132078521356287352090");
+    let bob = U256::from(0x5eed_1234_abcd_u64);
+    let alice_public = curve.scalar_mul(&alice, base);
+    let bob_public = curve.scalar_mul(&bob, base);
+    assert!(curve.is_on_curve(&alice_public));
+    let shared = curve.scalar_mul(&alice, &bob_public);
+    assert_eq!(shared, curve.scalar_mul(&bob, &alice_public));
+    assert_ne!(shared, curve.identity());
+  }
+
+  fn group_laws<C: EllipticCurve>(curve: &C, base: &C::Point) {
+    let double = curve.add_points(base, base);
+    assert!(curve.is_on_curve(&double));
+    assert_eq!(curve.add_points(base, &curve.identity()), *base);
+    assert_eq!(curve.add_points(&curve.identity(), base), *base);
+    assert_eq!(curve.add_points(base, &curve.negate(base)), curve.identity());
+    assert_eq!(curve.scalar_mul(&U256::zero(), base), curve.identity());
+    assert_eq!(curve.scalar_mul(&U256::from(3), base), curve.add_points(&double, base));
+  }
+
+  #[test]
+  fn test_generic_dh() {
+    let secp256k1 = Secp256k1();
+    dh(&secp256k1, &secp256k1.G);
+    let (ed25519, base) = ed25519();
+    dh(&ed25519, &base);
+    let (curve25519, base) = curve25519();
+    dh(&curve25519, &base);
+  }
+
+  #[test]
+  fn test_group_laws() {
+    let secp256k1 = Secp256k1();
+    group_laws(&secp256k1, &secp256k1.G);
+    let (ed25519, base) = ed25519();
+    group_laws(&ed25519, &base);
+    let (curve25519, base) = curve25519();
+    group_laws(&curve25519, &base);
+  }
+
+  #[test]
+  fn test_prime_order_subgroups() {
+    let order = (U256::one() << 252) + dec("27742317777372353535851937790883648493");
+    let (ed25519, base) = ed25519();
+    assert_eq!(ed25519.scalar_mul(&order, &base), ed25519.identity());
+    let (curve25519, base) = curve25519();
+    assert_eq!(curve25519.scalar_mul(&order, &base), None);
+    assert!(!ed25519.is_on_curve(&ECPoint::new(U256::one(), U256::one())));
+    assert!(!curve25519.is_on_curve(&Some(ECPoint::new(U256::one(), U256::one()))));
+  }
+}
@@ -1,120 +1,500 @@
-use primitive_types::U256;
-use rand::{rngs::OsRng, RngCore};
+use std::cmp::Ordering;
+use std::sync::OnceLock;
 
-use crate::mod_math::{ModMath, IntoU256};
+use primitive_types::{U256, U512};
+use crate::random::RandomSource;
+
+use crate::mod_math::{FieldInt, FieldMath, ModMath, RootConvention};
+use crate::number_mod::{ConvertPolicy, NumModError, NumberUnderMod};
+use super::CurvePoint;
+use super::glv::GlvParams;
+use crate::util::fingerprint;
+#[cfg(feature = "instrumentation")]
+use crate::mod_math::OpCounters;
 
 /// `ECPoint` represents a point on an elliptic curve.
 ///
-/// This struct provides methods for creating a new point and checking
-/// if two points are equal.
+/// An `ECPoint` is a plain pair of coordinates and does not know which curve it
+/// belongs to. Use `Curve::point` to build a point that is reduced and validated
+/// against a curve. Coordinates are `U256` unless another `FieldInt` is given.
+///
+/// `==` compares the coordinates as they are stored. The curve arithmetic only returns
+/// reduced coordinates, so for its results this is equality of the points; use
+/// `Curve::eq_points` for points that may not be reduced and
+/// `JacobianPoint::eq_affine` to compare against projective coordinates.
+///
+/// Points are ordered by `x`, then by `y`, as stored. This is a lexicographic order on
+/// the coordinates for canonical sorting, e.g. of the leaves of a Merkle tree of points;
+/// it has no geometric or group-theoretic meaning. Use `Curve::cmp_points` for points
+/// that may not be reduced.
 ///
 /// # Examples
 ///
 /// ```
+/// use modular_math::curves::ECPoint;
+/// use modular_math::mod_math::IntoU256;
+///
 /// let point1 = ECPoint::new(5.into_u256(), 7.into_u256());
 /// let point2 = ECPoint::new(5.into_u256(), 7.into_u256());
 /// assert!(point1.eq(&point2));
 /// ```
-#[derive(Clone, Copy, Debug)]
-pub struct ECPoint {
-    pub x: U256,
-    pub y: U256,
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ECPoint<T = U256> {
+    pub x: T,
+    pub y: T,
 }
 
-impl ECPoint {
+impl<T> ECPoint<T> {
 
     /// Creates a new `ECPoint` with the given x and y coordinates.
     ///
     /// # Examples
     ///
     /// ```
-    /// let point = ECPoint::new(5.into(), 7.into());
+    /// use modular_math::curves::ECPoint;
+    /// use primitive_types::U256;
+    ///
+    /// let point = ECPoint::new(U256::from(5), U256::from(7));
     /// ```
-    pub fn new(x: U256, y: U256) -> Self {
+    pub const fn new(x: T, y: T) -> Self {
         Self { x, y }
     }
+}
+
+impl<T: FieldInt> ECPoint<T> {
 
-    /// Checks if two `ECPoint`s are equal.
+    /// Returns the point at infinity, the identity of the curve group.
+    ///
+    /// It is represented as `(0, 0)`, which is not on any curve with `b != 0`
+    /// such as BN128 and secp256k1.
     ///
     /// # Examples
     ///
     /// ```
-    /// let point1 = ECPoint::new(5.into(), 7.into());
-    /// let point2 = ECPoint::new(5.into(), 7.into());
-    /// assert!(point1.eq(&point2));
+    /// use modular_math::curves::{BN128, ECPoint};
+    ///
+    /// let bn128 = BN128();
+    /// assert_eq!(bn128.add_points(&bn128.G, &ECPoint::identity()), bn128.G);
     /// ```
-    pub fn eq(&self, p: &ECPoint) -> bool {
-        self.x == p.x && self.y == p.y
+    pub fn identity() -> Self {
+        Self { x: T::zero(), y: T::zero() }
+    }
+
+    /// Checks if this is the point at infinity.
+    pub fn is_identity(&self) -> bool {
+        self.x == T::zero() && self.y == T::zero()
     }
 }
 
+impl ECPoint {
+
+    /// Encodes the point as 64 bytes: `x` followed by `y`, both big-endian.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::curves::{ECPoint, BN128};
+    ///
+    /// let bn128 = BN128();
+    /// let bytes = bn128.G.to_bytes();
+    /// assert_eq!(ECPoint::from_bytes(&bytes), Ok(bn128.G));
+    /// assert_eq!(bn128.point_from_bytes(&bytes), Ok(bn128.G));
+    /// ```
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        self.x.to_big_endian(&mut bytes[..32]);
+        self.y.to_big_endian(&mut bytes[32..]);
+        bytes
+    }
+
+    /// Formats the coordinates in decimal as `(x, y)`, see `ModMath::to_dec_string`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::curves::BN128;
+    ///
+    /// assert_eq!(BN128().G.to_dec_string(), "(1, 2)");
+    /// assert_eq!(BN128().G.to_hex_string(false), "(0x1, 0x2)");
+    /// ```
+    pub fn to_dec_string(&self) -> String {
+        format!("({}, {})", ModMath::to_dec_string(self.x), ModMath::to_dec_string(self.y))
+    }
+
+    /// Formats the coordinates in hex as `(x, y)`, see `ModMath::to_hex_string`.
+    pub fn to_hex_string(&self, fixed_width: bool) -> String {
+        format!("({}, {})", ModMath::to_hex_string(self.x, fixed_width), ModMath::to_hex_string(self.y, fixed_width))
+    }
+
+    /// Decodes a point encoded by `to_bytes` without checking it against any curve.
+    ///
+    /// Returns `CurveError::InvalidLength` unless there are exactly 64 bytes. Use
+    /// `Curve::point_from_bytes` to also check that the point is on a curve.
+    #[deny(clippy::unwrap_used, clippy::expect_used)]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, CurveError> {
+        if bytes.len() != 64 {
+            return Err(CurveError::InvalidLength);
+        }
+        Ok(Self::new(U256::from_big_endian(&bytes[..32]), U256::from_big_endian(&bytes[32..])))
+    }
+}
+
+/// Errors returned by the fallible `Curve` operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CurveError {
+    /// The field modulus is zero
+    InvalidModulus,
+    /// The point does not satisfy the curve equation
+    NotOnCurve,
+    /// An encoding does not have the expected number of bytes
+    InvalidLength,
+    /// A coordinate is not below the field modulus
+    NotCanonical,
+    /// The field has characteristic 2 or 3, where the short Weierstrass formulas divide by zero
+    UnsupportedCharacteristic,
+}
+
+impl std::fmt::Display for CurveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CurveError::InvalidModulus => write!(f, "the field modulus is zero"),
+            CurveError::NotOnCurve => write!(f, "the point does not satisfy the curve equation"),
+            CurveError::InvalidLength => write!(f, "the encoding does not have the expected number of bytes"),
+            CurveError::NotCanonical => write!(f, "a coordinate is not below the field modulus"),
+            CurveError::UnsupportedCharacteristic => write!(f, "curves over fields of characteristic 2 or 3 are not supported"),
+        }
+    }
+}
+
+impl std::error::Error for CurveError {}
+
 /// `Curve` represents a Weierstrass elliptic curve of form
 /// y^2 = x^3 + ax + b mod(p)
 ///
 /// This struct provides methods for creating a new curve and performing
 /// point addition, point doubling, and scalar multiplication on the curve.
+/// The field is `U256` by default; a `Curve<U512>` covers fields wider than 256 bits.
+/// The arithmetic is set up once for the field modulus passed to `new`, and for the
+/// curve order on the first scalar operation, so build a new curve rather than changing
+/// `field_modulus` or `curve_order` in place.
+///
+/// Field moduli 2 and 3 are not supported: the short Weierstrass form does not cover
+/// every curve in characteristic 2 or 3. In characteristic 2 the doubling slope also
+/// divides by `2y = 0`, so doubling, tripling and scalar multiplication panic.
+/// `try_new`, `checked_add_points` and `checked_scalar_mul` return
+/// `CurveError::UnsupportedCharacteristic` for these fields instead.
 ///
 /// # Examples
 ///
 /// ```
-/// let G = ECPoint::new(1.into_u256(), 1.into_u256());
-/// let curve = Curve::new(0.into(), 7.into_u256(), 11.into_u256(), 5.into_u256(), G);
+/// use modular_math::curves::{Curve, ECPoint};
+/// use modular_math::mod_math::IntoU256;
+///
+/// let G = ECPoint::new(2.into_u256(), 2.into_u256());
+/// let curve = Curve::new(0.into(), 7.into_u256(), 11.into_u256(), 12.into_u256(), G);
 /// let point = curve.scalar_multiply_generator(2.into_u256());
 /// ```
-pub struct Curve {
+#[allow(non_snake_case)]
+#[derive(Clone)]
+pub struct Curve<T: FieldInt = U256> {
   // y^2 = x^3 + ax + b mod(p)
-  a: U256,
-  b: U256,
-  pub field_modulus: U256,
-  pub curve_order: U256,
-  pub G: ECPoint // Generator Point
+  pub(crate) a: T,
+  pub(crate) b: T,
+  pub field_modulus: T,
+  pub curve_order: T,
+  pub G: ECPoint<T>, // Generator Point
+  // arithmetic modulo field_modulus, shared by all operations on the curve and by clones
+  pub(crate) math: T::Math,
+  // the GLV endomorphism from `derive_glv_parameters`, found on first use
+  pub(crate) glv: OnceLock<Option<GlvParams<T>>>,
+  // arithmetic modulo curve_order, set up on first use as `from_j_invariant` leaves it zero
+  scalars: OnceLock<T::Math>,
 }
 
-impl Curve {
+impl<T: FieldInt> Curve<T> {
 
-  pub fn new(a: U256, b: U256, field_modulus: U256, curve_order: U256, G: ECPoint) -> Self {
+  /// Creates a new curve, reducing `a`, `b` and the generator coordinates modulo the field modulus.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the field modulus is zero.
+  #[allow(non_snake_case)]
+  pub fn new(a: T, b: T, field_modulus: T, curve_order: T, G: ECPoint<T>) -> Self {
+    let mod_math = T::Math::with_modulus(field_modulus);
     Self {
-      a,
-      b,
+      a: mod_math.reduce(a),
+      b: mod_math.reduce(b),
       field_modulus,
       curve_order,
-      G
+      G: ECPoint::new(mod_math.reduce(G.x), mod_math.reduce(G.y)),
+      math: mod_math,
+      glv: OnceLock::new(),
+      scalars: OnceLock::new(),
+    }
+  }
+
+  // The arithmetic modulo the curve order, shared by the scalar operations and by clones.
+  // Panics if the curve order is zero.
+  pub(crate) fn scalar_math(&self) -> &T::Math {
+    self.scalars.get_or_init(|| T::Math::with_modulus(self.curve_order))
+  }
+
+  /// Creates a new curve like `new`, but returns an error instead of panicking for a zero
+  /// field modulus and rejects a generator that is not on the curve.
+  ///
+  /// Returns `CurveError::UnsupportedCharacteristic` for the field moduli 2 and 3.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::{Curve, CurveError, ECPoint};
+  /// use primitive_types::U256;
+  ///
+  /// let G = ECPoint::new(U256::from(1), U256::from(1));
+  /// let gf2_curve = Curve::try_new(U256::zero(), U256::one(), U256::from(2), U256::from(3), G);
+  /// assert!(matches!(gf2_curve, Err(CurveError::UnsupportedCharacteristic)));
+  /// ```
+  #[allow(non_snake_case)]
+  pub fn try_new(a: T, b: T, field_modulus: T, curve_order: T, G: ECPoint<T>) -> Result<Self, CurveError> {
+    if field_modulus == T::zero() {
+      return Err(CurveError::InvalidModulus);
+    }
+    Self::check_characteristic(field_modulus)?;
+    let curve = Self::new(a, b, field_modulus, curve_order, G);
+    if !curve.is_on_curve(&curve.G) {
+      return Err(CurveError::NotOnCurve);
+    }
+    Ok(curve)
+  }
+
+  // y^2 = x^3 + ax + b is only the general curve when 2 and 3 are invertible
+  fn check_characteristic(field_modulus: T) -> Result<(), CurveError> {
+    if field_modulus == T::from_u64(2) || field_modulus == T::from_u64(3) {
+      return Err(CurveError::UnsupportedCharacteristic);
+    }
+    Ok(())
+  }
+
+  /// Returns the number of field operations the curve arithmetic has performed so far.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::BN128;
+  ///
+  /// let bn128 = BN128();
+  /// bn128.point_doubling(&bn128.G);
+  /// assert_eq!(bn128.counters().inversions, 1);
+  /// ```
+  #[cfg(feature = "instrumentation")]
+  pub fn counters(&self) -> OpCounters {
+    self.math.counters()
+  }
+
+  /// Sets all operation counters back to zero.
+  #[cfg(feature = "instrumentation")]
+  pub fn reset_counters(&self) {
+    self.math.reset_counters()
+  }
+
+  /// Creates a point on the curve. Coordinates that are not below the field modulus are
+  /// reduced under `ConvertPolicy::Reduce` and rejected with `CurveError::NotCanonical`
+  /// under `ConvertPolicy::Strict`.
+  ///
+  /// Returns `CurveError::NotOnCurve` if the reduced point does not satisfy the curve equation.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::{BN128, CurveError};
+  /// use modular_math::number_mod::ConvertPolicy;
+  /// use primitive_types::U256;
+  ///
+  /// let bn128 = BN128();
+  /// let point = bn128.point(U256::from(1), U256::from(2), ConvertPolicy::Strict).unwrap();
+  /// assert_eq!(point, bn128.G);
+  /// let x = bn128.field_modulus + 1;
+  /// assert_eq!(bn128.point(x, U256::from(2), ConvertPolicy::Reduce), Ok(bn128.G));
+  /// assert_eq!(bn128.point(x, U256::from(2), ConvertPolicy::Strict), Err(CurveError::NotCanonical));
+  /// ```
+  pub fn point(&self, x: T, y: T, policy: ConvertPolicy) -> Result<ECPoint<T>, CurveError> {
+    if policy == ConvertPolicy::Strict && (x >= self.field_modulus || y >= self.field_modulus) {
+      return Err(CurveError::NotCanonical);
+    }
+    let mod_math = &self.math;
+    let point = ECPoint::new(mod_math.reduce(x), mod_math.reduce(y));
+    if self.is_on_curve(&point) {
+      Ok(point)
+    } else {
+      Err(CurveError::NotOnCurve)
     }
   }
 
+  /// Evaluates the right-hand side of the curve equation, `x^3 + ax + b`, at `x`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::BN128;
+  /// use primitive_types::U256;
+  ///
+  /// let bn128 = BN128();
+  /// // 1^3 + 3 = 2^2
+  /// assert_eq!(bn128.rhs(U256::one()), U256::from(4));
+  /// ```
+  pub fn rhs(&self, x: T) -> T {
+    let mod_math = &self.math;
+    let x_cubed = mod_math.mul(mod_math.square(x), x);
+    let ax = mod_math.mul(self.a, x);
+    mod_math.add(mod_math.add(x_cubed, ax), self.b)
+  }
+
+  /// Returns both sides of the curve equation at the point, `(y^2, x^3 + ax + b)` reduced
+  /// modulo the field modulus, e.g. to see how far off the curve a point is.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::{BN128, ECPoint};
+  /// use primitive_types::U256;
+  ///
+  /// let bn128 = BN128();
+  /// let (lhs, rhs) = bn128.curve_equation_sides(&ECPoint::new(U256::one(), U256::from(3)));
+  /// assert_eq!((lhs, rhs), (U256::from(9), U256::from(4)));
+  /// ```
+  pub fn curve_equation_sides(&self, p: &ECPoint<T>) -> (T, T) {
+    let p = Self::reduce_point(&self.math, p);
+    (self.math.square(p.y), self.rhs(p.x))
+  }
+
+  /// Checks if the point satisfies the curve equation, after reducing its coordinates.
+  pub fn is_on_curve(&self, p: &ECPoint<T>) -> bool {
+    let (lhs, rhs) = self.curve_equation_sides(p);
+    lhs == rhs
+  }
+
+  /// Checks every point like `is_on_curve`, returning one result per point in order.
+  ///
+  /// The checks share the field arithmetic of the curve and a single output allocation,
+  /// and the `ax` term is skipped for curves with `a = 0` such as BN128 and secp256k1.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::{BN128, ECPoint};
+  /// use primitive_types::U256;
+  ///
+  /// let bn128 = BN128();
+  /// let off_curve = ECPoint::new(U256::one(), U256::one());
+  /// assert_eq!(bn128.is_on_curve_batch(&[bn128.G, off_curve]), vec![true, false]);
+  /// ```
+  pub fn is_on_curve_batch(&self, points: &[ECPoint<T>]) -> Vec<bool> {
+    let mod_math = &self.math;
+    let a_is_zero = self.a == T::zero();
+    let mut results = Vec::with_capacity(points.len());
+    for p in points {
+      let mut rhs = mod_math.mul(mod_math.square(p.x), p.x);
+      if !a_is_zero {
+        rhs = mod_math.add(rhs, mod_math.mul(self.a, p.x));
+      }
+      results.push(mod_math.square(p.y) == mod_math.add(rhs, self.b));
+    }
+    results
+  }
+
   /// Adds two points on the curve.
   ///
-  /// If the points are equal, this method performs point doubling.
-  /// Otherwise, it performs point addition.
+  /// If the points are equal after reducing their coordinates, this method performs
+  /// point doubling. Otherwise, it performs point addition. The point at infinity is
+  /// handled by both.
   ///
   /// # Examples
   ///
   /// ```
-  /// let curve = ...; // create a curve
-  /// let p1 = ECPoint::new(5.into_u256(), 7.into_u256());
-  /// let p2 = ECPoint::new(3.into_u256(), 2.into_u256());
-  /// let result = curve.add_points(&p1, &p2);
+  /// use modular_math::curves::BN128;
+  ///
+  /// let bn128 = BN128();
+  /// let double_g = bn128.add_points(&bn128.G, &bn128.G);
+  /// let triple_g = bn128.add_points(&double_g, &bn128.G);
   /// ```
-  pub fn add_points(&self, p1: &ECPoint, p2: &ECPoint) -> ECPoint {
-    if p1.eq(p2) {
-      self.point_doubling(p1)
+  pub fn add_points(&self, p1: &ECPoint<T>, p2: &ECPoint<T>) -> ECPoint<T> {
+    self.debug_assert_valid(p1);
+    self.debug_assert_valid(p2);
+    self.unchecked_add_points(p1, p2)
+  }
+
+  /// Adds two points like `add_points`, without the debug-build check that both are on
+  /// the curve. Points that are not on the curve give meaningless results.
+  pub fn unchecked_add_points(&self, p1: &ECPoint<T>, p2: &ECPoint<T>) -> ECPoint<T> {
+    let mod_math = &self.math;
+    let p1 = Self::reduce_point(mod_math, p1);
+    let p2 = Self::reduce_point(mod_math, p2);
+    if p1 == p2 {
+      self.unchecked_point_doubling(&p1)
     } else {
-      self.point_addition(p1, p2)
+      self.unchecked_point_addition(&p1, &p2)
     }
   }
 
+  /// Adds two points like `add_points`, but returns `CurveError::NotOnCurve` if either
+  /// point is not on the curve, in release builds too. Use it for points that come from
+  /// untrusted input.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::{CurveError, ECPoint, BN128};
+  /// use primitive_types::U256;
+  ///
+  /// let bn128 = BN128();
+  /// assert!(bn128.checked_add_points(&bn128.G, &bn128.G).is_ok());
+  /// let off_curve = ECPoint::new(U256::from(1), U256::from(3));
+  /// assert_eq!(bn128.checked_add_points(&bn128.G, &off_curve), Err(CurveError::NotOnCurve));
+  /// ```
+  pub fn checked_add_points(&self, p1: &ECPoint<T>, p2: &ECPoint<T>) -> Result<ECPoint<T>, CurveError> {
+    Self::check_characteristic(self.field_modulus)?;
+    if !self.is_valid_point(p1) || !self.is_valid_point(p2) {
+      return Err(CurveError::NotOnCurve);
+    }
+    Ok(self.unchecked_add_points(p1, p2))
+  }
+
   /// Performs point addition on the curve.
   ///
+  /// The slope is computed from the reduced coordinates, so points with congruent
+  /// coordinates are treated as the same point and doubled.
+  ///
   /// # Examples
   ///
   /// ```
-  /// let curve = ...; // create a curve
-  /// let p1 = ECPoint::new(5.into_u256(), 7.into_u256());
-  /// let p2 = ECPoint::new(3.into_u256(), 2.into_u256());
-  /// let result = curve.point_addition(&p1, &p2);
+  /// use modular_math::curves::BN128;
+  ///
+  /// let bn128 = BN128();
+  /// let double_g = bn128.point_doubling(&bn128.G);
+  /// let triple_g = bn128.point_addition(&double_g, &bn128.G);
   /// ```
-  pub fn point_addition(&self, p1: &ECPoint, p2: &ECPoint) -> ECPoint {
-      let mod_math = ModMath::new(self.field_modulus);
+  pub fn point_addition(&self, p1: &ECPoint<T>, p2: &ECPoint<T>) -> ECPoint<T> {
+    self.debug_assert_valid(p1);
+    self.debug_assert_valid(p2);
+    self.unchecked_point_addition(p1, p2)
+  }
+
+  /// Performs point addition like `point_addition`, without the debug-build check that
+  /// both points are on the curve.
+  pub fn unchecked_point_addition(&self, p1: &ECPoint<T>, p2: &ECPoint<T>) -> ECPoint<T> {
+      let mod_math = &self.math;
+      let p1 = &Self::reduce_point(mod_math, p1);
+      let p2 = &Self::reduce_point(mod_math, p2);
+      if p1.is_identity() {
+        return *p2;
+      }
+      if p2.is_identity() {
+        return *p1;
+      }
+      if p1.x == p2.x {
+        // the same point, or inverses of each other
+        return if p1.y == p2.y { self.unchecked_point_doubling(p1) } else { ECPoint::identity() };
+      }
       let numerator = mod_math.sub(p2.y, p1.y);
       let denominator = mod_math.sub(p2.x, p1.x);
       let slope = mod_math.div(numerator, denominator);
@@ -126,10 +506,93 @@ impl Curve {
       let y_3_temp = mod_math.mul(slope, x_diff);
       let y_3 = mod_math.sub(y_3_temp, p1.y);
 
-      ECPoint {
+      let sum = ECPoint {
         x: x_3,
         y: y_3
-      }
+      };
+      self.debug_assert_canonical(&sum);
+      sum
+  }
+
+  /// Returns the inverse of a point under point addition, `(x, -y)`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::BN128;
+  ///
+  /// let bn128 = BN128();
+  /// let minus_g = bn128.negate(&bn128.G);
+  /// assert!(bn128.add_points(&bn128.G, &minus_g).is_identity());
+  /// ```
+  pub fn negate(&self, p: &ECPoint<T>) -> ECPoint<T> {
+    if p.is_identity() {
+      return *p;
+    }
+    let mod_math = &self.math;
+    ECPoint::new(mod_math.reduce(p.x), mod_math.sub(T::zero(), mod_math.reduce(p.y)))
+  }
+
+  /// Checks if two points are the same point of the curve, comparing their reduced
+  /// coordinates.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::{ECPoint, BN128};
+  ///
+  /// let bn128 = BN128();
+  /// let unreduced = ECPoint::new(bn128.G.x + bn128.field_modulus, bn128.G.y);
+  /// assert_ne!(unreduced, bn128.G);
+  /// assert!(bn128.eq_points(&unreduced, &bn128.G));
+  /// ```
+  pub fn eq_points(&self, p1: &ECPoint<T>, p2: &ECPoint<T>) -> bool {
+    Self::reduce_point(&self.math, p1) == Self::reduce_point(&self.math, p2)
+  }
+
+  /// Compares two points lexicographically by their reduced coordinates, `x` first and
+  /// then `y`, consistently with `eq_points`. Like `Ord` on `ECPoint` the order is only
+  /// meant for canonical sorting.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::{ECPoint, BN128};
+  /// use primitive_types::U256;
+  /// use std::cmp::Ordering;
+  ///
+  /// let bn128 = BN128();
+  /// let unreduced = ECPoint::new(bn128.G.x + bn128.field_modulus, bn128.G.y);
+  /// let other = ECPoint::new(U256::from(2), U256::zero());
+  /// assert_eq!(unreduced.cmp(&other), Ordering::Greater);
+  /// assert_eq!(bn128.cmp_points(&unreduced, &other), Ordering::Less);
+  /// ```
+  pub fn cmp_points(&self, p1: &ECPoint<T>, p2: &ECPoint<T>) -> Ordering {
+    Self::reduce_point(&self.math, p1).cmp(&Self::reduce_point(&self.math, p2))
+  }
+
+  /// Wraps a point of the curve in a `CurvePoint`, which supports the arithmetic operators.
+  pub fn wrap(&self, p: ECPoint<T>) -> CurvePoint<'_, T> {
+    CurvePoint::new(self, p)
+  }
+
+  fn reduce_point(mod_math: &T::Math, p: &ECPoint<T>) -> ECPoint<T> {
+    ECPoint::new(mod_math.reduce(p.x), mod_math.reduce(p.y))
+  }
+
+  // The point at infinity is not on the curve for b != 0 but is a valid operand
+  fn is_valid_point(&self, p: &ECPoint<T>) -> bool {
+    p.is_identity() || self.is_on_curve(p)
+  }
+
+  pub(crate) fn debug_assert_valid(&self, p: &ECPoint<T>) {
+    debug_assert!(self.is_valid_point(p), "point {:?} is not on the curve", p);
+  }
+
+  // The arithmetic accepts coordinates at or above the field modulus, but must only ever
+  // produce reduced ones
+  fn debug_assert_canonical(&self, p: &ECPoint<T>) {
+    debug_assert!(p.x < self.field_modulus && p.y < self.field_modulus, "point {:?} has coordinates that are not reduced", p);
   }
 
   /// Performs point doubling on the curve.
@@ -137,45 +600,160 @@ impl Curve {
   /// # Examples
   ///
   /// ```
-  /// let curve = ...; // create a curve
-  /// let p = ECPoint::new(5.into_u256(), 7.into_u256());
-  /// let result = curve.point_doubling(&p);
+  /// use modular_math::curves::BN128;
+  ///
+  /// let bn128 = BN128();
+  /// let double_g = bn128.point_doubling(&bn128.G);
   /// ```
-  pub fn point_doubling(&self, p: &ECPoint) -> ECPoint {
-      let mod_math = ModMath::new(self.field_modulus);
+  pub fn point_doubling(&self, p: &ECPoint<T>) -> ECPoint<T> {
+    self.debug_assert_valid(p);
+    self.unchecked_point_doubling(p)
+  }
+
+  /// Performs point doubling like `point_doubling`, without the debug-build check that
+  /// the point is on the curve.
+  pub fn unchecked_point_doubling(&self, p: &ECPoint<T>) -> ECPoint<T> {
+      let mod_math = &self.math;
+      if p.is_identity() || mod_math.reduce(p.y) == T::zero() {
+        return ECPoint::identity();
+      }
 
       let x_squared = mod_math.square(p.x);
-      let three_x_squared = mod_math.mul(x_squared, U256::from(3));
+      let three_x_squared = mod_math.mul(x_squared, T::from_u64(3));
       let numerator = mod_math.add(three_x_squared, self.a);
-      let denominator = mod_math.mul(U256::from(2), p.y);
+      let denominator = mod_math.mul(T::from_u64(2), p.y);
       let slope = mod_math.div(numerator, denominator);
 
       let slope_squared = mod_math.square(slope);
-      let two_p_x = mod_math.mul(U256::from(2), p.x);
+      let two_p_x = mod_math.mul(T::from_u64(2), p.x);
       let x_3 = mod_math.sub(slope_squared, two_p_x);
 
       let p_x_minus_x_3 = mod_math.sub(p.x, x_3);
       let slope_times_x_diff = mod_math.mul(slope, p_x_minus_x_3);
       let y_3 = mod_math.sub(slope_times_x_diff, p.y);
 
-      ECPoint {
+      let doubled = ECPoint {
         x: x_3,
         y: y_3
-      }
+      };
+      self.debug_assert_canonical(&doubled);
+      doubled
+  }
+
+  /// Computes `3P` in affine coordinates with a single field inversion, where doubling
+  /// and then adding `P` takes two.
+  ///
+  /// Both slopes, of `2P` and of `2P + P`, are written over the common denominator
+  /// `2y * D` with `D = (3x^2 + a)^2 - 12xy^2`, the numerator of `x(2P) - x`, and
+  /// inverted together.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::BN128;
+  ///
+  /// let bn128 = BN128();
+  /// let expected = bn128.point_addition(&bn128.point_doubling(&bn128.G), &bn128.G);
+  /// assert_eq!(bn128.triple(&bn128.G), expected);
+  /// ```
+  pub fn triple(&self, p: &ECPoint<T>) -> ECPoint<T> {
+    self.debug_assert_valid(p);
+    let m = &self.math;
+    if p.is_identity() {
+      return ECPoint::identity();
+    }
+    let (x, y) = (m.reduce(p.x), m.reduce(p.y));
+    if y == T::zero() {
+      // a point of order 2, so 3P = P
+      return ECPoint::new(x, y);
+    }
+
+    let two_y = m.add(y, y);
+    let four_y_squared = m.square(two_y);
+    let c = m.add(m.mul(m.square(x), T::from_u64(3)), self.a);
+    let d = m.sub(m.square(c), m.mul(m.mul(T::from_u64(3), x), four_y_squared));
+    if d == T::zero() {
+      // 2P = -P, so P has order 3
+      return ECPoint::identity();
+    }
+
+    // lambda1 = c / 2y and lambda2 = -(c * d + 16y^4) / (2y * d)
+    let inverse = m.div(T::one(), m.mul(two_y, d));
+    let lambda1 = m.mul(m.mul(c, d), inverse);
+    let lambda2 = m.sub(T::zero(), m.mul(m.add(m.mul(c, d), m.square(four_y_squared)), inverse));
+    // x3 = lambda2^2 - x - x(2P), with x(2P) = lambda1^2 - 2x
+    let x3 = m.add(m.sub(m.square(lambda2), m.square(lambda1)), x);
+    let y3 = m.sub(m.mul(lambda2, m.sub(x, x3)), y);
+
+    let tripled = ECPoint::new(x3, y3);
+    self.debug_assert_canonical(&tripled);
+    tripled
   }
 
   /// Performs scalar multiplication of a point on the curve with the generator
   ///
+  /// `curve_order` is the order of the generator, so both a zero scalar and the curve order
+  /// give the point at infinity.
+  ///
+  /// The scalar is recoded in non-adjacent form, whose digits are -1, 0 or 1 with no two
+  /// adjacent non-zero digits, so about a third of the steps add `G` or `-G` instead of
+  /// half of them adding `G`.
+  ///
   /// # Examples
   ///
   /// ```
-  /// let curve = ...; // create a curve
-  /// let scalar = 2.into_u256();
-  /// let starting_point = ECPoint::new(5.into_u256(), 7.into_u256());
-  /// let result = curve.point_multiplication_scalar(scalar, starting_point);
+  /// use modular_math::curves::BN128;
+  /// use modular_math::mod_math::IntoU256;
+  ///
+  /// let bn128 = BN128();
+  /// let result = bn128.scalar_multiply_generator(2.into_u256());
+  /// assert!(bn128.scalar_multiply_generator(bn128.curve_order).is_identity());
   /// ```
-  pub fn scalar_multiply_generator(&self, scalar: U256) -> ECPoint {
-    self.point_multiplication_scalar(scalar, self.G)
+  pub fn scalar_multiply_generator(&self, scalar: T) -> ECPoint<T> {
+    if scalar == self.curve_order {
+      return ECPoint::identity();
+    }
+    let g = self.G;
+    let minus_g = self.negate(&g);
+    let mut r: Option<ECPoint<T>> = None;
+    for digit in Self::naf(scalar).into_iter().rev() {
+      r = r.map(|r| self.unchecked_point_doubling(&r));
+      let term = match digit {
+        1 => g,
+        -1 => minus_g,
+        _ => continue,
+      };
+      r = Some(match r {
+        Some(r) => self.unchecked_add_points(&r, &term),
+        None => term,
+      });
+    }
+    r.unwrap_or(ECPoint::identity())
+  }
+
+  // Non-adjacent form of the scalar, least significant digit first. A digit is non-zero
+  // exactly when the remaining scalar is odd, and then it is 1 or -1 to leave a multiple of 4.
+  fn naf(scalar: T) -> Vec<i8> {
+    let bits = scalar.bits();
+    let bit = |i: usize| i < bits && scalar.bit(i);
+    let mut digits = Vec::with_capacity(bits + 1);
+    let mut carry = false;
+    for i in 0..=bits {
+      let digit = match (bit(i), carry) {
+        (false, false) => 0,
+        (true, true) => 0, // carry stays set
+        _ if bit(i + 1) => {
+          carry = true;
+          -1
+        }
+        _ => {
+          carry = false;
+          1
+        }
+      };
+      digits.push(digit);
+    }
+    digits
   }
 
   /// Performs scalar multiplication of a point on the curve.
@@ -183,25 +761,385 @@ impl Curve {
   /// # Examples
   ///
   /// ```
-  /// let curve = ...; // create a curve
-  /// let scalar = 2.into_u256();
-  /// let starting_point = ECPoint::new(5.into_u256(), 7.into_u256());
-  /// let result = curve.point_multiplication_scalar(scalar, starting_point);
+  /// use modular_math::curves::BN128;
+  /// use modular_math::mod_math::IntoU256;
+  ///
+  /// let bn128 = BN128();
+  /// let result = bn128.point_multiplication_scalar(2.into_u256(), bn128.G);
+  /// ```
+  pub fn point_multiplication_scalar(&self, scalar: T, starting_point: ECPoint<T>) -> ECPoint<T> {
+    // every intermediate point is on the curve if the starting point is
+    self.debug_assert_valid(&starting_point);
+    self.unchecked_point_multiplication_scalar(scalar, starting_point)
+  }
+
+  /// Performs scalar multiplication like `point_multiplication_scalar`, but returns
+  /// `CurveError::NotOnCurve` if the point is not on the curve, in release builds too.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::{CurveError, ECPoint, BN128};
+  /// use primitive_types::U256;
+  ///
+  /// let bn128 = BN128();
+  /// assert_eq!(bn128.checked_scalar_mul(U256::from(2), bn128.G), Ok(bn128.point_doubling(&bn128.G)));
+  /// let off_curve = ECPoint::new(U256::from(1), U256::from(3));
+  /// assert_eq!(bn128.checked_scalar_mul(U256::from(2), off_curve), Err(CurveError::NotOnCurve));
+  /// ```
+  pub fn checked_scalar_mul(&self, scalar: T, point: ECPoint<T>) -> Result<ECPoint<T>, CurveError> {
+    Self::check_characteristic(self.field_modulus)?;
+    if !self.is_valid_point(&point) {
+      return Err(CurveError::NotOnCurve);
+    }
+    Ok(self.unchecked_point_multiplication_scalar(scalar, point))
+  }
+
+  /// Performs scalar multiplication like `point_multiplication_scalar`, without the
+  /// debug-build check that the point is on the curve.
+  pub fn unchecked_point_multiplication_scalar(&self, scalar: T, starting_point: ECPoint<T>) -> ECPoint<T> {
+    if scalar == T::zero() {
+      return ECPoint::identity();
+    }
+    let mut r: Option<ECPoint<T>> = None;
+    let mut a = starting_point;
+
+    for i in 0..scalar.bits() {
+      if scalar.bit(i) {
+        r = Some(match r {
+          Some(r) => self.unchecked_add_points(&r, &a),
+          None => a,
+        });
+      }
+      a = self.unchecked_point_doubling(&a);
+    }
+
+    r.unwrap_or(ECPoint::identity())
+  }
+}
+
+impl Curve {
+
+  /// Creates a new curve from signed coefficients, mapping a negative `a` or `b` to its
+  /// representative modulo the field modulus. For example `a = -3` becomes `p - 3`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the field modulus is zero.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::{Curve, ECPoint};
+  /// use primitive_types::U256;
+  ///
+  /// // y^2 = x^3 - x + 1 over GF(11)
+  /// let G = ECPoint::new(U256::from(0), U256::from(1));
+  /// let curve = Curve::new_signed(-1, 1, U256::from(11), U256::from(10), G);
+  /// assert!(curve.is_on_curve(&curve.G));
+  /// ```
+  #[allow(non_snake_case)]
+  pub fn new_signed(a: i64, b: i64, field_modulus: U256, curve_order: U256, G: ECPoint) -> Self {
+    let a = NumberUnderMod::from_signed(a, field_modulus);
+    let b = NumberUnderMod::from_signed(b, field_modulus);
+    Self::new(a.value(), b.value(), field_modulus, curve_order, G)
+  }
+
+  /// Creates a new curve from coefficients given as field elements, taking the field
+  /// modulus from them. Combined with `NumberUnderMod::from_signed` this covers curves with
+  /// a small negative `a` and a full-width `b`.
+  ///
+  /// Returns `NumModError::ModulusMismatch` if `a` and `b` are under different moduli.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::{Curve, ECPoint};
+  /// use modular_math::number_mod::{NumModError, NumberUnderMod};
+  /// use primitive_types::U256;
+  ///
+  /// let G = ECPoint::new(U256::from(0), U256::from(1));
+  /// let a = NumberUnderMod::from_signed(-1, 11);
+  /// let b = NumberUnderMod::new(1, 11);
+  /// let curve = Curve::from_coefficients(&a, &b, U256::from(10), G).unwrap();
+  /// assert!(curve.is_on_curve(&curve.G));
+  ///
+  /// let b = NumberUnderMod::new(1, 13);
+  /// assert_eq!(Curve::from_coefficients(&a, &b, U256::from(10), G).err(), Some(NumModError::ModulusMismatch));
+  /// ```
+  #[allow(non_snake_case)]
+  pub fn from_coefficients(
+    a: &NumberUnderMod,
+    b: &NumberUnderMod,
+    curve_order: U256,
+    G: ECPoint,
+  ) -> Result<Self, NumModError> {
+    if a.modulus() != b.modulus() {
+      return Err(NumModError::ModulusMismatch);
+    }
+    Ok(Self::new(a.value(), b.value(), a.modulus(), curve_order, G))
+  }
+
+  /// Creates a curve with the j-invariant `j` over the field, for generating test curves.
+  ///
+  /// Uses `y^2 = x^3 + 1` for `j = 0`, `y^2 = x^3 + x` for `j = 1728`, and otherwise
+  /// `a = 3k` and `b = 2k` with `k = j / (1728 - j)`, which gives
+  /// `1728 * 4a^3 / (4a^3 + 27b^2) = 1728k / (k + 1) = j`. Any curve with the same
+  /// j-invariant is a twist of this one.
+  ///
+  /// Counting the points is out of scope, so `curve_order` is zero and `G` is the
+  /// identity; find points with `lift_x`, and build a curve with its order through
+  /// `Curve::new` before using the scalar methods.
+  ///
+  /// For `j = 1728`, `b = 0` puts the point `(0, 0)` of order 2 on the curve, and
+  /// `ECPoint` stores the identity as `(0, 0)` as well, so the arithmetic reads that
+  /// point as the identity.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the field modulus is zero, or not prime and sharing a factor with `1728 - j`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::{Curve, ECPoint};
+  /// use primitive_types::U256;
+  ///
+  /// // y^2 = x^3 + 1, the j = 0 family of secp256k1 and BN128
+  /// let curve = Curve::from_j_invariant(U256::zero(), U256::from(101));
+  /// assert!(curve.is_on_curve(&ECPoint::new(U256::from(0), U256::from(1))));
+  /// ```
+  pub fn from_j_invariant(j: U256, field_modulus: U256) -> Self {
+    let math = ModMath::new(field_modulus);
+    let j = math.reduce(j);
+    let (a, b) = if j.is_zero() {
+      (U256::zero(), U256::one())
+    } else if j == math.reduce(1728) {
+      (U256::one(), U256::zero())
+    } else {
+      let k = math.div(j, math.sub(1728, j));
+      (math.mul(3, k), math.mul(2, k))
+    };
+    Self::new(a, b, field_modulus, U256::zero(), ECPoint::identity())
+  }
+
+  /// Returns the discriminant `-16(4a^3 + 27b^2)` modulo the field modulus.
+  ///
+  /// The curve is singular, i.e. not an elliptic curve, exactly when it is zero.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::{toy, Curve, ECPoint};
+  /// use primitive_types::U256;
+  ///
+  /// // y^2 = x^3 + 2x + 2 over GF(17)
+  /// assert_eq!(toy::curve_17().discriminant(), U256::from(4));
+  /// // y^2 = x^3 has a cusp at the origin
+  /// let cusp = Curve::new(U256::zero(), U256::zero(), U256::from(17), U256::zero(), ECPoint::identity());
+  /// assert!(cusp.discriminant().is_zero());
+  /// ```
+  pub fn discriminant(&self) -> U256 {
+    let math = &self.math;
+    let sum = math.add(math.mul(4, math.cube(self.a)), math.mul(27, math.square(self.b)));
+    math.mul(math.add_inv(16), sum)
+  }
+
+  /// Returns the j-invariant `-1728 (4a)^3 / discriminant` modulo the field modulus.
+  ///
+  /// Two curves over the same field have the same j-invariant exactly when they are
+  /// isomorphic over its algebraic closure; `from_j_invariant` goes the other way. Curves
+  /// with `a = 0`, as secp256k1 and BN128, have the j-invariant 0.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the curve is singular, i.e. the discriminant is zero, or if the field
+  /// modulus is not prime and shares a factor with it.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::{toy, Curve, Secp256k1};
+  /// use primitive_types::U256;
+  ///
+  /// assert!(Secp256k1().j_invariant().is_zero());
+  /// assert_eq!(toy::curve_17().j_invariant(), U256::from(3));
+  /// let p = U256::from(10007);
+  /// assert_eq!(Curve::from_j_invariant(U256::from(8000), p).j_invariant(), U256::from(8000));
+  /// ```
+  pub fn j_invariant(&self) -> U256 {
+    let math = &self.math;
+    let numerator = math.mul(math.add_inv(1728), math.cube(math.mul(4, self.a)));
+    math.div(numerator, self.discriminant())
+  }
+
+  /// Returns a fingerprint of the curve parameters `a`, `b`, the field modulus, the curve
+  /// order and the generator, for binding protocol transcripts to the exact group.
+  ///
+  /// The fingerprint is a fixed mix of a domain tag and the parameters, stable across
+  /// platforms and versions. It is not a cryptographic hash.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::{Secp256k1, BN128};
+  ///
+  /// assert_ne!(BN128().fingerprint(), Secp256k1().fingerprint());
   /// ```
-  pub fn point_multiplication_scalar(&self, scalar: U256, starting_point: ECPoint) -> ECPoint {
-    let mut r = ECPoint {x: U256::zero(), y: U256::zero()};
-    let mut a = starting_point.clone();
-    let mut current_scalar = scalar;
+  pub fn fingerprint(&self) -> [u8; 32] {
+    let parameters = [self.a, self.b, self.field_modulus, self.curve_order, self.G.x, self.G.y];
+    fingerprint("modular_math/Curve", &parameters)
+  }
 
-    while current_scalar > U256::zero() {
-      
-      if current_scalar % 2 == U256::one() {
-        r = self.point_addition(&r, &a);
+  /// Maps 64 bytes, e.g. the output of a 512-bit hash, to a scalar in `[0, curve_order)`.
+  ///
+  /// The bytes are read as a big-endian 512-bit integer and reduced modulo the curve order.
+  /// Reducing twice the width of the order keeps the bias negligible, unlike reducing a
+  /// 256-bit value, which favours small scalars when the order is well below `2^256`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::Secp256k1;
+  ///
+  /// let secp256k1 = Secp256k1();
+  /// let challenge = secp256k1.hash_to_scalar(&[0xab; 64]);
+  /// assert!(challenge < secp256k1.curve_order);
+  /// ```
+  pub fn hash_to_scalar(&self, bytes: &[u8; 64]) -> U256 {
+    self.scalar_math().reduce_wide(U512::from_big_endian(bytes))
+  }
+
+  /// Reduces an integer to a scalar in `[0, curve_order)`.
+  ///
+  /// This is a plain `x % curve_order`; use `hash_to_scalar` or `random_scalar` to get
+  /// uniformly distributed scalars.
+  pub fn reduce_to_scalar(&self, x: U256) -> U256 {
+    self.scalar_math().reduce(x)
+  }
+
+  /// Returns the order of the subgroup generated by `p`: the smallest `d > 0` with
+  /// `d * p` the point at infinity, which divides `curve_order`.
+  ///
+  /// The generator gives `curve_order` and the point at infinity gives 1. A prime
+  /// `curve_order` is not factored; otherwise it is factored with
+  /// `ModMath::prime_factors`, which is only practical when all its prime factors but
+  /// the largest are small.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `curve_order * p` is not the point at infinity, i.e. `p` is not in the
+  /// subgroup of order `curve_order`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::{Curve, ECPoint};
+  /// use primitive_types::U256;
+  ///
+  /// // y^2 = x^3 + 7 over GF(11) has 12 points, generated by (4, 4)
+  /// let curve = Curve::new(U256::zero(), U256::from(7), U256::from(11), U256::from(12), ECPoint::new(U256::from(4), U256::from(4)));
+  /// assert_eq!(curve.subgroup_order(&curve.G), U256::from(12));
+  /// assert_eq!(curve.subgroup_order(&ECPoint::new(U256::from(5), U256::zero())), U256::from(2));
+  /// ```
+  pub fn subgroup_order(&self, p: &ECPoint) -> U256 {
+    let is_identity = |k: U256| self.point_multiplication_scalar(k, *p).is_identity();
+    assert!(is_identity(self.curve_order), "{:?} is not in the subgroup of order {}", p, self.curve_order);
+    if p.is_identity() {
+      return U256::one();
+    }
+    if ModMath::is_probable_prime(self.curve_order) {
+      return self.curve_order;
+    }
+    // strip each prime factor for as long as the smaller multiple still gives the identity
+    let mut order = self.curve_order;
+    for (prime, _) in ModMath::prime_factors(self.curve_order) {
+      while order % prime == U256::zero() && is_identity(order / prime) {
+        order /= prime;
       }
-      a = self.point_doubling(&a);
-      current_scalar = current_scalar / U256::from(2);
     }
+    order
+  }
+
+  /// Decodes a point encoded by `ECPoint::to_bytes` and checks that it is on the curve,
+  /// like `point` with `ConvertPolicy::Strict`. The input may be untrusted: every invalid
+  /// encoding is rejected with an error, and none is silently reduced.
+  ///
+  /// Returns `CurveError::InvalidLength` unless there are exactly 64 bytes,
+  /// `CurveError::NotCanonical` if a coordinate is not below the field modulus and
+  /// `CurveError::NotOnCurve` if the point is not on the curve.
+  #[deny(clippy::unwrap_used, clippy::expect_used)]
+  pub fn point_from_bytes(&self, bytes: &[u8]) -> Result<ECPoint, CurveError> {
+    let point = ECPoint::from_bytes(bytes)?;
+    self.point(point.x, point.y, ConvertPolicy::Strict)
+  }
+
+  /// Finds the point with the given `x` coordinate whose `y` follows the convention,
+  /// e.g. to decompress a point stored as `x` and the parity of `y`.
+  ///
+  /// Returns `None` if no point on the curve has this `x` coordinate.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::BN128;
+  /// use modular_math::mod_math::RootConvention;
+  /// use primitive_types::U256;
+  ///
+  /// let bn128 = BN128();
+  /// assert_eq!(bn128.lift_x(U256::from(1), RootConvention::EvenY), Some(bn128.G));
+  /// ```
+  pub fn lift_x(&self, x: U256, convention: RootConvention) -> Option<ECPoint> {
+    let x = self.math.reduce(x);
+    let y = self.math.sqrt_normalized(self.rhs(x), convention)?;
+    Some(ECPoint::new(x, y))
+  }
 
-    r
+  /// Returns both `y` with `(x, y)` on the curve, the smaller first, or `None` if there
+  /// is no such point. For a point of order two both are zero.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::BN128;
+  /// use primitive_types::U256;
+  ///
+  /// let bn128 = BN128();
+  /// let (y, minus_y) = bn128.y_candidates(U256::one()).unwrap();
+  /// assert_eq!(y, U256::from(2));
+  /// assert_eq!(minus_y, bn128.field_modulus - U256::from(2));
+  /// ```
+  pub fn y_candidates(&self, x: U256) -> Option<(U256, U256)> {
+    let y = self.math.sqrt_normalized(self.rhs(x), RootConvention::Smaller)?;
+    Some((y, self.math.add_inv(y)))
   }
-}
\ No newline at end of file
+
+  /// Returns a uniformly random scalar in `[1, curve_order)`, using rejection sampling.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the curve order is 0 or 1, as there is no such scalar.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::BN128;
+  /// use primitive_types::U256;
+  ///
+  /// let bn128 = BN128();
+  /// let k = bn128.random_scalar(&mut rand::thread_rng());
+  /// assert!(k > U256::zero() && k < bn128.curve_order);
+  /// ```
+  pub fn random_scalar<R: RandomSource + ?Sized>(&self, rng: &mut R) -> U256 {
+    assert!(self.curve_order > U256::one(), "Curve order must be greater than 1");
+    let bits = self.curve_order.bits();
+    loop {
+      let mut bytes = [0u8; 32];
+      rng.fill_bytes(&mut bytes);
+      let candidate = U256::from_big_endian(&bytes) >> (256 - bits);
+      if candidate != U256::zero() && candidate < self.curve_order {
+        return candidate;
+      }
+    }
+  }
+}
@@ -0,0 +1,80 @@
+use primitive_types::U256;
+
+use super::{Curve, ECPoint};
+use crate::mod_math::ModMath;
+
+/// The constants of the GLV endomorphism `phi(x, y) = (beta * x, y)` of a curve
+/// `y^2 = x^3 + b`, from `Curve::derive_glv_parameters`.
+///
+/// `beta` is a cube root of unity in the field and `lambda` a cube root of unity modulo
+/// the curve order, paired so that `phi(P) = lambda * P` for every point of the group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GlvParams<T = U256> {
+  /// The non-trivial cube root of unity modulo the field modulus
+  pub beta: T,
+  /// The non-trivial cube root of unity modulo the curve order that matches `beta`
+  pub lambda: T,
+}
+
+impl Curve {
+  /// Derives the GLV endomorphism of the curve, for splitting a scalar multiplication
+  /// into two of half the length.
+  ///
+  /// `beta` is `c^((p - 1) / 3)` for the first `c >= 2` that is not a cube modulo the
+  /// field modulus `p`, normalized to the smaller of the two non-trivial cube roots of
+  /// unity. `lambda` is found the same way modulo the curve order `n`, and whichever of
+  /// it and its square maps `G` to `(beta * G.x, G.y)` is returned. The result is
+  /// computed on the first call and cached on the curve, and clones made afterwards share
+  /// it.
+  ///
+  /// Returns None unless `a = 0`, `b != 0`, both `p` and `n` are primes congruent to 1
+  /// modulo 3, and `G` is not the point at infinity.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::{ECPoint, Secp256k1, P256};
+  /// use modular_math::mod_math::ModMath;
+  ///
+  /// let secp256k1 = Secp256k1();
+  /// let glv = secp256k1.derive_glv_parameters().unwrap();
+  /// let G = secp256k1.G;
+  /// let field = ModMath::new(secp256k1.field_modulus);
+  /// let image = ECPoint::new(field.mul(glv.beta, G.x), G.y);
+  /// assert_eq!(secp256k1.scalar_multiply_generator(glv.lambda), image);
+  ///
+  /// // y^2 = x^3 - 3x + b has no such endomorphism
+  /// assert!(P256().derive_glv_parameters().is_none());
+  /// ```
+  pub fn derive_glv_parameters(&self) -> Option<GlvParams> {
+    *self.glv.get_or_init(|| self.find_glv_parameters())
+  }
+
+  fn find_glv_parameters(&self) -> Option<GlvParams> {
+    if !self.a.is_zero() || self.b.is_zero() || self.G.is_identity() {
+      return None;
+    }
+    let scalars = self.scalar_math();
+    let beta = nontrivial_cube_root_of_unity(self.field_modulus)?;
+    let lambda = nontrivial_cube_root_of_unity(self.curve_order)?;
+
+    // beta and beta^2 are the two choices, and each pairs with one of lambda and lambda^2
+    let beta = beta.min(self.math.square(beta));
+    let image = ECPoint::new(self.math.mul(beta, self.G.x), self.G.y);
+    [lambda, scalars.square(lambda)]
+      .into_iter()
+      .find(|&lambda| self.scalar_multiply_generator(lambda) == image)
+      .map(|lambda| GlvParams { beta, lambda })
+  }
+}
+
+// A cube root of unity other than 1 under a prime modulus p = 1 (mod 3): c^((p - 1) / 3)
+// for the first non-cube c. Two thirds of the non-zero elements are non-cubes.
+fn nontrivial_cube_root_of_unity(p: U256) -> Option<U256> {
+  let math = ModMath::new(p);
+  if p % 3 != U256::one() || !math.is_prime_modulus() {
+    return None;
+  }
+  let exponent = (p - 1) / 3;
+  (2u64..).map(|c| math.exp(U256::from(c), exponent)).find(|&root| root != U256::one())
+}
@@ -0,0 +1,228 @@
+use primitive_types::U256;
+
+use super::{Curve, ECPoint};
+use crate::mod_math::{FieldInt, FieldMath};
+
+/// `JacobianPoint` is a point in Jacobian coordinates, representing the affine point
+/// `(X / Z^2, Y / Z^3)`.
+///
+/// Adding and doubling in Jacobian coordinates needs no inversions, so a scalar
+/// multiplication pays for a single inversion, in `Curve::to_affine`, instead of one
+/// per step. `Z = 0` is the point at infinity.
+#[derive(Clone, Copy, Debug)]
+pub struct JacobianPoint<T = primitive_types::U256> {
+  pub x: T,
+  pub y: T,
+  pub z: T,
+}
+
+impl<T: FieldInt> JacobianPoint<T> {
+  /// Returns the point at infinity, `(1, 1, 0)`.
+  pub fn identity() -> Self {
+    JacobianPoint { x: T::one(), y: T::one(), z: T::zero() }
+  }
+
+  /// Checks if this is the point at infinity.
+  pub fn is_identity(&self) -> bool {
+    self.z == T::zero()
+  }
+
+  /// Checks if this point represents the affine point `p`, without an inversion.
+  ///
+  /// Compares `X == x * Z^2` and `Y == y * Z^3` under `math`, which must be the field
+  /// of the curve both points lie on.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::BN128;
+  /// use modular_math::mod_math::ModMath;
+  /// use primitive_types::U256;
+  ///
+  /// let bn128 = BN128();
+  /// let math = ModMath::new(bn128.field_modulus);
+  /// let p = bn128.jacobian_scalar_mul(U256::from(5), &bn128.G);
+  /// let expected = bn128.scalar_multiply_generator(U256::from(5));
+  /// assert!(p.eq_affine(&expected, &math));
+  /// ```
+  pub fn eq_affine(&self, p: &ECPoint<T>, math: &T::Math) -> bool {
+    if self.is_identity() || p.is_identity() {
+      return self.is_identity() && p.is_identity();
+    }
+    let zz = math.square(self.z);
+    let zzz = math.mul(zz, self.z);
+    math.reduce(self.x) == math.mul(p.x, zz) && math.reduce(self.y) == math.mul(p.y, zzz)
+  }
+
+  /// Checks if two points in Jacobian coordinates represent the same affine point,
+  /// without an inversion.
+  ///
+  /// Compares `X1 * Z2^2 == X2 * Z1^2` and `Y1 * Z2^3 == Y2 * Z1^3` under `math`.
+  pub fn eq_jacobian(&self, other: &JacobianPoint<T>, math: &T::Math) -> bool {
+    if self.is_identity() || other.is_identity() {
+      return self.is_identity() && other.is_identity();
+    }
+    let zz1 = math.square(self.z);
+    let zz2 = math.square(other.z);
+    let zzz1 = math.mul(zz1, self.z);
+    let zzz2 = math.mul(zz2, other.z);
+    math.mul(self.x, zz2) == math.mul(other.x, zz1) && math.mul(self.y, zzz2) == math.mul(other.y, zzz1)
+  }
+}
+
+impl<T: FieldInt> Curve<T> {
+  /// Converts an affine point to Jacobian coordinates, with `Z = 1`.
+  pub fn to_jacobian(&self, p: &ECPoint<T>) -> JacobianPoint<T> {
+    if p.is_identity() {
+      return JacobianPoint::identity();
+    }
+    JacobianPoint { x: self.math.reduce(p.x), y: self.math.reduce(p.y), z: T::one() }
+  }
+
+  /// Converts a point in Jacobian coordinates back to affine coordinates, with one
+  /// inversion.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::BN128;
+  /// use primitive_types::U256;
+  ///
+  /// let bn128 = BN128();
+  /// let p = bn128.jacobian_scalar_mul(U256::from(5), &bn128.G);
+  /// assert_eq!(bn128.to_affine(&p), bn128.scalar_multiply_generator(U256::from(5)));
+  /// ```
+  pub fn to_affine(&self, p: &JacobianPoint<T>) -> ECPoint<T> {
+    if p.is_identity() {
+      return ECPoint::identity();
+    }
+    let m = &self.math;
+    let z_inv = m.div(T::one(), p.z);
+    let z_inv_squared = m.square(z_inv);
+    ECPoint::new(m.mul(p.x, z_inv_squared), m.mul(p.y, m.mul(z_inv_squared, z_inv)))
+  }
+
+  /// Doubles a point in Jacobian coordinates.
+  pub fn jacobian_double(&self, p: &JacobianPoint<T>) -> JacobianPoint<T> {
+    let m = &self.math;
+    if p.is_identity() || m.reduce(p.y) == T::zero() {
+      return JacobianPoint::identity();
+    }
+    // dbl-2007-bl
+    let xx = m.square(p.x);
+    let yy = m.square(p.y);
+    let yyyy = m.square(yy);
+    let zz = m.square(p.z);
+    let half_s = m.sub(m.sub(m.square(m.add(p.x, yy)), xx), yyyy);
+    let s = m.add(half_s, half_s);
+    let three_xx = m.add(m.add(xx, xx), xx);
+    let slope = m.add(three_xx, m.mul(self.a, m.square(zz)));
+    let x3 = m.sub(m.square(slope), m.add(s, s));
+    let eight_yyyy = m.mul(T::from_u64(8), yyyy);
+    let y3 = m.sub(m.mul(slope, m.sub(s, x3)), eight_yyyy);
+    let z3 = m.sub(m.sub(m.square(m.add(p.y, p.z)), yy), zz);
+    JacobianPoint { x: x3, y: y3, z: z3 }
+  }
+
+  /// Adds two points in Jacobian coordinates.
+  pub fn jacobian_add(&self, p1: &JacobianPoint<T>, p2: &JacobianPoint<T>) -> JacobianPoint<T> {
+    if p1.is_identity() {
+      return *p2;
+    }
+    if p2.is_identity() {
+      return *p1;
+    }
+    // add-2007-bl, without the doubled intermediate values
+    let m = &self.math;
+    let z1z1 = m.square(p1.z);
+    let z2z2 = m.square(p2.z);
+    let u1 = m.mul(p1.x, z2z2);
+    let u2 = m.mul(p2.x, z1z1);
+    let s1 = m.mul(p1.y, m.mul(p2.z, z2z2));
+    let s2 = m.mul(p2.y, m.mul(p1.z, z1z1));
+    let h = m.sub(u2, u1);
+    let r = m.sub(s2, s1);
+    if h == T::zero() {
+      // the same affine point, or inverses of each other
+      return if r == T::zero() { self.jacobian_double(p1) } else { JacobianPoint::identity() };
+    }
+    let hh = m.square(h);
+    let hhh = m.mul(h, hh);
+    let v = m.mul(u1, hh);
+    let x3 = m.sub(m.sub(m.square(r), hhh), m.add(v, v));
+    let y3 = m.sub(m.mul(r, m.sub(v, x3)), m.mul(s1, hhh));
+    let z3 = m.mul(m.mul(p1.z, p2.z), h);
+    JacobianPoint { x: x3, y: y3, z: z3 }
+  }
+
+  /// Multiplies an affine point by a scalar in Jacobian coordinates, leaving the result
+  /// in Jacobian coordinates. No inversions are performed.
+  pub fn jacobian_scalar_mul(&self, scalar: T, p: &ECPoint<T>) -> JacobianPoint<T> {
+    self.debug_assert_valid(p);
+    let mut result = JacobianPoint::identity();
+    let mut addend = self.to_jacobian(p);
+    for i in 0..scalar.bits() {
+      if scalar.bit(i) {
+        result = self.jacobian_add(&result, &addend);
+      }
+      addend = self.jacobian_double(&addend);
+    }
+    result
+  }
+}
+
+impl Curve {
+  /// Returns the multiples `[P, 2P, ..., k_max * P]` of an affine point, e.g. for the
+  /// precomputed tables of windowed scalar multiplication.
+  ///
+  /// The multiples are built in Jacobian coordinates and converted back to affine with
+  /// one inversion shared by all of them.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::BN128;
+  /// use primitive_types::U256;
+  ///
+  /// let bn128 = BN128();
+  /// let multiples = bn128.small_multiples(&bn128.G, 7);
+  /// assert_eq!(multiples.len(), 7);
+  /// assert_eq!(multiples[4], bn128.scalar_multiply_generator(U256::from(5)));
+  /// ```
+  pub fn small_multiples(&self, p: &ECPoint, k_max: usize) -> Vec<ECPoint> {
+    self.debug_assert_valid(p);
+    let base = self.to_jacobian(p);
+    let mut multiples = Vec::with_capacity(k_max);
+    let mut current = base;
+    for k in 1..=k_max {
+      if k == 2 {
+        current = self.jacobian_double(&base);
+      } else if k > 2 {
+        current = self.jacobian_add(&current, &base);
+      }
+      multiples.push(current);
+    }
+    self.batch_to_affine(&multiples)
+  }
+
+  // Converts the points to affine coordinates with one inversion for all of them
+  pub(super) fn batch_to_affine(&self, points: &[JacobianPoint]) -> Vec<ECPoint> {
+    let m = &self.math;
+    let z_values: Vec<U256> = points.iter().filter(|p| !p.is_identity()).map(|p| p.z).collect();
+    let mut z_inverses = m
+      .batch_inv(&z_values)
+      .expect("Z is non-zero and the field modulus is prime")
+      .into_iter();
+    points
+      .iter()
+      .map(|p| {
+        if p.is_identity() {
+          return ECPoint::identity();
+        }
+        let z_inv = z_inverses.next().expect("one inverse per finite point");
+        let z_inv_squared = m.square(z_inv);
+        ECPoint::new(m.mul(p.x, z_inv_squared), m.mul(p.y, m.mul(z_inv_squared, z_inv)))
+      })
+      .collect()
+  }
+}
@@ -1,6 +1,31 @@
 mod elliptical_curve;
+mod msm;
+mod curve_point;
+mod jacobian;
+mod scalar;
+mod elliptic_curve;
+mod edwards;
+mod montgomery;
+mod walk;
+mod vector_commitment;
+mod glv;
+pub mod toy;
+#[allow(clippy::module_inception)]
 mod curves;
-pub use elliptical_curve::{Curve, ECPoint};
-pub use curves::BN128;
+pub use elliptical_curve::{Curve, CurveError, ECPoint};
+pub use curves::{
+  BN128, P256, Secp256k1, BN128_CURVE_ORDER, BN128_FIELD_MODULUS, BN128_G, P256_CURVE_ORDER,
+  P256_FIELD_MODULUS, P256_G, SECP256K1_CURVE_ORDER, SECP256K1_FIELD_MODULUS, SECP256K1_G,
+};
+pub use scalar::Scalar;
+pub use curve_point::CurvePoint;
+pub use jacobian::JacobianPoint;
+pub use elliptic_curve::EllipticCurve;
+pub use edwards::EdwardsCurve;
+pub use montgomery::MontgomeryCurve;
+pub use vector_commitment::{OpeningProof, VectorCommitment};
+pub use glv::GlvParams;
 
-mod bn128_test;
\ No newline at end of file
+mod bn128_test;
+mod toy_test;
+mod elliptic_curve_test;
@@ -0,0 +1,89 @@
+use primitive_types::U256;
+
+use super::{ECPoint, EllipticCurve};
+use crate::mod_math::ModMath;
+
+/// `MontgomeryCurve` is a Montgomery curve of form
+/// B y^2 = x^3 + A x^2 + x mod(p)
+///
+/// `(0, 0)` is a point of order two on every Montgomery curve, so it cannot stand for the
+/// identity as it does for `Curve`. Points are `Option<ECPoint>` instead, with `None` the
+/// point at infinity.
+///
+/// # Examples
+///
+/// ```
+/// use modular_math::curves::{ECPoint, EllipticCurve, MontgomeryCurve};
+/// use primitive_types::U256;
+///
+/// let curve = MontgomeryCurve::new(U256::from(3), U256::from(1), U256::from(13));
+/// let p = Some(ECPoint::new(U256::zero(), U256::zero()));
+/// assert!(curve.is_on_curve(&p));
+/// assert_eq!(curve.add_points(&p, &p), curve.identity());
+/// ```
+#[allow(non_snake_case)]
+pub struct MontgomeryCurve {
+  pub A: U256,
+  pub B: U256,
+  pub field_modulus: U256,
+  math: ModMath,
+}
+
+impl MontgomeryCurve {
+  /// Creates a new Montgomery curve, reducing `A` and `B` modulo the field modulus.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the field modulus is zero.
+  #[allow(non_snake_case)]
+  pub fn new(A: U256, B: U256, field_modulus: U256) -> Self {
+    assert!(!field_modulus.is_zero(), "Modulus Cannot be Zero");
+    let math = ModMath::new(field_modulus);
+    Self { A: math.reduce(A), B: math.reduce(B), field_modulus, math }
+  }
+}
+
+impl EllipticCurve for MontgomeryCurve {
+  type Point = Option<ECPoint>;
+
+  fn identity(&self) -> Option<ECPoint> {
+    None
+  }
+
+  fn is_on_curve(&self, p: &Option<ECPoint>) -> bool {
+    let Some(p) = p else {
+      return true;
+    };
+    let m = &self.math;
+    let xx = m.square(p.x);
+    let rhs = m.add(m.add(m.mul(xx, p.x), m.mul(self.A, xx)), p.x);
+    m.mul(self.B, m.square(p.y)) == rhs
+  }
+
+  fn add_points(&self, p: &Option<ECPoint>, q: &Option<ECPoint>) -> Option<ECPoint> {
+    let (p, q) = match (p, q) {
+      (None, _) => return *q,
+      (_, None) => return *p,
+      (Some(p), Some(q)) => (*p, *q),
+    };
+    let m = &self.math;
+    let (x1, y1, x2, y2) = (m.reduce(p.x), m.reduce(p.y), m.reduce(q.x), m.reduce(q.y));
+    if x1 == x2 && m.add(y1, y2).is_zero() {
+      return None;
+    }
+    let slope = if x1 == x2 {
+      // tangent: (3 x^2 + 2 A x + 1) / (2 B y)
+      let numerator = m.add(m.add(m.mul(U256::from(3), m.square(x1)), m.mul(m.add(self.A, self.A), x1)), U256::one());
+      m.div(numerator, m.mul(m.add(self.B, self.B), y1))
+    } else {
+      m.div(m.sub(y2, y1), m.sub(x2, x1))
+    };
+    let x3 = m.sub(m.sub(m.sub(m.mul(self.B, m.square(slope)), self.A), x1), x2);
+    let y3 = m.sub(m.mul(slope, m.sub(x1, x3)), y1);
+    Some(ECPoint::new(x3, y3))
+  }
+
+  fn negate(&self, p: &Option<ECPoint>) -> Option<ECPoint> {
+    p.map(|p| ECPoint::new(self.math.reduce(p.x), self.math.add_inv(p.y)))
+  }
+}
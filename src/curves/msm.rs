@@ -0,0 +1,185 @@
+use primitive_types::U256;
+
+use super::elliptical_curve::{Curve, ECPoint};
+
+impl Curve {
+
+  /// Picks the Pippenger window (bucket width in bits) used by `msm` for `n` points.
+  ///
+  /// A window of `c` bits means `256 / c` windows of `2^c - 1` buckets each, so wider
+  /// windows pay off only once there are enough points to fill the buckets. The table
+  /// follows the usual `c ≈ ln n` heuristic, flattened to match `benches/msm.rs` on
+  /// BN128 up to 2^16 points, where the optimum is broad. Larger sizes are extrapolated.
+  ///
+  /// | points          | window |
+  /// |-----------------|--------|
+  /// | fewer than 32   | 3      |
+  /// | fewer than 128  | 5      |
+  /// | fewer than 1024 | 7      |
+  /// | fewer than 8192 | 8      |
+  /// | fewer than 2^17 | 10     |
+  /// | fewer than 2^20 | 12     |
+  /// | more            | 14     |
+  pub fn msm_window_size(n: usize) -> usize {
+    match n {
+      0..=31 => 3,
+      32..=127 => 5,
+      128..=1023 => 7,
+      1024..=8191 => 8,
+      8192..=131071 => 10,
+      131072..=1048575 => 12,
+      _ => 14,
+    }
+  }
+
+  /// Computes the multi-scalar multiplication `scalars[0] * points[0] + scalars[1] * points[1] + ...`
+  /// with Pippenger's bucket method, using the window picked by `msm_window_size`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `scalars` and `points` have different lengths.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::BN128;
+  /// use primitive_types::U256;
+  ///
+  /// let bn128 = BN128();
+  /// let double_g = bn128.point_doubling(&bn128.G);
+  /// let result = bn128.msm(&[U256::from(3), U256::from(2)], &[bn128.G, double_g]);
+  /// assert_eq!(result, bn128.scalar_multiply_generator(U256::from(7)));
+  /// ```
+  pub fn msm(&self, scalars: &[U256], points: &[ECPoint]) -> ECPoint {
+    self.msm_with_window(scalars, points, Self::msm_window_size(points.len()))
+  }
+
+  /// Computes the multi-scalar multiplication like `msm`, with an explicit window of `window` bits.
+  ///
+  /// The result does not depend on the window, only the running time does.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `scalars` and `points` have different lengths or if `window` is not in `1..=16`.
+  pub fn msm_with_window(&self, scalars: &[U256], points: &[ECPoint], window: usize) -> ECPoint {
+    assert_eq!(scalars.len(), points.len(), "msm needs as many scalars as points");
+    assert!((1..=16).contains(&window), "msm window must be between 1 and 16 bits");
+    points.iter().for_each(|p| self.debug_assert_valid(p));
+
+    let mod_math = &self.math;
+    let points: Vec<ECPoint> = points
+      .iter()
+      .map(|p| ECPoint::new(mod_math.reduce(p.x), mod_math.reduce(p.y)))
+      .collect();
+    let bits = scalars.iter().map(|k| k.bits()).max().unwrap_or(0);
+    let mask = U256::from((1u64 << window) - 1);
+
+    let mut result = ECPoint::identity();
+    for w in (0..bits.div_ceil(window)).rev() {
+      for _ in 0..window {
+        result = self.unchecked_point_doubling(&result);
+      }
+
+      let mut buckets: Vec<Vec<ECPoint>> = vec![Vec::new(); (1 << window) - 1];
+      for (k, p) in scalars.iter().zip(&points) {
+        let digit = ((*k >> (w * window)) & mask).as_usize();
+        if digit != 0 && !p.is_identity() {
+          buckets[digit - 1].push(*p);
+        }
+      }
+      self.accumulate_buckets(&mut buckets);
+      let window_sum = self.sum_buckets(&buckets, window);
+      result = self.unchecked_add_points(&result, &window_sum);
+    }
+    result
+  }
+
+  // Collapses every bucket to at most one point. Each round adds disjoint pairs across all
+  // buckets with `batch_add`, so a round costs a single inversion.
+  fn accumulate_buckets(&self, buckets: &mut [Vec<ECPoint>]) {
+    loop {
+      let mut pairs = Vec::new();
+      let mut owners = Vec::new();
+      for (i, bucket) in buckets.iter_mut().enumerate() {
+        while bucket.len() >= 2 {
+          let p = bucket.pop().unwrap();
+          let q = bucket.pop().unwrap();
+          pairs.push((p, q));
+          owners.push(i);
+        }
+      }
+      if pairs.is_empty() {
+        return;
+      }
+      for (i, sum) in owners.into_iter().zip(self.batch_add(&pairs)) {
+        buckets[i].push(sum);
+      }
+    }
+  }
+
+  // Computes sum(d * bucket[d - 1]) as sum(2^j * S_j), where S_j adds up the buckets whose
+  // digit has bit j set. The S_j are batch-added like the buckets, so only the final
+  // double-and-add over the window bits needs an inversion per step.
+  fn sum_buckets(&self, buckets: &[Vec<ECPoint>], window: usize) -> ECPoint {
+    let mut bit_sums: Vec<Vec<ECPoint>> = vec![Vec::new(); window];
+    for (i, bucket) in buckets.iter().enumerate() {
+      if let Some(p) = bucket.first() {
+        let digit = i + 1;
+        for (j, bit_sum) in bit_sums.iter_mut().enumerate() {
+          if (digit >> j) & 1 == 1 {
+            bit_sum.push(*p);
+          }
+        }
+      }
+    }
+    self.accumulate_buckets(&mut bit_sums);
+
+    let mut sum = ECPoint::identity();
+    for bit_sum in bit_sums.iter().rev() {
+      sum = self.unchecked_point_doubling(&sum);
+      if let Some(p) = bit_sum.first() {
+        sum = self.unchecked_add_points(&sum, p);
+      }
+    }
+    sum
+  }
+
+  // Adds each pair of reduced affine points, sharing one inversion between all pairs.
+  fn batch_add(&self, pairs: &[(ECPoint, ECPoint)]) -> Vec<ECPoint> {
+    let mod_math = &self.math;
+    let mut results = vec![ECPoint::identity(); pairs.len()];
+    let mut pending = Vec::new();
+    let mut numerators = Vec::new();
+    let mut denominators = Vec::new();
+
+    for (i, (p, q)) in pairs.iter().enumerate() {
+      if p.is_identity() {
+        results[i] = *q;
+      } else if q.is_identity() {
+        results[i] = *p;
+      } else if p.x != q.x {
+        pending.push(i);
+        numerators.push(mod_math.sub(q.y, p.y));
+        denominators.push(mod_math.sub(q.x, p.x));
+      } else if p.y == q.y && p.y != U256::zero() {
+        // doubling
+        pending.push(i);
+        numerators.push(mod_math.add(mod_math.mul(mod_math.square(p.x), U256::from(3)), self.a));
+        denominators.push(mod_math.add(p.y, p.y));
+      }
+      // otherwise q = -p and the sum stays at the identity
+    }
+
+    let inverses = mod_math
+      .batch_inv(&denominators)
+      .expect("denominators are non-zero in a prime field");
+    for ((i, numerator), inverse) in pending.into_iter().zip(numerators).zip(inverses) {
+      let (p, q) = pairs[i];
+      let slope = mod_math.mul(numerator, inverse);
+      let x = mod_math.sub(mod_math.sub(mod_math.square(slope), p.x), q.x);
+      let y = mod_math.sub(mod_math.mul(slope, mod_math.sub(p.x, x)), p.y);
+      results[i] = ECPoint::new(x, y);
+    }
+    results
+  }
+}
@@ -0,0 +1,120 @@
+use primitive_types::{U256, U512};
+
+use super::Curve;
+use crate::mod_math::ModMath;
+use crate::number_mod::{ConvertPolicy, NumModError, NumberUnderMod};
+
+/// `Scalar` is an integer modulo the order of a curve's generator, the curve-side
+/// counterpart of a `NumberUnderMod` in the base field.
+///
+/// A scalar shares the arithmetic modulo the curve order with the curve it came from.
+#[derive(Clone, Debug)]
+pub struct Scalar {
+  value: U256,
+  // arithmetic modulo the curve order
+  math: ModMath,
+}
+
+impl PartialEq for Scalar {
+  fn eq(&self, other: &Scalar) -> bool {
+    self.value == other.value && self.order() == other.order()
+  }
+}
+
+impl Eq for Scalar {}
+
+impl Scalar {
+  /// Creates a scalar of the curve, reducing the value modulo the curve order.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::{Scalar, BN128};
+  /// use primitive_types::U256;
+  ///
+  /// let bn128 = BN128();
+  /// let k = Scalar::new(&bn128, bn128.curve_order + U256::one());
+  /// assert_eq!(k.value(), U256::one());
+  /// ```
+  pub fn new(curve: &Curve, value: U256) -> Self {
+    let math = curve.scalar_math();
+    Scalar { value: math.reduce(value), math: math.clone() }
+  }
+
+  /// Moves an element of the curve's base field to the scalar field, with the same
+  /// policy as `NumberUnderMod::convert_to`.
+  ///
+  /// Returns `NumModError::ModulusMismatch` if the element is not in the base field.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::{Scalar, BN128};
+  /// use modular_math::number_mod::{ConvertPolicy, NumberUnderMod};
+  ///
+  /// let bn128 = BN128();
+  /// let x = NumberUnderMod::new(bn128.G.x, bn128.field_modulus);
+  /// let k = Scalar::from_field_element(&bn128, &x, ConvertPolicy::Strict).unwrap();
+  /// assert_eq!(k.value(), bn128.G.x);
+  /// ```
+  pub fn from_field_element(curve: &Curve, element: &NumberUnderMod, policy: ConvertPolicy) -> Result<Self, NumModError> {
+    if element.modulus() != curve.field_modulus {
+      return Err(NumModError::ModulusMismatch);
+    }
+    let converted = element.convert_to(curve.curve_order, policy)?;
+    Ok(Scalar { value: converted.value(), math: curve.scalar_math().clone() })
+  }
+
+  /// Returns the value of the scalar, in `[0, order)`.
+  pub fn value(&self) -> U256 {
+    self.value
+  }
+
+  /// Returns the curve order the scalar is reduced by.
+  pub fn order(&self) -> U256 {
+    self.math.modulus
+  }
+
+  /// Computes `self * a + b` modulo the curve order, with a single reduction of the
+  /// `U512` result.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the scalars belong to curves of different orders.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::{Scalar, BN128};
+  /// use primitive_types::U256;
+  ///
+  /// let bn128 = BN128();
+  /// let minus_one = Scalar::new(&bn128, bn128.curve_order - U256::one());
+  /// let three = Scalar::new(&bn128, U256::from(3));
+  /// // -1 * -1 + 3 = 4
+  /// assert_eq!(minus_one.mul_add(&minus_one, &three).value(), U256::from(4));
+  /// ```
+  pub fn mul_add(&self, a: &Scalar, b: &Scalar) -> Scalar {
+    self.assert_same_order(a);
+    self.assert_same_order(b);
+    let product = self.value.full_mul(a.value);
+    // below order^2 + order, which fits in a U512
+    let sum = product + U512::from(b.value);
+    Scalar { value: self.math.reduce_wide(sum), math: self.math.clone() }
+  }
+
+  /// Computes `self^-1 * a` modulo the curve order, or `None` if `self` is not invertible.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the scalars belong to curves of different orders.
+  pub fn inv_mul(&self, a: &Scalar) -> Option<Scalar> {
+    self.assert_same_order(a);
+    let value = self.math.mul(self.math.inv(self.value)?, a.value);
+    Some(Scalar { value, math: self.math.clone() })
+  }
+
+  fn assert_same_order(&self, other: &Scalar) {
+    assert_eq!(self.order(), other.order(), "Cannot combine scalars of different curves");
+  }
+}
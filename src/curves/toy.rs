@@ -0,0 +1,213 @@
+//! Small curves of prime order whose points can all be listed.
+//!
+//! Each curve comes with the complete list of its affine points, so group-law changes
+//! can be checked exhaustively against ground truth. The tables are generated by
+//! `enumerate_points` (see the ignored `print_point_tables` test) and checked against
+//! it by the tests; do not edit them by hand.
+
+use primitive_types::U256;
+
+use super::{Curve, ECPoint};
+use crate::mod_math::{ModMath, RootConvention};
+
+/// y^2 = x^3 + 2x + 2 over GF(17), of order 19, with generator (5, 1).
+pub fn curve_17() -> Curve {
+  toy_curve(2, 2, 17, 19, (5, 1))
+}
+
+/// y^2 = x^3 + 5 over GF(97), of order 79, with generator (1, 43).
+pub fn curve_97() -> Curve {
+  toy_curve(0, 5, 97, 79, (1, 43))
+}
+
+/// y^2 = x^3 + 11 over GF(1009), of order 967, with generator (1, 298).
+pub fn curve_1009() -> Curve {
+  toy_curve(0, 11, 1009, 967, (1, 298))
+}
+
+/// The affine points of `curve_17`, sorted by `x` and then `y`.
+pub const CURVE_17_POINTS: [(u64, u64); 18] = [
+  (0, 6), (0, 11), (3, 1), (3, 16), (5, 1), (5, 16), (6, 3), (6, 14),
+  (7, 6), (7, 11), (9, 1), (9, 16), (10, 6), (10, 11), (13, 7), (13, 10),
+  (16, 4), (16, 13),
+];
+
+/// The affine points of `curve_97`, sorted by `x` and then `y`.
+pub const CURVE_97_POINTS: [(u64, u64); 78] = [
+  (1, 43), (1, 54), (3, 41), (3, 56), (5, 18), (5, 79), (6, 30), (6, 67),
+  (8, 41), (8, 56), (10, 36), (10, 61), (11, 47), (11, 50), (14, 18), (14, 79),
+  (16, 30), (16, 67), (23, 12), (23, 85), (26, 11), (26, 86), (27, 26), (27, 71),
+  (28, 36), (28, 61), (29, 12), (29, 85), (34, 11), (34, 86), (35, 43), (35, 54),
+  (36, 2), (36, 95), (37, 11), (37, 86), (38, 13), (38, 84), (45, 12), (45, 85),
+  (46, 27), (46, 70), (55, 5), (55, 92), (57, 5), (57, 92), (58, 27), (58, 70),
+  (59, 36), (59, 61), (61, 43), (61, 54), (62, 2), (62, 95), (69, 13), (69, 84),
+  (72, 26), (72, 71), (75, 30), (75, 67), (78, 18), (78, 79), (82, 5), (82, 92),
+  (86, 41), (86, 56), (87, 13), (87, 84), (89, 47), (89, 50), (90, 27), (90, 70),
+  (94, 47), (94, 50), (95, 26), (95, 71), (96, 2), (96, 95),
+];
+
+/// The affine points of `curve_1009`, sorted by `x` and then `y`.
+pub const CURVE_1009_POINTS: [(u64, u64); 966] = [
+  (1, 298), (1, 711), (4, 264), (4, 745), (6, 338), (6, 671), (8, 98), (8, 911),
+  (9, 473), (9, 536), (10, 439), (10, 570), (11, 86), (11, 923), (16, 468), (16, 541),
+  (17, 114), (17, 895), (21, 47), (21, 962), (23, 227), (23, 782), (25, 276), (25, 733),
+  (27, 98), (27, 911), (30, 500), (30, 509), (32, 320), (32, 689), (34, 213), (34, 796),
+  (35, 292), (35, 717), (36, 430), (36, 579), (39, 306), (39, 703), (40, 202), (40, 807),
+  (41, 66), (41, 943), (42, 253), (42, 756), (50, 339), (50, 670), (53, 108), (53, 901),
+  (54, 468), (54, 541), (57, 263), (57, 746), (63, 83), (63, 926), (64, 433), (64, 576),
+  (66, 250), (66, 759), (70, 256), (70, 753), (72, 470), (72, 539), (74, 226), (74, 783),
+  (76, 393), (76, 616), (78, 86), (78, 923), (80, 360), (80, 649), (84, 230), (84, 779),
+  (87, 167), (87, 842), (97, 212), (97, 797), (99, 113), (99, 896), (101, 321), (101, 688),
+  (103, 398), (103, 611), (104, 203), (104, 806), (105, 166), (105, 843), (107, 485), (107, 524),
+  (108, 320), (108, 689), (110, 238), (110, 771), (112, 350), (112, 659), (114, 315), (114, 694),
+  (115, 172), (115, 837), (116, 142), (116, 867), (117, 417), (117, 592), (118, 214), (118, 795),
+  (119, 238), (119, 771), (120, 151), (120, 858), (121, 500), (121, 509), (122, 173), (122, 836),
+  (124, 374), (124, 635), (125, 349), (125, 660), (128, 463), (128, 546), (129, 263), (129, 746),
+  (130, 411), (130, 598), (131, 177), (131, 832), (134, 267), (134, 742), (135, 202), (135, 807),
+  (136, 78), (136, 931), (137, 230), (137, 779), (139, 88), (139, 921), (140, 192), (140, 817),
+  (142, 322), (142, 687), (146, 214), (146, 795), (147, 153), (147, 856), (151, 482), (151, 527),
+  (155, 26), (155, 983), (158, 384), (158, 625), (160, 312), (160, 697), (167, 491), (167, 518),
+  (171, 442), (171, 567), (172, 393), (172, 616), (176, 361), (176, 648), (180, 398), (180, 611),
+  (182, 497), (182, 512), (183, 29), (183, 980), (188, 411), (188, 598), (194, 132), (194, 877),
+  (197, 47), (197, 962), (199, 66), (199, 943), (200, 267), (200, 742), (201, 455), (201, 554),
+  (205, 209), (205, 800), (208, 113), (208, 896), (210, 221), (210, 788), (216, 433), (216, 576),
+  (218, 43), (218, 966), (221, 363), (221, 646), (223, 173), (223, 836), (225, 119), (225, 890),
+  (226, 338), (226, 671), (227, 322), (227, 687), (228, 200), (228, 809), (232, 472), (232, 537),
+  (235, 485), (235, 524), (236, 348), (236, 661), (237, 272), (237, 737), (239, 361), (239, 648),
+  (243, 470), (243, 539), (244, 57), (244, 952), (248, 275), (248, 734), (249, 476), (249, 533),
+  (250, 167), (250, 842), (253, 495), (253, 514), (257, 305), (257, 704), (258, 315), (258, 694),
+  (260, 128), (260, 881), (261, 149), (261, 860), (262, 172), (262, 837), (263, 305), (263, 704),
+  (264, 287), (264, 722), (265, 200), (265, 809), (267, 479), (267, 530), (268, 139), (268, 870),
+  (269, 276), (269, 733), (270, 360), (270, 649), (271, 260), (271, 749), (276, 487), (276, 522),
+  (281, 384), (281, 625), (284, 260), (284, 749), (286, 439), (286, 570), (288, 62), (288, 947),
+  (291, 383), (291, 626), (292, 348), (292, 661), (296, 488), (296, 521), (298, 476), (298, 533),
+  (299, 50), (299, 959), (300, 455), (300, 554), (302, 252), (302, 757), (303, 131), (303, 878),
+  (304, 114), (304, 895), (305, 108), (305, 901), (306, 487), (306, 522), (307, 401), (307, 608),
+  (309, 312), (309, 697), (310, 48), (310, 961), (314, 131), (314, 878), (316, 177), (316, 832),
+  (319, 57), (319, 952), (321, 446), (321, 563), (322, 197), (322, 812), (329, 187), (329, 822),
+  (330, 197), (330, 812), (336, 349), (336, 660), (339, 473), (339, 536), (341, 139), (341, 870),
+  (343, 88), (343, 921), (345, 379), (345, 630), (347, 430), (347, 579), (350, 51), (350, 958),
+  (351, 203), (351, 806), (355, 83), (355, 926), (356, 451), (356, 558), (357, 197), (357, 812),
+  (362, 497), (362, 512), (366, 304), (366, 705), (367, 213), (367, 796), (370, 153), (370, 856),
+  (371, 417), (371, 592), (373, 128), (373, 881), (374, 298), (374, 711), (375, 162), (375, 847),
+  (376, 128), (376, 881), (378, 350), (378, 659), (380, 60), (380, 949), (381, 119), (381, 890),
+  (387, 442), (387, 567), (392, 131), (392, 878), (394, 253), (394, 756), (396, 216), (396, 793),
+  (397, 26), (397, 983), (400, 139), (400, 870), (401, 206), (401, 803), (403, 119), (403, 890),
+  (404, 376), (404, 633), (405, 151), (405, 858), (414, 78), (414, 931), (418, 14), (418, 995),
+  (420, 484), (420, 525), (421, 339), (421, 670), (426, 35), (426, 974), (427, 487), (427, 522),
+  (428, 112), (428, 897), (431, 471), (431, 538), (432, 463), (432, 546), (433, 226), (433, 783),
+  (436, 331), (436, 678), (439, 493), (439, 516), (441, 321), (441, 688), (444, 41), (444, 968),
+  (445, 11), (445, 998), (446, 57), (446, 952), (447, 168), (447, 841), (449, 463), (449, 546),
+  (450, 388), (450, 621), (451, 442), (451, 567), (453, 261), (453, 748), (454, 260), (454, 749),
+  (456, 227), (456, 782), (457, 26), (457, 983), (458, 489), (458, 520), (459, 78), (459, 931),
+  (460, 306), (460, 703), (462, 476), (462, 533), (463, 428), (463, 581), (465, 497), (465, 512),
+  (467, 321), (467, 688), (468, 250), (468, 759), (471, 146), (471, 863), (475, 250), (475, 759),
+  (479, 31), (479, 978), (480, 334), (480, 675), (481, 348), (481, 661), (482, 107), (482, 902),
+  (484, 151), (484, 858), (485, 435), (485, 574), (487, 264), (487, 745), (489, 305), (489, 704),
+  (492, 153), (492, 856), (493, 453), (493, 556), (502, 226), (502, 783), (504, 106), (504, 903),
+  (507, 178), (507, 831), (508, 455), (508, 554), (509, 246), (509, 763), (510, 306), (510, 703),
+  (512, 184), (512, 825), (514, 125), (514, 884), (515, 219), (515, 790), (516, 200), (516, 809),
+  (517, 496), (517, 513), (518, 264), (518, 745), (519, 350), (519, 659), (520, 420), (520, 589),
+  (521, 417), (521, 592), (526, 125), (526, 884), (527, 88), (527, 921), (528, 55), (528, 954),
+  (530, 227), (530, 782), (533, 182), (533, 827), (534, 33), (534, 976), (538, 339), (538, 670),
+  (539, 293), (539, 716), (540, 312), (540, 697), (541, 33), (541, 976), (542, 333), (542, 676),
+  (544, 368), (544, 641), (547, 207), (547, 802), (548, 349), (548, 660), (550, 110), (550, 899),
+  (552, 175), (552, 834), (553, 31), (553, 978), (554, 203), (554, 806), (555, 5), (555, 1004),
+  (558, 436), (558, 573), (562, 177), (562, 832), (563, 459), (563, 550), (568, 333), (568, 676),
+  (569, 182), (569, 827), (570, 384), (570, 625), (573, 253), (573, 756), (574, 232), (574, 777),
+  (576, 178), (576, 831), (580, 41), (580, 968), (582, 220), (582, 789), (585, 458), (585, 551),
+  (587, 458), (587, 551), (588, 146), (588, 863), (591, 83), (591, 926), (593, 426), (593, 583),
+  (594, 361), (594, 648), (595, 110), (595, 899), (601, 81), (601, 928), (603, 219), (603, 790),
+  (608, 213), (608, 796), (609, 440), (609, 569), (611, 334), (611, 675), (612, 175), (612, 834),
+  (614, 426), (614, 583), (615, 331), (615, 678), (617, 425), (617, 584), (619, 11), (619, 998),
+  (622, 436), (622, 573), (623, 428), (623, 581), (626, 430), (626, 579), (632, 172), (632, 837),
+  (633, 53), (633, 956), (634, 298), (634, 711), (635, 162), (635, 847), (636, 53), (636, 956),
+  (637, 315), (637, 694), (639, 496), (639, 513), (640, 322), (640, 687), (641, 81), (641, 928),
+  (642, 206), (642, 803), (646, 261), (646, 748), (647, 368), (647, 641), (650, 112), (650, 897),
+  (651, 108), (651, 901), (654, 14), (654, 995), (659, 360), (659, 649), (661, 473), (661, 536),
+  (664, 173), (664, 836), (666, 107), (666, 902), (667, 485), (667, 524), (668, 440), (668, 569),
+  (669, 304), (669, 705), (672, 167), (672, 842), (674, 246), (674, 763), (675, 267), (675, 742),
+  (676, 232), (676, 777), (681, 35), (681, 974), (684, 293), (684, 716), (685, 484), (685, 525),
+  (688, 114), (688, 895), (690, 459), (690, 550), (691, 411), (691, 598), (692, 106), (692, 903),
+  (693, 168), (693, 841), (694, 470), (694, 539), (695, 425), (695, 584), (697, 451), (697, 558),
+  (702, 113), (702, 896), (703, 220), (703, 789), (705, 446), (705, 563), (706, 425), (706, 584),
+  (711, 207), (711, 802), (713, 439), (713, 570), (715, 276), (715, 733), (717, 55), (717, 954),
+  (719, 184), (719, 825), (723, 488), (723, 521), (725, 5), (725, 1004), (726, 398), (726, 611),
+  (728, 493), (728, 516), (729, 433), (729, 576), (732, 187), (732, 822), (733, 220), (733, 789),
+  (738, 5), (738, 1004), (739, 51), (739, 958), (741, 440), (741, 569), (744, 453), (744, 556),
+  (745, 214), (745, 795), (746, 420), (746, 589), (749, 53), (749, 956), (750, 149), (750, 860),
+  (752, 420), (752, 589), (754, 435), (754, 574), (755, 376), (755, 633), (758, 62), (758, 947),
+  (760, 207), (760, 802), (761, 393), (761, 616), (762, 388), (762, 621), (763, 471), (763, 538),
+  (765, 459), (765, 550), (767, 252), (767, 757), (768, 232), (768, 777), (769, 66), (769, 943),
+  (771, 489), (771, 520), (773, 55), (773, 954), (775, 479), (775, 530), (776, 81), (776, 928),
+  (777, 338), (777, 671), (778, 60), (778, 949), (779, 435), (779, 574), (780, 238), (780, 771),
+  (781, 453), (781, 556), (783, 472), (783, 537), (785, 495), (785, 514), (786, 379), (786, 630),
+  (787, 184), (787, 825), (788, 230), (788, 779), (789, 489), (789, 520), (790, 216), (790, 793),
+  (791, 47), (791, 962), (794, 48), (794, 961), (795, 293), (795, 716), (801, 401), (801, 608),
+  (806, 388), (806, 621), (811, 426), (811, 583), (812, 43), (812, 966), (818, 209), (818, 800),
+  (822, 106), (822, 903), (823, 263), (823, 746), (824, 471), (824, 538), (827, 368), (827, 641),
+  (832, 216), (832, 793), (834, 202), (834, 807), (835, 246), (835, 763), (836, 50), (836, 959),
+  (837, 275), (837, 734), (838, 436), (838, 573), (839, 29), (839, 980), (846, 458), (846, 551),
+  (847, 221), (847, 788), (851, 493), (851, 516), (854, 175), (854, 834), (855, 272), (855, 737),
+  (856, 383), (856, 626), (858, 500), (858, 509), (859, 376), (859, 633), (860, 60), (860, 949),
+  (862, 496), (862, 513), (863, 287), (863, 722), (869, 320), (869, 689), (870, 107), (870, 902),
+  (871, 383), (871, 626), (872, 363), (872, 646), (873, 110), (873, 899), (878, 168), (878, 841),
+  (883, 50), (883, 959), (887, 379), (887, 630), (888, 482), (888, 527), (891, 287), (891, 722),
+  (896, 142), (896, 867), (900, 219), (900, 790), (901, 192), (901, 817), (907, 132), (907, 877),
+  (908, 333), (908, 676), (909, 491), (909, 518), (910, 401), (910, 608), (911, 35), (911, 974),
+  (913, 484), (913, 525), (914, 48), (914, 961), (916, 182), (916, 827), (917, 132), (917, 877),
+  (919, 261), (919, 748), (920, 86), (920, 923), (923, 374), (923, 635), (925, 363), (925, 646),
+  (926, 272), (926, 737), (927, 334), (927, 675), (928, 166), (928, 843), (929, 51), (929, 958),
+  (932, 428), (932, 581), (933, 275), (933, 734), (935, 178), (935, 831), (939, 468), (939, 541),
+  (940, 112), (940, 897), (942, 491), (942, 518), (943, 33), (943, 976), (946, 14), (946, 995),
+  (949, 252), (949, 757), (954, 11), (954, 998), (955, 256), (955, 753), (957, 187), (957, 822),
+  (958, 212), (958, 797), (959, 146), (959, 863), (961, 221), (961, 788), (963, 212), (963, 797),
+  (965, 451), (965, 558), (967, 331), (967, 678), (971, 374), (971, 635), (972, 62), (972, 947),
+  (974, 98), (974, 911), (975, 206), (975, 803), (976, 479), (976, 530), (977, 192), (977, 817),
+  (978, 125), (978, 884), (979, 482), (979, 527), (980, 495), (980, 514), (982, 292), (982, 717),
+  (983, 304), (983, 705), (985, 166), (985, 843), (986, 31), (986, 978), (988, 43), (988, 966),
+  (992, 446), (992, 563), (993, 256), (993, 753), (994, 41), (994, 968), (995, 209), (995, 800),
+  (996, 29), (996, 980), (999, 488), (999, 521), (1001, 292), (1001, 717), (1003, 472), (1003, 537),
+  (1006, 142), (1006, 867), (1007, 149), (1007, 860), (1008, 162), (1008, 847),
+];
+
+/// Converts one of the point tables to points.
+///
+/// # Examples
+///
+/// ```
+/// use modular_math::curves::toy;
+///
+/// let curve = toy::curve_17();
+/// let points = toy::points(&toy::CURVE_17_POINTS);
+/// assert!(points.iter().all(|p| curve.is_on_curve(p)));
+/// ```
+pub fn points(table: &[(u64, u64)]) -> Vec<ECPoint> {
+  table.iter().map(|&(x, y)| ECPoint::new(U256::from(x), U256::from(y))).collect()
+}
+
+/// Lists every affine point of the curve, sorted by `x` and then `y`.
+///
+/// The point at infinity is not included, so a curve has one point more than listed.
+/// This walks the whole field and is only usable for tiny fields.
+pub fn enumerate_points(curve: &Curve) -> Vec<ECPoint> {
+  let p = curve.field_modulus;
+  let mod_math = ModMath::new(p);
+  let mut points = Vec::new();
+  let mut x = U256::zero();
+  while x < p {
+    let rhs = mod_math.add(mod_math.add(mod_math.exp(x, U256::from(3)), mod_math.mul(curve.a, x)), curve.b);
+    if let Some(y) = mod_math.sqrt_normalized(rhs, RootConvention::Smaller) {
+      points.push(ECPoint::new(x, y));
+      if y != U256::zero() {
+        points.push(ECPoint::new(x, mod_math.add_inv(y)));
+      }
+    }
+    x += U256::one();
+  }
+  points
+}
+
+fn toy_curve(a: u64, b: u64, p: u64, order: u64, (x, y): (u64, u64)) -> Curve {
+  Curve::new(U256::from(a), U256::from(b), U256::from(p), U256::from(order), ECPoint::new(U256::from(x), U256::from(y)))
+}
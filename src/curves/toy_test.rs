@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+  use crate::curves::toy::{self, CURVE_1009_POINTS, CURVE_17_POINTS, CURVE_97_POINTS};
+  use crate::curves::{Curve, ECPoint};
+  use primitive_types::U256;
+
+  type PointTable = &'static [(u64, u64)];
+
+  fn catalog() -> Vec<(&'static str, Curve, PointTable)> {
+    vec![
+      ("CURVE_17_POINTS", toy::curve_17(), &CURVE_17_POINTS),
+      ("CURVE_97_POINTS", toy::curve_97(), &CURVE_97_POINTS),
+      ("CURVE_1009_POINTS", toy::curve_1009(), &CURVE_1009_POINTS),
+    ]
+  }
+
+  // Regenerates the tables in toy.rs: cargo test print_point_tables -- --ignored --nocapture
+  #[test]
+  #[ignore]
+  fn print_point_tables() {
+    for (name, curve, _) in catalog() {
+      let points = toy::enumerate_points(&curve);
+      println!("pub const {}: [(u64, u64); {}] = [", name, points.len());
+      for row in points.chunks(8) {
+        let row: Vec<String> = row.iter().map(|p| format!("({}, {})", p.x, p.y)).collect();
+        println!("  {},", row.join(", "));
+      }
+      println!("];\n");
+    }
+  }
+
+  #[test]
+  fn test_tables_match_enumeration() {
+    for (name, curve, table) in catalog() {
+      assert_eq!(toy::points(table), toy::enumerate_points(&curve), "{} is stale", name);
+      // the listed points and the point at infinity make up the whole group
+      assert_eq!(U256::from(table.len() + 1), curve.curve_order, "{}", name);
+    }
+  }
+
+  #[test]
+  fn test_generator_generates_every_point() {
+    for (name, curve, table) in catalog() {
+      let mut multiples = Vec::new();
+      let mut p = curve.G;
+      while !p.is_identity() {
+        multiples.push(p);
+        p = curve.add_points(&p, &curve.G);
+      }
+      multiples.sort_by_key(|p| (p.x, p.y));
+      assert_eq!(multiples, toy::points(table), "{}", name);
+    }
+  }
+
+  #[test]
+  fn test_curve_17_group_law_is_exhaustively_associative() {
+    let curve = toy::curve_17();
+    let mut group = toy::points(&CURVE_17_POINTS);
+    group.push(ECPoint::identity());
+
+    for p in &group {
+      assert_eq!(curve.add_points(p, &ECPoint::identity()), *p);
+      let neg_p = if p.is_identity() { *p } else { ECPoint::new(p.x, (curve.field_modulus - p.y) % curve.field_modulus) };
+      assert!(curve.add_points(p, &neg_p).is_identity());
+      for q in &group {
+        let p_q = curve.add_points(p, q);
+        assert_eq!(p_q, curve.add_points(q, p));
+        for r in &group {
+          assert_eq!(curve.add_points(&p_q, r), curve.add_points(p, &curve.add_points(q, r)), "{:?} {:?} {:?}", p, q, r);
+        }
+      }
+    }
+  }
+}
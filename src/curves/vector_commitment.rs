@@ -0,0 +1,164 @@
+use primitive_types::U256;
+
+use super::elliptical_curve::{Curve, ECPoint};
+use crate::mod_math::{ArithError, Domain, RootConvention};
+
+/// A Pedersen commitment to a vector of `n` scalars, opened one position at a time.
+///
+/// The vector `v` is read as the values on a `Domain` of `n` roots of unity modulo the
+/// curve order, i.e. the polynomial `p` with `p(w^i) = v[i]`, and the commitment is
+/// `p_0 * B_0 + ... + p_(n-1) * B_(n-1) + r * H` for the coefficients `p_j` of `p`, a
+/// blinding factor `r` and generators `B_j` and `H` with no known relation. `new`
+/// precomputes the commitments to the Lagrange basis polynomials, so `commit` is a
+/// single multi-scalar multiplication over the values themselves.
+///
+/// The opening is not succinct: the proof for position `i` holds the `n - 1`
+/// coefficients of the quotient `(p(x) - v[i]) / (x - w^i)` and the blinding factor, and
+/// the verifier recomputes the commitment from them. This binds `v[i]` to the
+/// commitment but reveals the rest of the vector.
+///
+/// The curve must have prime order, as BN128, secp256k1 and P-256 do, so that every
+/// point found by `lift_x` lies in the group generated by `G`.
+///
+/// # Examples
+///
+/// ```
+/// use modular_math::curves::{VectorCommitment, BN128};
+/// use primitive_types::U256;
+///
+/// let bn128 = BN128();
+/// let scheme = VectorCommitment::new(&bn128, 4).unwrap();
+/// let values = [3, 1, 4, 1].map(U256::from);
+/// let blinding = U256::from(5926);
+/// let commitment = scheme.commit(&values, blinding).unwrap();
+///
+/// let (value, proof) = scheme.open(&values, blinding, 2).unwrap();
+/// assert_eq!(value, U256::from(4));
+/// assert!(scheme.verify_open(&commitment, 2, value, &proof));
+/// assert!(!scheme.verify_open(&commitment, 2, U256::from(5), &proof));
+/// ```
+pub struct VectorCommitment<'a> {
+  curve: &'a Curve,
+  domain: Domain,
+  // B_0, ..., B_(n-1) for the coefficients and H for the blinding factor
+  monomial_generators: Vec<ECPoint>,
+  blinding_generator: ECPoint,
+  // the commitments to the Lagrange basis, sum_j L_i[j] * B_j
+  basis_commitments: Vec<ECPoint>,
+}
+
+/// The proof that a committed vector has a value at a position, from `VectorCommitment::open`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpeningProof {
+  /// The coefficients of `(p(x) - v[i]) / (x - w^i)`, lowest degree first
+  pub quotient: Vec<U256>,
+  /// The blinding factor of the commitment
+  pub blinding: U256,
+}
+
+impl<'a> VectorCommitment<'a> {
+  /// Sets up commitments to vectors of `size` scalars on `curve`.
+  ///
+  /// The generators are the points with the smallest `x` coordinates, and the even
+  /// `y`, so nobody knows a discrete-log relation between them. Setting up computes the
+  /// Lagrange basis with inverse transforms and commits to each basis polynomial with a
+  /// multi-scalar multiplication.
+  ///
+  /// Returns None unless `size` is a power of two, at least 2, dividing `curve_order - 1`.
+  pub fn new(curve: &'a Curve, size: usize) -> Option<Self> {
+    let domain = Domain::new(curve.scalar_math(), size)?;
+    let mut generators = Vec::with_capacity(size + 1);
+    let mut x = U256::one();
+    while generators.len() <= size {
+      if let Some(point) = curve.lift_x(x, RootConvention::EvenY) {
+        generators.push(point);
+      }
+      x += U256::one();
+    }
+    let blinding_generator = generators.pop()?;
+
+    let basis_commitments = domain
+      .lagrange_basis()
+      .iter()
+      .map(|basis_polynomial| curve.msm(basis_polynomial, &generators))
+      .collect();
+    Some(VectorCommitment { curve, domain, monomial_generators: generators, blinding_generator, basis_commitments })
+  }
+
+  /// Returns the domain whose elements index the positions of the vector.
+  pub fn domain(&self) -> &Domain {
+    &self.domain
+  }
+
+  /// Returns the precomputed commitments to the Lagrange basis polynomials, i.e. the
+  /// commitment to each unit vector without blinding.
+  pub fn basis_commitments(&self) -> &[ECPoint] {
+    &self.basis_commitments
+  }
+
+  /// Commits to `values`, reduced modulo the curve order, with the blinding factor
+  /// `blinding`: `values[0] * C_0 + ... + values[n-1] * C_(n-1) + blinding * H` for the
+  /// basis commitments `C_i`.
+  ///
+  /// Returns `ArithError::LengthMismatch` unless there are exactly `n` values.
+  pub fn commit(&self, values: &[U256], blinding: U256) -> Result<ECPoint, ArithError> {
+    if values.len() != self.domain.size() {
+      return Err(ArithError::LengthMismatch);
+    }
+    let math = self.domain.math();
+    let mut scalars: Vec<U256> = values.iter().map(|&v| math.reduce(v)).collect();
+    scalars.push(math.reduce(blinding));
+    let mut points = self.basis_commitments.clone();
+    points.push(self.blinding_generator);
+    Ok(self.curve.msm(&scalars, &points))
+  }
+
+  /// Opens the commitment to `values` made with `blinding` at position `index`,
+  /// returning the value there and the proof for `verify_open`.
+  ///
+  /// Returns `ArithError::LengthMismatch` unless there are exactly `n` values.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `index` is not below `n`.
+  pub fn open(&self, values: &[U256], blinding: U256, index: usize) -> Result<(U256, OpeningProof), ArithError> {
+    let math = self.domain.math();
+    let coefficients = self.domain.interpolate(values)?;
+    let z = self.domain.elements()[index];
+
+    // synthetic division by x - z, from the leading coefficient down; the remainder is p(z)
+    let n = coefficients.len();
+    let mut quotient = vec![U256::zero(); n - 1];
+    let mut carry = U256::zero();
+    for k in (1..n).rev() {
+      carry = math.add(coefficients[k], math.mul(carry, z));
+      quotient[k - 1] = carry;
+    }
+    let proof = OpeningProof { quotient, blinding: math.reduce(blinding) };
+    Ok((math.reduce(values[index]), proof))
+  }
+
+  /// Checks that `commitment` is a commitment to a vector with `value` at position
+  /// `index`, by recomputing it from the polynomial `quotient * (x - w^index) + value`.
+  ///
+  /// Returns false for an `index` that is not below `n` or a proof of the wrong size.
+  pub fn verify_open(&self, commitment: &ECPoint, index: usize, value: U256, proof: &OpeningProof) -> bool {
+    let n = self.domain.size();
+    if index >= n || proof.quotient.len() != n - 1 {
+      return false;
+    }
+    let math = self.domain.math();
+    let z = self.domain.elements()[index];
+    let q = |k: usize| proof.quotient.get(k).copied().unwrap_or_default();
+
+    let mut scalars = Vec::with_capacity(n + 1);
+    scalars.push(math.sub(value, math.mul(z, q(0))));
+    for k in 1..n {
+      scalars.push(math.sub(q(k - 1), math.mul(z, q(k))));
+    }
+    scalars.push(math.reduce(proof.blinding));
+    let mut points = self.monomial_generators.clone();
+    points.push(self.blinding_generator);
+    self.curve.msm(&scalars, &points) == *commitment
+  }
+}
@@ -0,0 +1,77 @@
+use primitive_types::U256;
+
+use super::{Curve, ECPoint};
+
+impl Curve {
+  /// Walks the multiples of the generator from `start_scalar`, yielding `(k, k * G)` for
+  /// `k = start, start + 1, ...` with one point addition per step instead of a scalar
+  /// multiplication, e.g. for vanity searches.
+  ///
+  /// The scalar is reduced modulo the curve order and wraps around it: after
+  /// `curve_order - 1` comes `0` with the point at infinity, and then `1` with `G`.
+  /// The iterator never ends.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::BN128;
+  /// use primitive_types::U256;
+  ///
+  /// let bn128 = BN128();
+  /// let (k, point) = bn128.walk_from(U256::from(5)).nth(2).unwrap();
+  /// assert_eq!(k, U256::from(7));
+  /// assert_eq!(point, bn128.scalar_multiply_generator(U256::from(7)));
+  /// ```
+  pub fn walk_from(&self, start_scalar: U256) -> impl Iterator<Item = (U256, ECPoint)> + '_ {
+    let k = start_scalar % self.curve_order;
+    let start = (k, self.scalar_multiply_generator(k));
+    std::iter::successors(Some(start), move |(k, point)| Some((self.next_scalar(*k), self.add_points(point, &self.G))))
+  }
+
+  /// Walks the multiples of the generator like `walk_from`, computing `chunk_size` points
+  /// at a time in Jacobian coordinates and converting each chunk to affine with a single
+  /// inversion. This is faster than `walk_from` when many points are consumed.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `chunk_size` is zero.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use modular_math::curves::BN128;
+  /// use primitive_types::U256;
+  ///
+  /// let bn128 = BN128();
+  /// let batched: Vec<_> = bn128.walk_from_batched(U256::from(5), 16).take(40).collect();
+  /// let stepped: Vec<_> = bn128.walk_from(U256::from(5)).take(40).collect();
+  /// assert_eq!(batched, stepped);
+  /// ```
+  pub fn walk_from_batched(&self, start_scalar: U256, chunk_size: usize) -> impl Iterator<Item = (U256, ECPoint)> + '_ {
+    assert!(chunk_size > 0, "chunk_size must be positive");
+    let g = self.to_jacobian(&self.G);
+    let mut k = start_scalar % self.curve_order;
+    let mut current = self.to_jacobian(&self.scalar_multiply_generator(k));
+    std::iter::from_fn(move || {
+      let mut scalars = Vec::with_capacity(chunk_size);
+      let mut points = Vec::with_capacity(chunk_size);
+      for _ in 0..chunk_size {
+        scalars.push(k);
+        points.push(current);
+        k = self.next_scalar(k);
+        current = self.jacobian_add(&current, &g);
+      }
+      Some(scalars.into_iter().zip(self.batch_to_affine(&points)))
+    })
+    .flatten()
+  }
+
+  fn next_scalar(&self, k: U256) -> U256 {
+    let next = k + 1;
+    if next == self.curve_order {
+      U256::zero()
+    } else {
+      next
+    }
+  }
+}
@@ -0,0 +1,182 @@
+//! Adversarial inputs for the decoders of untrusted data: `NumberUnderMod::from_bytes`,
+//! `ECPoint::from_bytes`, `Curve::point_from_bytes`, `ModMath::from_modulus_hex` and
+//! `TryIntoU256` for strings.
+//!
+//! Every input must give an error or a canonical value, and none may panic.
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    use primitive_types::U256;
+    use rand::{rngs::StdRng, Rng, RngCore, SeedableRng};
+
+    use crate::curves::{Curve, CurveError, ECPoint, Secp256k1, BN128};
+    use crate::mod_math::{ArithError, ModMath, TryIntoU256};
+    use crate::number_mod::NumberUnderMod;
+
+    fn no_panic<R>(f: impl FnOnce() -> R) -> R {
+        catch_unwind(AssertUnwindSafe(f)).expect("decoding panicked")
+    }
+
+    fn encode(a: U256, b: U256) -> Vec<u8> {
+        ECPoint::new(a, b).to_bytes().to_vec()
+    }
+
+    // Lengths 0 to 130, with zero, all-ones and random contents
+    fn wrong_length_buffers(rng: &mut StdRng) -> Vec<Vec<u8>> {
+        let mut buffers = Vec::new();
+        for len in (0..=130).filter(|&len| len != 64) {
+            buffers.push(vec![0; len]);
+            buffers.push(vec![0xff; len]);
+            let mut random = vec![0; len];
+            rng.fill_bytes(&mut random);
+            buffers.push(random);
+        }
+        buffers
+    }
+
+    // Values at and above the modulus that still fit in 256 bits
+    fn non_canonical(modulus: U256) -> Vec<U256> {
+        let mut values = vec![modulus, U256::MAX];
+        values.extend(modulus.checked_add(U256::one()));
+        values.extend(modulus.checked_mul(U256::from(2)));
+        values.extend((0..8u64).filter_map(|i| modulus.checked_add(U256::from(1u64 << (8 * i)))));
+        values
+    }
+
+    #[test]
+    fn test_number_from_bytes_rejects_adversarial_inputs() {
+        let mut rng = StdRng::seed_from_u64(973);
+        let mut vectors = 0;
+        for buffer in wrong_length_buffers(&mut rng) {
+            assert_eq!(no_panic(|| NumberUnderMod::from_bytes(&buffer)), Err(ArithError::InvalidLength));
+            vectors += 1;
+        }
+
+        for modulus in [U256::one(), U256::from(7), U256::from(u64::MAX), Secp256k1().field_modulus, U256::MAX] {
+            for value in non_canonical(modulus) {
+                let bytes = encode(value, modulus);
+                assert_eq!(no_panic(|| NumberUnderMod::from_bytes(&bytes)), Err(ArithError::NotCanonical));
+                vectors += 1;
+            }
+            let bytes = encode(modulus - 1, modulus);
+            assert_eq!(no_panic(|| NumberUnderMod::from_bytes(&bytes)), Ok(NumberUnderMod::new(modulus - 1, modulus)));
+        }
+        for value in [U256::zero(), U256::one(), U256::MAX] {
+            let bytes = encode(value, U256::zero());
+            assert_eq!(no_panic(|| NumberUnderMod::from_bytes(&bytes)), Err(ArithError::ZeroModulus));
+            vectors += 1;
+        }
+
+        // random encodings are either rejected or decoded to the same canonical number
+        for _ in 0..100 {
+            let mut bytes = [0u8; 64];
+            rng.fill_bytes(&mut bytes);
+            if rng.gen() {
+                bytes[32..48].fill(0);
+            }
+            match no_panic(|| NumberUnderMod::from_bytes(&bytes)) {
+                Ok(num) => {
+                    assert!(num.value() < num.modulus());
+                    assert_eq!(num.to_bytes(), bytes);
+                }
+                Err(e) => assert_eq!(e, ArithError::NotCanonical),
+            }
+            vectors += 1;
+        }
+        assert!(vectors > 400);
+    }
+
+    #[test]
+    fn test_point_from_bytes_rejects_adversarial_inputs() {
+        let mut rng = StdRng::seed_from_u64(9731);
+        let curves: [Curve; 2] = [BN128(), Secp256k1()];
+        for buffer in wrong_length_buffers(&mut rng) {
+            assert_eq!(no_panic(|| ECPoint::from_bytes(&buffer)), Err(CurveError::InvalidLength));
+            for curve in &curves {
+                assert_eq!(no_panic(|| curve.point_from_bytes(&buffer)), Err(CurveError::InvalidLength));
+            }
+        }
+
+        for curve in &curves {
+            let p = curve.field_modulus;
+            let point = curve.scalar_multiply_generator(U256::from(0x1234_5678_u64));
+            assert_eq!(curve.point_from_bytes(&point.to_bytes()), Ok(point));
+
+            // coordinates that are only on the curve after reduction
+            for x in non_canonical(p).into_iter().chain(point.x.checked_add(p)) {
+                let bytes = encode(x, point.y);
+                assert_eq!(no_panic(|| curve.point_from_bytes(&bytes)), Err(CurveError::NotCanonical));
+                let bytes = encode(point.x, x);
+                assert_eq!(no_panic(|| curve.point_from_bytes(&bytes)), Err(CurveError::NotCanonical));
+            }
+
+            // canonical coordinates off the curve, including the identity's (0, 0)
+            for (x, y) in [(point.x, point.y + 1), (U256::zero(), U256::zero()), (p - 1, p - 1), (U256::one(), U256::one())] {
+                assert_eq!(no_panic(|| curve.point_from_bytes(&encode(x, y))), Err(CurveError::NotOnCurve));
+            }
+
+            // random encodings are either rejected or decoded to a canonical point on the curve
+            for _ in 0..100 {
+                let mut bytes = [0u8; 64];
+                rng.fill_bytes(&mut bytes);
+                match no_panic(|| curve.point_from_bytes(&bytes)) {
+                    Ok(point) => {
+                        assert!(curve.is_on_curve(&point));
+                        assert_eq!(point.to_bytes(), bytes);
+                    }
+                    Err(e) => assert!(e == CurveError::NotCanonical || e == CurveError::NotOnCurve),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_string_decoding_rejects_adversarial_inputs() {
+        let long_digits = "1".repeat(129);
+        let long_zeros = format!("{}1", "0".repeat(200));
+        let huge = "9".repeat(100_000);
+        let invalid = [
+            "",
+            "0x",
+            "0X",
+            "x",
+            "0x0x1",
+            "+1",
+            "-1",
+            " 1",
+            "1 ",
+            "1\n",
+            "1_000",
+            "0xg",
+            "0x-1",
+            "\u{0661}",
+            "\u{0}",
+            "115792089237316195423570985008687907853269984665640564039457584007913129639936",
+            "0x10000000000000000000000000000000000000000000000000000000000000000",
+            &long_digits,
+            &long_zeros,
+            &huge,
+        ];
+        for input in invalid {
+            let error = no_panic(|| input.try_into_u256()).expect_err(input);
+            assert!(error.input().chars().count() <= 128);
+            assert!(no_panic(|| ModMath::from_modulus_hex(input)).is_err(), "{:?}", input);
+        }
+        // hex digits, but not decimal
+        for input in ["1e3", "0b101", "ff"] {
+            assert!(input.try_into_u256().is_err());
+            assert!(ModMath::from_modulus_hex(input).is_ok());
+        }
+
+        assert_eq!("0".try_into_u256(), Ok(U256::zero()));
+        assert_eq!("0x0".try_into_u256(), Ok(U256::zero()));
+        assert_eq!(
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935".try_into_u256(),
+            Ok(U256::MAX)
+        );
+        assert_eq!(format!("0x{}", "f".repeat(64)).as_str().try_into_u256(), Ok(U256::MAX));
+        assert_eq!(format!("{}7", "0".repeat(127)).as_str().try_into_u256(), Ok(U256::from(7)));
+    }
+}
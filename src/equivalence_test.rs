@@ -0,0 +1,84 @@
+//! Cross-checks of the three notions of equality in the crate.
+//!
+//! - `ModMath::eq` is congruence: values that differ by a multiple of the modulus are equal.
+//! - `NumberUnderMod`'s `==` compares the value and the modulus. The value is reduced
+//!   on construction, so `==` agrees with `ModMath::eq` for numbers under one modulus.
+//! - `ECPoint`'s `==` compares coordinates. The curve arithmetic returns reduced affine
+//!   points, so equal points compare equal whichever way they were computed;
+//!   `Curve::eq_points` reduces first and `JacobianPoint::eq_affine` normalizes
+//!   projective coordinates.
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use primitive_types::U256;
+
+    use crate::curves::{ECPoint, Secp256k1, BN128};
+    use crate::galois_field::GaloisField;
+    use crate::mod_math::ModMath;
+    use crate::number_mod::NumberUnderMod;
+
+    #[test]
+    fn test_congruent_inputs_give_equal_numbers() {
+        for modulus in [2u64, 13, 97, 65537] {
+            let math = ModMath::new(modulus);
+            let m = U256::from(modulus);
+            for value in [0u64, 1, 5, modulus - 1] {
+                let value = U256::from(value);
+                for multiple in [1u64, 2, 1000] {
+                    let congruent = value + m * multiple;
+                    assert!(math.eq(value, congruent));
+                    assert_eq!(NumberUnderMod::new(value, m), NumberUnderMod::new(congruent, m));
+                }
+                // but the same value under another modulus is a different number
+                assert_ne!(NumberUnderMod::new(value, m), NumberUnderMod::new(value, m + 1));
+            }
+        }
+        let GF97 = GaloisField::new(97).unwrap();
+        assert_eq!(GF97.gf(3), GF97.gf(100));
+        assert_eq!(GF97.gf(3), NumberUnderMod::new(3, 97));
+    }
+
+    #[test]
+    fn test_mod_math_eq_agrees_with_number_eq() {
+        let math = ModMath::new(101);
+        for a in 0u64..300 {
+            for b in [0u64, 7, 108, 202, 299] {
+                let numbers_equal = NumberUnderMod::new(a, 101) == NumberUnderMod::new(b, 101);
+                assert_eq!(math.eq(a, b), numbers_equal, "a = {}, b = {}", a, b);
+                assert_eq!(math.eq(a, b), math.reduce(a) == math.reduce(b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_points_from_different_paths_are_equal() {
+        for curve in [BN128(), Secp256k1()] {
+            let G = curve.G;
+            let math = ModMath::new(curve.field_modulus);
+            let double_G = curve.point_doubling(&G);
+            let five_G = curve.scalar_multiply_generator(U256::from(5));
+
+            // 5G as 2G + 2G + G, as 4G + G, by a scalar multiplication of G and in Jacobian coordinates
+            let by_additions = curve.add_points(&curve.add_points(&double_G, &double_G), &G);
+            let by_doubling = curve.add_points(&curve.point_doubling(&double_G), &G);
+            let by_scalar = curve.point_multiplication_scalar(U256::from(5), G);
+            let jacobian = curve.jacobian_scalar_mul(U256::from(5), &G);
+            assert_eq!(by_additions, five_G);
+            assert_eq!(by_doubling, five_G);
+            assert_eq!(by_scalar, five_G);
+            assert!(jacobian.eq_affine(&five_G, &math));
+            assert_eq!(curve.to_affine(&jacobian), five_G);
+
+            // the same point with unreduced coordinates is the same point of the curve
+            if let Some(x) = five_G.x.checked_add(curve.field_modulus) {
+                let unreduced = ECPoint::new(x, five_G.y);
+                assert_ne!(unreduced, five_G);
+                assert!(curve.eq_points(&unreduced, &five_G));
+                assert_eq!(curve.add_points(&unreduced, &G), curve.add_points(&five_G, &G));
+            }
+            assert!(!curve.eq_points(&five_G, &double_G));
+            assert!(curve.eq_points(&curve.add_points(&G, &curve.negate(&G)), &ECPoint::identity()));
+        }
+    }
+}
@@ -0,0 +1,191 @@
+//! The crate-level error that every module error converts into.
+//!
+//! Each module keeps its own error enum (`ArithError`, `InvError`, `SqrtError`,
+//! `CurveError`, `GaloisFieldError`, `NumModError`), and `From` sorts each of them into a category of
+//! `Error`, keeping the original as an `ErrorDetail`. Code mixing several modules can
+//! return `Result<_, Error>` and use `?` on all of them.
+
+use crate::curves::CurveError;
+use crate::galois_field::GaloisFieldError;
+use crate::mod_math::{ArithError, IntoU256Error, InvError, SqrtError};
+use crate::number_mod::NumModError;
+
+/// Any error returned by this crate, by category.
+///
+/// # Examples
+///
+/// ```
+/// use modular_math::curves::{ECPoint, BN128};
+/// use modular_math::Error;
+/// use primitive_types::U256;
+///
+/// fn double(x: &str, y: &str) -> Result<ECPoint, Error> {
+///     let bn128 = BN128();
+///     let p = ECPoint::new(U256::from_dec_str(x)?, U256::from_dec_str(y)?);
+///     Ok(bn128.checked_add_points(&p, &p)?)
+/// }
+///
+/// assert!(double("1", "2").is_ok());
+/// assert!(matches!(double("1", "3"), Err(Error::NotOnCurve(_))));
+/// assert!(matches!(double("one", "2"), Err(Error::Parse(_))));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// An argument is outside the domain of the operation
+    InvalidInput(ErrorDetail),
+    /// A value has no inverse under the modulus
+    NotInvertible(ErrorDetail),
+    /// A point does not satisfy the curve equation
+    NotOnCurve(ErrorDetail),
+    /// A signature or proof does not verify
+    VerificationFailed,
+    /// A string or an encoding cannot be decoded
+    Parse(ErrorDetail),
+}
+
+/// The module error behind an `Error`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorDetail {
+    Arith(ArithError),
+    Inv(InvError),
+    Sqrt(SqrtError),
+    Curve(CurveError),
+    GaloisField(GaloisFieldError),
+    NumMod(NumModError),
+    /// The message of a rejected number string
+    Number(String),
+}
+
+impl Error {
+    /// Returns the module error behind this error, if any.
+    pub fn detail(&self) -> Option<&ErrorDetail> {
+        match self {
+            Error::InvalidInput(detail)
+            | Error::NotInvertible(detail)
+            | Error::NotOnCurve(detail)
+            | Error::Parse(detail) => Some(detail),
+            Error::VerificationFailed => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidInput(detail) => write!(f, "invalid input: {}", detail),
+            Error::NotInvertible(detail) => write!(f, "not invertible: {}", detail),
+            Error::NotOnCurve(detail) => write!(f, "not on curve: {}", detail),
+            Error::VerificationFailed => write!(f, "verification failed"),
+            Error::Parse(detail) => write!(f, "parse error: {}", detail),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.detail().and_then(|detail| detail.as_error())
+    }
+}
+
+impl ErrorDetail {
+    fn as_error(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ErrorDetail::Arith(e) => Some(e),
+            ErrorDetail::Inv(e) => Some(e),
+            ErrorDetail::Sqrt(e) => Some(e),
+            ErrorDetail::Curve(e) => Some(e),
+            ErrorDetail::GaloisField(e) => Some(e),
+            ErrorDetail::NumMod(e) => Some(e),
+            ErrorDetail::Number(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorDetail {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorDetail::Arith(e) => e.fmt(f),
+            ErrorDetail::Inv(e) => e.fmt(f),
+            ErrorDetail::Sqrt(e) => e.fmt(f),
+            ErrorDetail::Curve(e) => e.fmt(f),
+            ErrorDetail::GaloisField(e) => e.fmt(f),
+            ErrorDetail::NumMod(e) => e.fmt(f),
+            ErrorDetail::Number(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl From<ArithError> for Error {
+    fn from(e: ArithError) -> Self {
+        match e {
+            ArithError::NotInvertible => Error::NotInvertible(ErrorDetail::Arith(e)),
+            ArithError::InvalidLength => Error::Parse(ErrorDetail::Arith(e)),
+            ArithError::Overflow
+            | ArithError::ZeroModulus
+            | ArithError::NotPrime
+            | ArithError::LengthMismatch
+            | ArithError::NotCanonical
+            | ArithError::EvenModulus
+            | ArithError::ModulusTooLarge => {
+                Error::InvalidInput(ErrorDetail::Arith(e))
+            }
+        }
+    }
+}
+
+impl From<InvError> for Error {
+    fn from(e: InvError) -> Self {
+        Error::NotInvertible(ErrorDetail::Inv(e))
+    }
+}
+
+impl From<SqrtError> for Error {
+    fn from(e: SqrtError) -> Self {
+        Error::InvalidInput(ErrorDetail::Sqrt(e))
+    }
+}
+
+impl From<CurveError> for Error {
+    fn from(e: CurveError) -> Self {
+        match e {
+            CurveError::NotOnCurve => Error::NotOnCurve(ErrorDetail::Curve(e)),
+            CurveError::InvalidLength => Error::Parse(ErrorDetail::Curve(e)),
+            CurveError::InvalidModulus | CurveError::NotCanonical | CurveError::UnsupportedCharacteristic => {
+                Error::InvalidInput(ErrorDetail::Curve(e))
+            }
+        }
+    }
+}
+
+impl From<GaloisFieldError> for Error {
+    fn from(e: GaloisFieldError) -> Self {
+        Error::InvalidInput(ErrorDetail::GaloisField(e))
+    }
+}
+
+impl From<NumModError> for Error {
+    fn from(e: NumModError) -> Self {
+        match e {
+            NumModError::NotInvertible => Error::NotInvertible(ErrorDetail::NumMod(e)),
+            _ => Error::InvalidInput(ErrorDetail::NumMod(e)),
+        }
+    }
+}
+
+impl From<IntoU256Error> for Error {
+    fn from(e: IntoU256Error) -> Self {
+        Error::Parse(ErrorDetail::Number(e.to_string()))
+    }
+}
+
+impl From<uint::FromDecStrErr> for Error {
+    fn from(e: uint::FromDecStrErr) -> Self {
+        Error::Parse(ErrorDetail::Number(e.to_string()))
+    }
+}
+
+impl From<uint::FromStrRadixErr> for Error {
+    fn from(e: uint::FromStrRadixErr) -> Self {
+        Error::Parse(ErrorDetail::Number(e.to_string()))
+    }
+}
@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod tests {
+    use primitive_types::U256;
+
+    use crate::curves::{CurveError, ECPoint, BN128};
+    use crate::galois_field::{GaloisField, GaloisFieldError};
+    use crate::number_mod::{NumModError, NumberUnderMod};
+    use crate::mod_math::{ArithError, InvError, ModMath, SqrtError};
+    use crate::{Error, ErrorDetail};
+
+    // Parses a point, checks it is on BN128 and places its x coordinate in GF(modulus)
+    fn x_in_field(x: &str, y: &str, modulus: u64) -> Result<U256, Error> {
+        let bn128 = BN128();
+        let p = ECPoint::new(U256::from_dec_str(x)?, U256::from_dec_str(y)?);
+        let p = bn128.checked_add_points(&p, &ECPoint::identity())?;
+        let field = GaloisField::new(U256::from(modulus)).ok_or(GaloisFieldError::InvalidModulus)?;
+        Ok(field.gf_strict(p.x)?.value())
+    }
+
+    #[test]
+    fn test_errors_chain_through_question_mark() {
+        assert_eq!(x_in_field("1", "2", 7), Ok(U256::one()));
+
+        let parse = x_in_field("1x", "2", 7).unwrap_err();
+        assert!(matches!(parse, Error::Parse(ErrorDetail::Number(_))));
+        assert_eq!(parse.to_string(), format!("parse error: {}", U256::from_dec_str("1x").unwrap_err()));
+
+        let curve = x_in_field("1", "3", 7).unwrap_err();
+        assert_eq!(curve.to_string(), "not on curve: the point does not satisfy the curve equation");
+
+        let field = x_in_field("1", "2", 1).unwrap_err();
+        assert_eq!(field, Error::InvalidInput(ErrorDetail::GaloisField(GaloisFieldError::InvalidModulus)));
+        assert_eq!(field.to_string(), "invalid input: the modulus is not a valid field size");
+        let source = std::error::Error::source(&field).unwrap();
+        assert_eq!(source.to_string(), GaloisFieldError::InvalidModulus.to_string());
+    }
+
+    #[test]
+    fn test_error_categories() {
+        assert!(matches!(Error::from(ArithError::NotInvertible), Error::NotInvertible(_)));
+        assert!(matches!(Error::from(ArithError::InvalidLength), Error::Parse(_)));
+        assert!(matches!(Error::from(ArithError::NotPrime), Error::InvalidInput(_)));
+        assert!(matches!(Error::from(ArithError::NotCanonical), Error::InvalidInput(_)));
+        assert!(matches!(Error::from(ArithError::EvenModulus), Error::InvalidInput(_)));
+        assert!(matches!(Error::from(ArithError::ModulusTooLarge), Error::InvalidInput(_)));
+        assert!(matches!(Error::from(CurveError::UnsupportedCharacteristic), Error::InvalidInput(_)));
+        assert_eq!(
+            Error::from(ModMath::new(15).sqrt(6).unwrap_err()),
+            Error::InvalidInput(ErrorDetail::Sqrt(SqrtError::NotCoprime(U256::from(3))))
+        );
+
+        let inv: Error = ModMath::new(15).inv_detailed(U256::from(6)).unwrap_err().into();
+        assert_eq!(inv, Error::NotInvertible(ErrorDetail::Inv(InvError::NotCoprime(U256::from(3)))));
+        assert_eq!(inv.to_string(), "not invertible: the value shares the factor 3 with the modulus");
+
+        let mismatch = (NumberUnderMod::new(1, 7) + NumberUnderMod::new(1, 11)).unwrap_err();
+        assert_eq!(mismatch, NumModError::ModulusMismatch);
+        assert!(matches!(Error::from(mismatch), Error::InvalidInput(ErrorDetail::NumMod(_))));
+        let not_invertible = (NumberUnderMod::new(1, 8) / 2).unwrap_err();
+        assert!(matches!(Error::from(not_invertible), Error::NotInvertible(ErrorDetail::NumMod(_))));
+
+        assert_eq!(Error::VerificationFailed.detail(), None);
+        assert!(std::error::Error::source(&Error::VerificationFailed).is_none());
+    }
+}
@@ -0,0 +1,191 @@
+use crate::mod_math::{ModMath, IntoU256};
+use crate::number_mod::{NumberUnderMod as NM};
+use super::GaloisFieldError;
+use primitive_types::U256;
+
+/// `ExtensionField` is the field GF(p^n), built as polynomials over GF(p) modulo a
+/// monic polynomial of degree n.
+///
+/// The modulus polynomial must be irreducible over GF(p) for the result to be a field;
+/// this is not checked.
+#[derive(Debug)]
+pub struct ExtensionField {
+    // arithmetic modulo the characteristic p
+    math: ModMath,
+    // monic, lowest degree first
+    modulus: Vec<U256>,
+}
+
+/// An element of an `ExtensionField`, as the coefficients of a polynomial of degree
+/// below n, lowest degree first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtensionFieldElement {
+    coefficients: Vec<U256>,
+}
+
+impl ExtensionFieldElement {
+    /// Returns the coefficients of the element, lowest degree first.
+    pub fn coefficients(&self) -> &[U256] {
+        &self.coefficients
+    }
+}
+
+impl ExtensionField {
+
+    /// Creates GF(p^n) from the prime `p` and the coefficients of the modulus polynomial,
+    /// lowest degree first.
+    ///
+    /// Returns `GaloisFieldError::InvalidModulus` if `p` is not prime or the polynomial is
+    /// not monic of degree at least 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::galois_field::ExtensionField;
+    ///
+    /// // GF(2^3) = GF(2)[x] / (x^3 + x + 1)
+    /// let GF8 = ExtensionField::new(2, vec![1, 1, 0, 1]).unwrap();
+    /// assert_eq!(GF8.degree(), 3);
+    /// ```
+    pub fn new<T: IntoU256>(p: T, modulus: Vec<T>) -> Result<Self, GaloisFieldError> {
+        let p = p.into_u256();
+        if !ModMath::is_probable_prime(p) {
+            return Err(GaloisFieldError::InvalidModulus);
+        }
+        let math = ModMath::new_prime(p);
+        let modulus: Vec<U256> = modulus.into_iter().map(|c| math.reduce(c)).collect();
+        if modulus.len() < 2 || modulus.last() != Some(&U256::one()) {
+            return Err(GaloisFieldError::InvalidModulus);
+        }
+        Ok(Self { math, modulus })
+    }
+
+    /// Returns the degree n of the extension.
+    pub fn degree(&self) -> usize {
+        self.modulus.len() - 1
+    }
+
+    /// Returns the characteristic p of the field.
+    pub fn characteristic(&self) -> U256 {
+        self.math.modulus
+    }
+
+    /// Creates an element from polynomial coefficients, lowest degree first, reducing
+    /// them modulo p and the polynomial modulo the field's modulus polynomial.
+    pub fn element<T: IntoU256>(&self, coefficients: Vec<T>) -> ExtensionFieldElement {
+        let coefficients = coefficients.into_iter().map(|c| self.math.reduce(c)).collect();
+        self.reduce(coefficients)
+    }
+
+    /// Returns the additive identity.
+    pub fn zero(&self) -> ExtensionFieldElement {
+        self.reduce(Vec::new())
+    }
+
+    /// Returns the multiplicative identity.
+    pub fn one(&self) -> ExtensionFieldElement {
+        self.reduce(vec![U256::one()])
+    }
+
+    /// Adds two elements.
+    pub fn add(&self, a: &ExtensionFieldElement, b: &ExtensionFieldElement) -> ExtensionFieldElement {
+        let coefficients = a.coefficients.iter()
+            .zip(&b.coefficients)
+            .map(|(&x, &y)| self.math.add(x, y))
+            .collect();
+        ExtensionFieldElement { coefficients }
+    }
+
+    /// Multiplies two elements.
+    pub fn mul(&self, a: &ExtensionFieldElement, b: &ExtensionFieldElement) -> ExtensionFieldElement {
+        let mut product = vec![U256::zero(); 2 * self.degree() - 1];
+        for (i, &x) in a.coefficients.iter().enumerate() {
+            for (j, &y) in b.coefficients.iter().enumerate() {
+                product[i + j] = self.math.add(product[i + j], self.math.mul(x, y));
+            }
+        }
+        self.reduce(product)
+    }
+
+    /// Raises an element to a power by square-and-multiply.
+    pub fn pow(&self, a: &ExtensionFieldElement, exponent: U256) -> ExtensionFieldElement {
+        let mut result = self.one();
+        let mut base = a.clone();
+        for i in 0..exponent.bits() {
+            if exponent.bit(i) {
+                result = self.mul(&result, &base);
+            }
+            base = self.mul(&base, &base);
+        }
+        result
+    }
+
+    /// Applies the Frobenius automorphism, `a -> a^p`.
+    pub fn frobenius(&self, a: &ExtensionFieldElement) -> ExtensionFieldElement {
+        self.pow(a, self.math.modulus)
+    }
+
+    /// Returns the trace of an element, the sum of its conjugates
+    /// `a + a^p + ... + a^(p^(n-1))`, which lies in GF(p).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::galois_field::ExtensionField;
+    ///
+    /// let GF8 = ExtensionField::new(2, vec![1, 1, 0, 1]).unwrap();
+    /// assert_eq!(GF8.trace(&GF8.one()).value(), 1.into());
+    /// ```
+    pub fn trace(&self, a: &ExtensionFieldElement) -> NM {
+        let mut sum = self.zero();
+        let mut conjugate = a.clone();
+        for _ in 0..self.degree() {
+            sum = self.add(&sum, &conjugate);
+            conjugate = self.frobenius(&conjugate);
+        }
+        self.to_base_field(&sum)
+    }
+
+    /// Returns the norm of an element, the product of its conjugates
+    /// `a * a^p * ... * a^(p^(n-1))`, which lies in GF(p).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::galois_field::ExtensionField;
+    ///
+    /// // GF(3^2) = GF(3)[i] / (i^2 + 1), where the norm of a + bi is a^2 + b^2
+    /// let GF9 = ExtensionField::new(3, vec![1, 0, 1]).unwrap();
+    /// assert_eq!(GF9.norm(&GF9.element(vec![1, 1])).value(), 2.into());
+    /// ```
+    pub fn norm(&self, a: &ExtensionFieldElement) -> NM {
+        let mut product = self.one();
+        let mut conjugate = a.clone();
+        for _ in 0..self.degree() {
+            product = self.mul(&product, &conjugate);
+            conjugate = self.frobenius(&conjugate);
+        }
+        self.to_base_field(&product)
+    }
+
+    // Trace and norm are fixed by the Frobenius map, so only the constant term is non-zero
+    fn to_base_field(&self, a: &ExtensionFieldElement) -> NM {
+        debug_assert!(a.coefficients[1..].iter().all(|c| c.is_zero()), "{:?} is not in the base field", a);
+        NM::with_math(a.coefficients[0], &self.math)
+    }
+
+    // Reduces a polynomial with reduced coefficients modulo the modulus polynomial and
+    // pads it to n coefficients
+    fn reduce(&self, mut coefficients: Vec<U256>) -> ExtensionFieldElement {
+        let n = self.degree();
+        while coefficients.len() > n {
+            let lead = coefficients.pop().unwrap();
+            let shift = coefficients.len() - n;
+            for (i, &m) in self.modulus[..n].iter().enumerate() {
+                coefficients[shift + i] = self.math.sub(coefficients[shift + i], self.math.mul(lead, m));
+            }
+        }
+        coefficients.resize(n, U256::zero());
+        ExtensionFieldElement { coefficients }
+    }
+}
@@ -0,0 +1,89 @@
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+  use crate::galois_field::{ExtensionField, GaloisFieldError};
+  use primitive_types::U256;
+
+  // GF(2^3) = GF(2)[a] / (a^3 + a + 1)
+  fn gf8() -> ExtensionField {
+    ExtensionField::new(2, vec![1, 1, 0, 1]).unwrap()
+  }
+
+  #[test]
+  fn test_new_extension_field() {
+    assert_eq!(gf8().degree(), 3);
+    assert_eq!(gf8().characteristic(), U256::from(2));
+    assert_eq!(ExtensionField::new(4, vec![1, 1, 1]).err(), Some(GaloisFieldError::InvalidModulus));
+    assert_eq!(ExtensionField::new(2, vec![1, 1, 0, 0]).err(), Some(GaloisFieldError::InvalidModulus));
+    assert_eq!(ExtensionField::new(2, vec![1]).err(), Some(GaloisFieldError::InvalidModulus));
+  }
+
+  #[test]
+  fn test_large_characteristic() {
+    // the BN128 base field is 3 mod 4, so i^2 = -1 gives GF(p^2), where the norm of 1 + i is 2
+    let p = U256::from_dec_str("21888242871839275222246405745257275088696311157297823662689037894645226208583").unwrap();
+    let GFp2 = ExtensionField::new(p, vec![U256::one(), U256::zero(), U256::one()]).unwrap();
+    assert_eq!(GFp2.characteristic(), p);
+    let a = GFp2.element(vec![1, 1]);
+    assert_eq!(GFp2.norm(&a).value(), U256::from(2));
+    assert_eq!(GFp2.trace(&a).value(), U256::from(2));
+    // 3 * r has a 254-bit factor, out of reach of trial division
+    let r = U256::from_dec_str("21888242871839275222246405745257275088548364400416034343698204186575808495617").unwrap();
+    assert_eq!(ExtensionField::new(r * 3, vec![U256::one(), U256::one()]).err(), Some(GaloisFieldError::InvalidModulus));
+  }
+
+  #[test]
+  fn test_gf8_arithmetic() {
+    let GF8 = gf8();
+    let a = GF8.element(vec![0, 1]);
+    // a^3 = a + 1 and a^7 = 1
+    assert_eq!(GF8.pow(&a, U256::from(3)), GF8.element(vec![1, 1]));
+    assert_eq!(GF8.pow(&a, U256::from(7)), GF8.one());
+    assert_eq!(GF8.element(vec![0, 0, 0, 1]), GF8.element(vec![1, 1]));
+    assert_eq!(GF8.add(&a, &a), GF8.zero());
+    assert_eq!(GF8.frobenius(&a), GF8.element(vec![0, 0, 1]));
+  }
+
+  #[test]
+  fn test_gf8_trace() {
+    let GF8 = gf8();
+    // Tr(1) = 1 + 1 + 1, Tr(a) = a + a^2 + a^4 = a + a^2 + (a^2 + a)
+    let expected = [
+      (vec![0, 0, 0], 0),
+      (vec![1, 0, 0], 1),
+      (vec![0, 1, 0], 0),
+      (vec![0, 0, 1], 0),
+      (vec![1, 1, 0], 1),
+      (vec![1, 0, 1], 1),
+      (vec![0, 1, 1], 0),
+      (vec![1, 1, 1], 1),
+    ];
+    for (coefficients, trace) in expected {
+      assert_eq!(GF8.trace(&GF8.element(coefficients)).value(), U256::from(trace));
+    }
+  }
+
+  #[test]
+  fn test_gf8_norm() {
+    let GF8 = gf8();
+    // the norm maps the 7 non-zero elements onto GF(2)* = {1}
+    assert_eq!(GF8.norm(&GF8.zero()).value(), U256::zero());
+    for bits in 1..8u64 {
+      let element = GF8.element(vec![bits & 1, (bits >> 1) & 1, bits >> 2]);
+      assert_eq!(GF8.norm(&element).value(), U256::one());
+    }
+  }
+
+  #[test]
+  fn test_gf9_trace_and_norm() {
+    // GF(3^2) = GF(3)[i] / (i^2 + 1): Tr(a + bi) = 2a and N(a + bi) = a^2 + b^2
+    let GF9 = ExtensionField::new(3, vec![1, 0, 1]).unwrap();
+    for a in 0..3u64 {
+      for b in 0..3u64 {
+        let element = GF9.element(vec![a, b]);
+        assert_eq!(GF9.trace(&element).value(), U256::from(2 * a % 3));
+        assert_eq!(GF9.norm(&element).value(), U256::from((a * a + b * b) % 3));
+      }
+    }
+  }
+}
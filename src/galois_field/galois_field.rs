@@ -1,79 +1,191 @@
-use crate::mod_math::{ModMath, IntoU256};
+use crate::mod_math::{ModMath, FieldParams, IntoU256};
+use std::sync::Arc;
 use crate::number_mod::{NumberUnderMod as NM};
 use primitive_types::U256;
-use std::collections::HashMap;
-use std::error::Error;
+use crate::random::RandomSource;
 
-#[derive(Debug)]
+/// Fields compare equal when they have the same size. Cloning a field, and every
+/// element created from it, shares the precomputed arithmetic of the field.
+#[derive(Debug, Clone)]
 pub struct GaloisField {
-    modulus: U256,
+    math: ModMath,
 }
 
+impl PartialEq for GaloisField {
+    fn eq(&self, other: &Self) -> bool {
+        self.math.modulus == other.math.modulus
+    }
+}
+
+impl Eq for GaloisField {}
+
 pub struct GaloisFieldPolynomial {
     polynomial: Vec<U256>,
 }
 
+impl GaloisFieldPolynomial {
+    /// Returns the coefficients of the polynomial, lowest degree first.
+    pub fn coefficients(&self) -> &[U256] {
+        &self.polynomial
+    }
+}
+
 impl GaloisField {
 
     pub fn new<T: IntoU256>(modulus: T) -> Option<Self> { // TODO: Change to Result<Self, Err>
         let modulus = modulus.into_u256();
         if Self::is_valid_galois_field_size(modulus) {
-            return Some(Self { modulus });
+            Some(Self { math: ModMath::new(modulus) })
         } else {
             None
         }
     }
 
-    pub fn gf(&self, value: U256) -> NM {
-        NM::new(value, self.modulus)
+    /// Creates an element of the field, reducing the value modulo the field size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::galois_field::GaloisField;
+    ///
+    /// let GF7 = GaloisField::new(7).unwrap();
+    /// assert_eq!(GF7.gf(12), GF7.gf("5"));
+    /// ```
+    pub fn gf<T: IntoU256>(&self, value: T) -> NM {
+        NM::with_math(value, &self.math)
     }
 
-    pub fn poly<T: IntoU256>(&self, coefficients: Vec<T>) -> GaloisFieldPolynomial {
-        todo!(
-            "Implement Galois Polynomial Function"
-        )
+    /// Creates an element of the field like `gf`, but returns
+    /// `GaloisFieldError::ValueOutOfRange` instead of reducing a value that is not
+    /// below the field size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::galois_field::{GaloisField, GaloisFieldError};
+    ///
+    /// let GF7 = GaloisField::new(7).unwrap();
+    /// assert!(GF7.gf_strict(5).is_ok());
+    /// assert_eq!(GF7.gf_strict(12).err(), Some(GaloisFieldError::ValueOutOfRange));
+    /// ```
+    pub fn gf_strict<T: IntoU256>(&self, value: T) -> Result<NM, GaloisFieldError> {
+        let value = value.into_u256();
+        if value >= self.math.modulus {
+            return Err(GaloisFieldError::ValueOutOfRange);
+        }
+        Ok(NM::with_math(value, &self.math))
     }
 
+    /// Returns the multiplicative inverse of `value` as an element of the field, or `None`
+    /// if `value` is zero in the field.
+    ///
+    /// This only works for prime fields: for a field of size `p^k` with `k > 1` the
+    /// arithmetic is not yet that of the extension field, and `None` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::galois_field::GaloisField;
+    /// use primitive_types::U256;
+    ///
+    /// let GF7 = GaloisField::new(7).unwrap();
+    /// assert_eq!(GF7.inverse(U256::from(3)), Some(GF7.gf(5)));
+    /// assert_eq!(GF7.inverse(U256::from(14)), None);
+    /// ```
+    pub fn inverse(&self, value: U256) -> Option<NM> {
+        if !self.math.is_prime_modulus() {
+            return None;
+        }
+        self.math.inv(value).map(|inverse| NM::with_math(inverse, &self.math))
+    }
 
-    fn prime_factors(mut n: U256) -> HashMap<U256, U256> {
-        let mut factors = HashMap::new();
-        let mut count: U256;
+    /// Returns a uniformly random element of the field, by rejection sampling against
+    /// the field size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::galois_field::GaloisField;
+    /// use primitive_types::U256;
+    ///
+    /// let GF7 = GaloisField::new(7).unwrap();
+    /// let element = GF7.random(&mut rand::thread_rng());
+    /// assert!(element.value() < U256::from(7));
+    /// ```
+    pub fn random<R: RandomSource + ?Sized>(&self, rng: &mut R) -> NM {
+        NM::with_math(self.math.random(rng), &self.math)
+    }
 
-        count = U256::zero();
-        while n % U256::from(2) == U256::zero() {
-            count += U256::one();
-            n /= U256::from(2);
-        }
-        if count > U256::zero() {
-            factors.insert(U256::from(2), count);
-        }
+    /// Returns a uniformly random non-zero element of the field, like `random`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::galois_field::GaloisField;
+    /// use primitive_types::U256;
+    ///
+    /// let GF7 = GaloisField::new(7).unwrap();
+    /// assert_ne!(GF7.random_nonzero(&mut rand::thread_rng()).value(), U256::zero());
+    /// ```
+    pub fn random_nonzero<R: RandomSource + ?Sized>(&self, rng: &mut R) -> NM {
+        NM::with_math(self.math.random_nonzero(rng), &self.math)
+    }
 
-        let mut i = U256::from(3);
-        while i * i <= n {
-            count = U256::zero();
-            while n % i == U256::zero() {
-                count += U256::one();
-                n /= i;
-            }
-            if count > U256::zero() {
-                factors.insert(i, count);
-            }
-            i += U256::from(2);
-        }
+    /// Checks if `value` is a canonical element of the field, i.e. below the field size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::galois_field::GaloisField;
+    /// use primitive_types::U256;
+    ///
+    /// let GF7 = GaloisField::new(7).unwrap();
+    /// assert!(GF7.contains(U256::from(6)));
+    /// assert!(!GF7.contains(U256::from(7)));
+    /// ```
+    pub fn contains(&self, value: U256) -> bool {
+        value < self.math.modulus
+    }
+
+    /// Returns the parameters of the field, shared with every element created from it.
+    pub fn params(&self) -> &Arc<FieldParams> {
+        self.math.params()
+    }
 
-        if n > U256::from(2) {
-            factors.insert(n, U256::from(1));
+    /// Creates a polynomial over the field with the coefficients reduced modulo the field size.
+    pub fn poly<T: IntoU256>(&self, coefficients: Vec<T>) -> GaloisFieldPolynomial {
+        GaloisFieldPolynomial {
+            polynomial: coefficients.into_iter().map(|c| self.math.reduce(c)).collect()
         }
-    
-        factors
     }
-    
+
+
+    // a field has at least two elements, which also keeps a zero modulus out of `ModMath`
     fn is_valid_galois_field_size(n: U256) -> bool {
-        let factors = Self::prime_factors(n);
+        if n < U256::from(2) {
+            return false;
+        }
+        let factors = ModMath::prime_factors(n);
         factors.len() == 1 && factors.values().all(|&count| count >= U256::from(1))
     }
 }
 
+/// Errors returned by the fallible `GaloisField` operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum GaloisFieldError {
-    InvalidModulus
-}
\ No newline at end of file
+    /// The modulus is not a valid field size
+    InvalidModulus,
+    /// The value is not below the field size
+    ValueOutOfRange,
+}
+
+impl std::fmt::Display for GaloisFieldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GaloisFieldError::InvalidModulus => write!(f, "the modulus is not a valid field size"),
+            GaloisFieldError::ValueOutOfRange => write!(f, "the value is not below the field size"),
+        }
+    }
+}
+
+impl std::error::Error for GaloisFieldError {}
@@ -1,13 +1,141 @@
 #[cfg(test)]
+#[allow(non_snake_case)]
 mod tests {
-  use crate::galois_field::GaloisField;
+  use crate::galois_field::{GaloisField, GaloisFieldError};
+  use crate::number_mod::NumModError;
   use primitive_types::U256;
+  use rand::{rngs::StdRng, SeedableRng};
 
   #[test]
   fn test_new_galois_field() {
     let GF7 = GaloisField::new(7).unwrap();
     dbg!(GF7);
+    // neither is a field size, and 0 used to hang in the factorization
+    assert!(GaloisField::new(0).is_none());
+    assert!(GaloisField::new(1).is_none());
+    assert!(GaloisField::new(6).is_none());
+    assert!(GaloisField::new(9).is_some());
   }
 
-  
-}
\ No newline at end of file
+  #[test]
+  fn test_gf() {
+    let GF101 = GaloisField::new(101).unwrap();
+    assert_eq!(GF101.gf(101 + 5), GF101.gf(5));
+    assert_eq!(GF101.gf(U256::from(5)).value(), U256::from(5));
+    assert_eq!(GF101.gf("106"), GF101.gf(5));
+    assert_eq!(GF101.gf(106_u64), GF101.gf(5));
+  }
+
+  #[test]
+  fn test_gf_strict() {
+    let GF101 = GaloisField::new(101).unwrap();
+    assert_eq!(GF101.gf_strict(5), Ok(GF101.gf(5)));
+    assert_eq!(GF101.gf_strict(100), Ok(GF101.gf(100)));
+    assert_eq!(GF101.gf_strict(101 + 5), Err(GaloisFieldError::ValueOutOfRange));
+    assert_eq!(GF101.gf_strict(101), Err(GaloisFieldError::ValueOutOfRange));
+  }
+
+  #[test]
+  fn test_equality() {
+    assert_eq!(GaloisField::new(101).unwrap(), GaloisField::new(U256::from(101)).unwrap());
+    assert_ne!(GaloisField::new(101).unwrap(), GaloisField::new(103).unwrap());
+    assert_ne!(GaloisField::new(8).unwrap(), GaloisField::new(2).unwrap());
+  }
+
+  #[test]
+  fn test_inverse() {
+    let GF7 = GaloisField::new(7).unwrap();
+    for a in 1..7u32 {
+      let inverse = GF7.inverse(U256::from(a)).unwrap();
+      assert_eq!((GF7.gf(a) * inverse).unwrap(), GF7.gf(1));
+    }
+    assert_eq!(GF7.inverse(U256::zero()), None);
+    assert_eq!(GF7.inverse(U256::from(7)), None);
+
+    // GF(9) is not a prime field
+    assert_eq!(GaloisField::new(9).unwrap().inverse(U256::from(2)), None);
+  }
+
+  #[test]
+  fn test_random() {
+    let mut rng = StdRng::seed_from_u64(975);
+    let GF7 = GaloisField::new(7).unwrap();
+    let mut seen = [false; 7];
+    for _ in 0..500 {
+      let element = GF7.random(&mut rng);
+      assert!(element.value() < U256::from(7));
+      assert_eq!(element.modulus(), U256::from(7));
+      seen[element.value().as_usize()] = true;
+      assert_ne!(GF7.random_nonzero(&mut rng).value(), U256::zero());
+    }
+    assert!(seen.iter().all(|&s| s));
+
+    // GF(2) has a single non-zero element
+    let GF2 = GaloisField::new(2).unwrap();
+    assert!((0..50).all(|_| GF2.random_nonzero(&mut rng).value() == U256::one()));
+
+    // 2^32 + 15, wide enough that the shift drops most of the sampled bytes
+    let p = U256::from(4294967311u64);
+    let GFp = GaloisField::new(p).unwrap();
+    for _ in 0..100 {
+      assert!(GFp.random(&mut rng).value() < p);
+    }
+  }
+
+  #[test]
+  fn test_tiny_fields() {
+    let GF2 = GaloisField::new(2).unwrap();
+    assert_eq!((GF2.gf(1) + GF2.gf(1)).unwrap(), GF2.gf(0));
+    assert_eq!((-GF2.gf(1)).unwrap(), GF2.gf(1));
+    assert_eq!(GF2.inverse(U256::one()), Some(GF2.gf(1)));
+    assert_eq!(GF2.inverse(U256::zero()), None);
+    // halving is undefined in characteristic 2
+    assert_eq!(GF2.gf(1) / 2, Err(NumModError::NotInvertible));
+    assert!(GF2.contains(U256::one()));
+    assert!(!GF2.contains(U256::from(2)));
+
+    let GF3 = GaloisField::new(3).unwrap();
+    for a in 0..3u32 {
+      for b in 0..3u32 {
+        assert_eq!((GF3.gf(a) * GF3.gf(b)).unwrap(), GF3.gf(a * b));
+        assert_eq!((GF3.gf(a) - GF3.gf(b)).unwrap(), GF3.gf(a + 3 - b));
+      }
+    }
+    assert_eq!(GF3.inverse(U256::from(2)), Some(GF3.gf(2)));
+    assert_eq!(GF3.gf(1) / 2, Ok(GF3.gf(2)));
+  }
+
+  #[test]
+  fn test_contains() {
+    let GF101 = GaloisField::new(101).unwrap();
+    assert!(GF101.contains(U256::zero()));
+    assert!(GF101.contains(U256::from(100)));
+    assert!(!GF101.contains(U256::from(101)));
+    assert!(!GF101.contains(U256::MAX));
+    // agrees with gf_strict
+    for value in [0u64, 50, 100, 101, 202] {
+      assert_eq!(GF101.contains(U256::from(value)), GF101.gf_strict(value).is_ok());
+    }
+  }
+
+  #[test]
+  fn test_poly_reduces_coefficients() {
+    let GF7 = GaloisField::new(7).unwrap();
+    let poly = GF7.poly(vec![3, 7, 15]);
+    assert_eq!(poly.coefficients(), &[U256::from(3), U256::zero(), U256::one()]);
+  }
+
+  #[test]
+  fn test_elements_share_the_field() {
+    let GF101 = GaloisField::new(101).unwrap();
+    let elements: Vec<_> = (0..5000u64).map(|value| GF101.gf(value)).collect();
+    assert_eq!(elements[5000 - 1].value(), U256::from(4999 % 101));
+    // one set of field parameters for the field and all its elements
+    assert_eq!(std::sync::Arc::strong_count(GF101.params()), 5001);
+    // an element stays a value, the modulus and a pointer, however many share the field
+    // (operation counters add to it when instrumentation is enabled)
+    #[cfg(not(feature = "instrumentation"))]
+    assert!(std::mem::size_of_val(&elements[0]) <= 96);
+    assert_eq!(GF101.clone(), GF101);
+  }
+}
@@ -1,5 +1,9 @@
+#[allow(clippy::module_inception)]
 mod galois_field;
+mod extension_field;
 
-pub use galois_field::GaloisField;
+pub use galois_field::{GaloisField, GaloisFieldError, GaloisFieldPolynomial};
+pub use extension_field::{ExtensionField, ExtensionFieldElement};
 
-mod galois_field_test;
\ No newline at end of file
+mod galois_field_test;
+mod extension_field_test;
@@ -0,0 +1,193 @@
+use primitive_types::U256;
+use crate::random::RandomSource;
+
+use crate::curves::{Curve, ECPoint};
+use crate::mod_math::ModMath;
+
+/// `PrimeGroup` is a cyclic group of prime order, written additively.
+///
+/// It lets protocols such as Schnorr or Diffie-Hellman be written once and run over
+/// both a prime-order subgroup of a prime field (`MultiplicativeSubgroup`) and an
+/// elliptic curve (`Curve`). For the multiplicative group `op` is multiplication and
+/// `scalar_mul` is exponentiation.
+pub trait PrimeGroup {
+    /// Integers modulo the group order
+    type Scalar: Copy + PartialEq + std::fmt::Debug;
+    /// Elements of the group
+    type Element: Copy + PartialEq + std::fmt::Debug;
+
+    /// Returns the fixed generator of the group.
+    fn generator(&self) -> Self::Element;
+
+    /// Returns the identity element.
+    fn identity(&self) -> Self::Element;
+
+    /// Applies the group operation to two elements.
+    fn op(&self, a: &Self::Element, b: &Self::Element) -> Self::Element;
+
+    /// Applies the group operation `k` times to `a`.
+    fn scalar_mul(&self, k: &Self::Scalar, a: &Self::Element) -> Self::Element;
+
+    /// Adds two scalars modulo the group order.
+    fn add_scalars(&self, a: &Self::Scalar, b: &Self::Scalar) -> Self::Scalar;
+
+    /// Multiplies two scalars modulo the group order.
+    fn mul_scalars(&self, a: &Self::Scalar, b: &Self::Scalar) -> Self::Scalar;
+
+    /// Returns a uniformly random non-zero scalar.
+    fn random_scalar<R: RandomSource + ?Sized>(&self, rng: &mut R) -> Self::Scalar;
+
+    /// Encodes an element as bytes, e.g. for hashing into a transcript.
+    fn element_to_bytes(&self, a: &Self::Element) -> Vec<u8>;
+
+    /// Encodes a scalar as bytes.
+    fn scalar_to_bytes(&self, k: &Self::Scalar) -> Vec<u8>;
+}
+
+/// `MultiplicativeSubgroup` is the subgroup of prime order `q` generated by `g`
+/// in the multiplicative group modulo a prime `p`.
+pub struct MultiplicativeSubgroup {
+    p: ModMath,
+    q: ModMath,
+    order: U256,
+    g: U256,
+}
+
+impl MultiplicativeSubgroup {
+    /// Creates the subgroup generated by `g`.
+    ///
+    /// Returns `None` if `q` does not divide `p - 1` or `g` does not have order `q`.
+    /// The primality of `p` and `q` is not checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::group::{MultiplicativeSubgroup, PrimeGroup};
+    /// use primitive_types::U256;
+    ///
+    /// // 4 has order 11 modulo 23
+    /// let group = MultiplicativeSubgroup::new(U256::from(23), U256::from(11), U256::from(4)).unwrap();
+    /// assert_eq!(group.scalar_mul(&U256::from(11), &group.generator()), group.identity());
+    /// ```
+    pub fn new(p: U256, q: U256, g: U256) -> Option<Self> {
+        if p <= U256::one() || q <= U256::one() || (p - U256::one()) % q != U256::zero() {
+            return None;
+        }
+        let p_math = ModMath::new(p);
+        let g = p_math.reduce(g);
+        // q is prime, so g has order q exactly when g != 1 and g^q = 1
+        if g == U256::one() || p_math.exp(g, q) != U256::one() {
+            return None;
+        }
+        Some(MultiplicativeSubgroup { p: p_math, q: ModMath::new(q), order: q, g })
+    }
+
+    /// Returns the order `q` of the subgroup.
+    pub fn order(&self) -> U256 {
+        self.order
+    }
+}
+
+impl PrimeGroup for MultiplicativeSubgroup {
+    type Scalar = U256;
+    type Element = U256;
+
+    fn generator(&self) -> U256 {
+        self.g
+    }
+
+    fn identity(&self) -> U256 {
+        U256::one()
+    }
+
+    fn op(&self, a: &U256, b: &U256) -> U256 {
+        self.p.mul(*a, *b)
+    }
+
+    fn scalar_mul(&self, k: &U256, a: &U256) -> U256 {
+        self.p.exp(*a, *k)
+    }
+
+    fn add_scalars(&self, a: &U256, b: &U256) -> U256 {
+        self.q.add(*a, *b)
+    }
+
+    fn mul_scalars(&self, a: &U256, b: &U256) -> U256 {
+        self.q.mul(*a, *b)
+    }
+
+    fn random_scalar<R: RandomSource + ?Sized>(&self, rng: &mut R) -> U256 {
+        random_nonzero_below(self.order, rng)
+    }
+
+    fn element_to_bytes(&self, a: &U256) -> Vec<u8> {
+        to_bytes(a)
+    }
+
+    fn scalar_to_bytes(&self, k: &U256) -> Vec<u8> {
+        to_bytes(k)
+    }
+}
+
+/// The group of points of a curve whose `curve_order` is prime, with `G` as generator.
+impl PrimeGroup for Curve {
+    type Scalar = U256;
+    type Element = ECPoint;
+
+    fn generator(&self) -> ECPoint {
+        self.G
+    }
+
+    fn identity(&self) -> ECPoint {
+        ECPoint::identity()
+    }
+
+    fn op(&self, a: &ECPoint, b: &ECPoint) -> ECPoint {
+        self.add_points(a, b)
+    }
+
+    fn scalar_mul(&self, k: &U256, a: &ECPoint) -> ECPoint {
+        self.point_multiplication_scalar(*k % self.curve_order, *a)
+    }
+
+    fn add_scalars(&self, a: &U256, b: &U256) -> U256 {
+        self.scalar_math().add(*a, *b)
+    }
+
+    fn mul_scalars(&self, a: &U256, b: &U256) -> U256 {
+        self.scalar_math().mul(*a, *b)
+    }
+
+    fn random_scalar<R: RandomSource + ?Sized>(&self, rng: &mut R) -> U256 {
+        Curve::random_scalar(self, rng)
+    }
+
+    fn element_to_bytes(&self, a: &ECPoint) -> Vec<u8> {
+        let mut bytes = to_bytes(&a.x);
+        bytes.extend(to_bytes(&a.y));
+        bytes
+    }
+
+    fn scalar_to_bytes(&self, k: &U256) -> Vec<u8> {
+        to_bytes(k)
+    }
+}
+
+// Rejection sampling over the bit length of `bound`, which keeps the result unbiased
+fn random_nonzero_below<R: RandomSource + ?Sized>(bound: U256, rng: &mut R) -> U256 {
+    let bits = bound.bits();
+    loop {
+        let mut bytes = [0u8; 32];
+        rng.fill_bytes(&mut bytes);
+        let candidate = U256::from_big_endian(&bytes) >> (256 - bits);
+        if candidate != U256::zero() && candidate < bound {
+            return candidate;
+        }
+    }
+}
+
+fn to_bytes(value: &U256) -> Vec<u8> {
+    let mut bytes = vec![0u8; 32];
+    value.to_big_endian(&mut bytes);
+    bytes
+}
@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod tests {
+    use primitive_types::U256;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::curves::{ECPoint, Secp256k1, BN128};
+    use crate::group::{MultiplicativeSubgroup, PrimeGroup, Schnorr};
+
+    // p = 2q + 1 with q prime, and 4 = 2^2 generates the subgroup of order q
+    fn multiplicative_subgroup() -> MultiplicativeSubgroup {
+        let p = U256::from_dec_str("340282366920938463463374607431768223907").unwrap();
+        let q = U256::from_dec_str("170141183460469231731687303715884111953").unwrap();
+        MultiplicativeSubgroup::new(p, q, U256::from(4)).unwrap()
+    }
+
+    fn schnorr<G: PrimeGroup>(group: &G) {
+        let mut rng = StdRng::seed_from_u64(11);
+        let schnorr = Schnorr::new(group);
+        let secret = group.random_scalar(&mut rng);
+        let public = schnorr.public_key(&secret);
+
+        let (nonce, commitment) = schnorr.commit(&mut rng);
+        let challenge = group.random_scalar(&mut rng);
+        let response = schnorr.respond(&secret, &nonce, &challenge);
+        assert!(schnorr.verify(&public, &commitment, &challenge, &response));
+
+        let other_secret = group.add_scalars(&secret, &group.random_scalar(&mut rng));
+        let wrong_response = schnorr.respond(&other_secret, &nonce, &challenge);
+        assert!(!schnorr.verify(&public, &commitment, &challenge, &wrong_response));
+        assert!(!schnorr.verify(&group.generator(), &commitment, &challenge, &response));
+    }
+
+    #[test]
+    fn test_schnorr_multiplicative_subgroup() {
+        schnorr(&multiplicative_subgroup());
+    }
+
+    #[test]
+    fn test_schnorr_curves() {
+        schnorr(&Secp256k1());
+        schnorr(&BN128());
+    }
+
+    #[test]
+    fn test_multiplicative_subgroup() {
+        let group = multiplicative_subgroup();
+        let g = group.generator();
+        assert_eq!(group.scalar_mul(&group.order(), &g), group.identity());
+        assert_eq!(group.op(&g, &group.identity()), g);
+
+        // 7 does not divide 22, and 5 has order 22 modulo 23
+        assert!(MultiplicativeSubgroup::new(U256::from(23), U256::from(7), U256::from(4)).is_none());
+        assert!(MultiplicativeSubgroup::new(U256::from(23), U256::from(11), U256::from(5)).is_none());
+        assert!(MultiplicativeSubgroup::new(U256::from(23), U256::from(11), U256::one()).is_none());
+        assert!(MultiplicativeSubgroup::new(U256::from(23), U256::from(11), U256::from(4)).is_some());
+    }
+
+    #[test]
+    fn test_curve_identity() {
+        let curve = BN128();
+        let g = curve.generator();
+        let minus_g = ECPoint::new(g.x, curve.field_modulus - g.y);
+        assert_eq!(curve.op(&g, &curve.identity()), g);
+        assert_eq!(curve.op(&curve.identity(), &g), g);
+        assert!(curve.op(&g, &minus_g).is_identity());
+        assert_eq!(curve.scalar_mul(&U256::zero(), &g), curve.identity());
+    }
+
+    #[test]
+    fn test_random_scalar_in_range() {
+        let group = MultiplicativeSubgroup::new(U256::from(23), U256::from(11), U256::from(4)).unwrap();
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut seen = [false; 11];
+        for _ in 0..500 {
+            let k = group.random_scalar(&mut rng);
+            assert!(k < U256::from(11));
+            seen[k.as_usize()] = true;
+        }
+        assert!(!seen[0]);
+        assert!(seen[1..].iter().all(|&s| s));
+    }
+}
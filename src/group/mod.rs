@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+mod group;
+mod schnorr;
+mod group_test;
+pub use group::{MultiplicativeSubgroup, PrimeGroup};
+pub use schnorr::Schnorr;
@@ -0,0 +1,60 @@
+use crate::random::RandomSource;
+
+use super::group::PrimeGroup;
+
+/// Schnorr's sigma protocol for proving knowledge of `x` such that `public = x * G`,
+/// generic over any `PrimeGroup`.
+///
+/// The prover sends a commitment `t = r * G`, the verifier answers with a random
+/// challenge `c` and the prover responds with `s = r + c * x`. The verifier accepts
+/// if `s * G = t + c * public`.
+///
+/// # Examples
+///
+/// ```
+/// use modular_math::curves::Secp256k1;
+/// use modular_math::group::{PrimeGroup, Schnorr};
+///
+/// let curve = Secp256k1();
+/// let mut rng = rand::thread_rng();
+/// let secret = curve.random_scalar(&mut rng);
+/// let schnorr = Schnorr::new(&curve);
+/// let public = schnorr.public_key(&secret);
+///
+/// let (nonce, commitment) = schnorr.commit(&mut rng);
+/// let challenge = curve.random_scalar(&mut rng);
+/// let response = schnorr.respond(&secret, &nonce, &challenge);
+/// assert!(schnorr.verify(&public, &commitment, &challenge, &response));
+/// ```
+pub struct Schnorr<'a, G: PrimeGroup> {
+    group: &'a G,
+}
+
+impl<'a, G: PrimeGroup> Schnorr<'a, G> {
+    pub fn new(group: &'a G) -> Self {
+        Schnorr { group }
+    }
+
+    /// Returns the public key `secret * G`.
+    pub fn public_key(&self, secret: &G::Scalar) -> G::Element {
+        self.group.scalar_mul(secret, &self.group.generator())
+    }
+
+    /// Picks a random nonce `r` and returns it with the commitment `r * G`.
+    pub fn commit<R: RandomSource + ?Sized>(&self, rng: &mut R) -> (G::Scalar, G::Element) {
+        let nonce = self.group.random_scalar(rng);
+        (nonce, self.public_key(&nonce))
+    }
+
+    /// Computes the response `nonce + challenge * secret`.
+    pub fn respond(&self, secret: &G::Scalar, nonce: &G::Scalar, challenge: &G::Scalar) -> G::Scalar {
+        self.group.add_scalars(nonce, &self.group.mul_scalars(challenge, secret))
+    }
+
+    /// Checks that `response * G = commitment + challenge * public`.
+    pub fn verify(&self, public: &G::Element, commitment: &G::Element, challenge: &G::Scalar, response: &G::Scalar) -> bool {
+        let lhs = self.group.scalar_mul(response, &self.group.generator());
+        let rhs = self.group.op(commitment, &self.group.scalar_mul(challenge, public));
+        lhs == rhs
+    }
+}
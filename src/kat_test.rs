@@ -0,0 +1,92 @@
+//! Known-answer tests for the numeric core.
+//!
+//! The vectors in `kat_vectors.rs` are generated by `scripts/kat_vectors.py` from Python's
+//! integers. Every vector is checked and all failures are reported together, each with the
+//! name of its table and its index, so a wrong value points at exactly one vector.
+
+#[cfg(test)]
+mod tests {
+    use primitive_types::U256;
+
+    use crate::curves::{Curve, ECPoint, Secp256k1, BN128};
+    use crate::kat_vectors::*;
+    use crate::mod_math::{ModMath, RootConvention};
+
+    type Binary = [(&'static str, &'static str, &'static str)];
+    type Unary = [(&'static str, &'static str)];
+
+    fn hex(s: &str) -> U256 {
+        U256::from_str_radix(s, 16).unwrap()
+    }
+
+    // An empty string stands for no answer
+    fn optional_hex(s: &str) -> Option<U256> {
+        (!s.is_empty()).then(|| hex(s))
+    }
+
+    #[derive(Default)]
+    struct Failures(Vec<String>);
+
+    impl Failures {
+        fn check<T: PartialEq + std::fmt::Debug>(&mut self, table: &str, index: usize, actual: T, expected: T) {
+            if actual != expected {
+                self.0.push(format!("{}[{}]: expected {:?}, got {:?}", table, index, expected, actual));
+            }
+        }
+
+        fn assert_none(self) {
+            assert!(self.0.is_empty(), "{} known-answer vectors failed:\n{}", self.0.len(), self.0.join("\n"));
+        }
+    }
+
+    fn check_mod_math(failures: &mut Failures, name: &str, p: U256, tables: (&Binary, &Binary, &Unary, &Unary)) {
+        let (mul, exp, inv, sqrt) = tables;
+        let math = ModMath::new(p);
+        for (i, &(a, b, expected)) in mul.iter().enumerate() {
+            failures.check(&format!("{}_MUL", name), i, math.mul(hex(a), hex(b)), hex(expected));
+        }
+        for (i, &(a, e, expected)) in exp.iter().enumerate() {
+            failures.check(&format!("{}_EXP", name), i, math.exp(hex(a), hex(e)), hex(expected));
+        }
+        for (i, &(a, expected)) in inv.iter().enumerate() {
+            failures.check(&format!("{}_INV", name), i, math.inv(hex(a)), optional_hex(expected));
+        }
+        for (i, &(a, expected)) in sqrt.iter().enumerate() {
+            let root = math.sqrt_normalized(hex(a), RootConvention::Smaller);
+            failures.check(&format!("{}_SQRT", name), i, root, optional_hex(expected));
+        }
+    }
+
+    fn check_scalar_mul(failures: &mut Failures, name: &str, curve: &Curve, table: &Binary) {
+        for (i, &(k, x, y)) in table.iter().enumerate() {
+            let actual = curve.scalar_multiply_generator(hex(k));
+            failures.check(&format!("{}_SCALAR_MUL", name), i, actual, ECPoint::new(hex(x), hex(y)));
+        }
+    }
+
+    #[test]
+    fn test_mod_math_known_answers() {
+        let mut failures = Failures::default();
+        let bn128 = (&BN128_MUL[..], &BN128_EXP[..], &BN128_INV[..], &BN128_SQRT[..]);
+        check_mod_math(&mut failures, "BN128", BN128().field_modulus, bn128);
+        let secp256k1 = (&SECP256K1_MUL[..], &SECP256K1_EXP[..], &SECP256K1_INV[..], &SECP256K1_SQRT[..]);
+        check_mod_math(&mut failures, "SECP256K1", Secp256k1().field_modulus, secp256k1);
+        failures.assert_none();
+    }
+
+    #[test]
+    fn test_scalar_multiplication_known_answers() {
+        let mut failures = Failures::default();
+        check_scalar_mul(&mut failures, "BN128", &BN128(), &BN128_SCALAR_MUL);
+        check_scalar_mul(&mut failures, "SECP256K1", &Secp256k1(), &SECP256K1_SCALAR_MUL);
+        failures.assert_none();
+    }
+
+    #[test]
+    fn test_failures_name_the_vector() {
+        let mut failures = Failures::default();
+        failures.check("BN128_MUL", 3, U256::one(), U256::one());
+        failures.check("BN128_MUL", 7, U256::one(), U256::zero());
+        assert_eq!(failures.0, vec!["BN128_MUL[7]: expected 0, got 1".to_string()]);
+    }
+}
@@ -0,0 +1,285 @@
+// Generated by scripts/kat_vectors.py, do not edit.
+//
+// Numbers are hex strings. An empty expected value means no answer (no inverse, no
+// square root) and the point at infinity is (0, 0).
+
+pub(crate) const BN128_MUL: [(&str, &str, &str); 25] = [
+  ("0", "2c7caa86943cbfc4c00dbd0c11a7dce1c81659fad70e20ed1269fc806599e9bf", "0"),
+  ("1", "f9e313b76ba332ebd2db7e7654404724da01b08e9eb6131242fe64c60ae6450", "f9e313b76ba332ebd2db7e7654404724da01b08e9eb6131242fe64c60ae6450"),
+  ("30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd46", "2a5637907ce38415677ee89e75acbcbe4a1981551c7ad3a5223e402a1b75d2f0", "60e16e2644e1c1450d15d180bd49b9f4d67e93c4bf6f6e819e24becbd072a57"),
+  ("a061edfb33bce04afc16957daa33e3ab9548d3e0db7b29392070fc82c44de3c", "a0d5f3b2d77e1d0b52cf49bece79ac540480087094358f3b36e52324e44c9c2", "23ae107298f413638a38abbbaf44e1b512a7511ffdc5a5a3d6a9764831925c44"),
+  ("127c2b954b1945ceed450c3eb2347c09ed014120b65a6c36e615f3a48a95d566", "22c49ab2313d2614eee8448943a39c0bf68acef2dff7ac7dfac1457143b463ab", "5097a606088b1ac192d9effdbf4b7b643cb38221d447b424b53b01923acb7a1"),
+  ("9b510548330dc6f5bec4ef10494168db13ef87a92fa9d2fd00db3a49a727fb8", "2443f29df3540aeb1505e86b157aaae35b36ee5308757cf5904cccad93039e6f", "14a5233bd5162d01352f70b03e90d1124bbc87de701c84961070736c5e2f423e"),
+  ("e321c4847bb9d9459d5f07fe883520f345a02601f9bdee8c25150961025e68c", "c1452b6698baa279ae5d7629d022a35ec3bf1ced0e1147659e5e78108ca91a", "2ea4310772e41004783f64a06e8dce7ecce4534f3b9699b72df6ad3b388603d8"),
+  ("2443f29df3540aeb1505e86b157aaae35b36ee5308757cf5904cccad93039e6f", "165b4ca3199af201d1bafa9cc5238f95ab8c4552b41505d62bf622e32903cb7c", "1a35a6ea45cded1c8b1d035e5aaa1479db7be04271db8c823754b51a18968ec4"),
+  ("395b206e13a47aef55b9b2974929c7c932a428d1ab5d99516e533358d22909", "bf996ba69fae795e1f992577ec909aa74160ec2953e83d355138dc64e77d01f", "ccc8f341697f1b2a7bc70c9ca35f98860be22d2b781e2a788cd51e65cd8ad79"),
+  ("bf996ba69fae795e1f992577ec909aa74160ec2953e83d355138dc64e77d01f", "a061edfb33bce04afc16957daa33e3ab9548d3e0db7b29392070fc82c44de3c", "1cd640be77eada3c12c4dbccaa8e3e057b1bb710e801e6796023fda211977995"),
+  ("11674dad8e90e08f314f8ae54121ef7d78ec827d9c357d3b1b11696d1d24ebf8", "10d3a623981adf23bb57333c5904000e37d68c64a4d65939663f00aa93ef1ecb", "1b8af9fd18a6dea381ddff1bb602d8c30d5c0d7949ada7212064896af179a688"),
+  ("247cbb177b8c6f52fcfce2113b341ea55efd97c4c2360363538a38d3a63b18d6", "1", "247cbb177b8c6f52fcfce2113b341ea55efd97c4c2360363538a38d3a63b18d6"),
+  ("13c71f409535fe26e1ed5bee10ccb589b7b53dcdb8d793520ca3804745ef025a", "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd46", "1c9d2f324bfba202d662e9c870b4a2d3dfcc2cc3af9a373b2f7d0bcf928dfaed"),
+  ("10d3a623981adf23bb57333c5904000e37d68c64a4d65939663f00aa93ef1ecb", "0", "0"),
+  ("fdef1086d04be69d9ee8b1ee74e93034fa9c334207a94b49e640288112c58aa", "245c24b951bfa812e1400c967c0fc1e8b408d88ed03fef828a24e79fa041ca38", "1d6b8ad04c7a7e8f363c0e3826d2bc65b0575926f640ef402f2e69f9729f5f9a"),
+  ("245c24b951bfa812e1400c967c0fc1e8b408d88ed03fef828a24e79fa041ca38", "127c2b954b1945ceed450c3eb2347c09ed014120b65a6c36e615f3a48a95d566", "934cfed2367599f63210f093795099ac548cafc15fde9ea993cb08d79252d26"),
+  ("1228f2d1ec2de9e9a10f8b6e1f0d3fe315e0dae4c389b4f90524b6d84e17c2ac", "247cbb177b8c6f52fcfce2113b341ea55efd97c4c2360363538a38d3a63b18d6", "14f4f229be6ce408d20e2eb3cce603e4f6ec7c3742856f928ae6d23481e4373"),
+  ("c1452b6698baa279ae5d7629d022a35ec3bf1ced0e1147659e5e78108ca91a", "395b206e13a47aef55b9b2974929c7c932a428d1ab5d99516e533358d22909", "290bef8150029f714c9fa7ae40418eff5b650598aec4d3bdb602067e3909f73b"),
+  ("2a5637907ce38415677ee89e75acbcbe4a1981551c7ad3a5223e402a1b75d2f0", "1228f2d1ec2de9e9a10f8b6e1f0d3fe315e0dae4c389b4f90524b6d84e17c2ac", "29cb2bf0339d81de84206f1d09ad99b63da0e16410ab870c7d00527983200331"),
+  ("f2fe9c30992d6a701e9997cde7eff606b0bc3d6c6f116ac78919659a77d0b73", "13c71f409535fe26e1ed5bee10ccb589b7b53dcdb8d793520ca3804745ef025a", "18b6743d04fee6286c2f40283d6fabdf9de9a8229d69a1dfda41275ea333c413"),
+  ("22c49ab2313d2614eee8448943a39c0bf68acef2dff7ac7dfac1457143b463ab", "11674dad8e90e08f314f8ae54121ef7d78ec827d9c357d3b1b11696d1d24ebf8", "23ad77b11fdc0e6f3b4b5afa2d27cc6302a55fc50d04cd76ee8c50d28ce4ed5c"),
+  ("165b4ca3199af201d1bafa9cc5238f95ab8c4552b41505d62bf622e32903cb7c", "fdef1086d04be69d9ee8b1ee74e93034fa9c334207a94b49e640288112c58aa", "23943a00000495b1009a450000fbd3af47b2330b0dba0323f325c675f3038909"),
+  ("f9e313b76ba332ebd2db7e7654404724da01b08e9eb6131242fe64c60ae6450", "9b510548330dc6f5bec4ef10494168db13ef87a92fa9d2fd00db3a49a727fb8", "cffab9c23aa55d1ce76c1ec0ce8caf7c17daa2343c85c113ace7ccdbef1acc9"),
+  ("2c7caa86943cbfc4c00dbd0c11a7dce1c81659fad70e20ed1269fc806599e9bf", "f2fe9c30992d6a701e9997cde7eff606b0bc3d6c6f116ac78919659a77d0b73", "fd27972758d97b8e7e1468d366a41e6ff4285109f1392ea45726900316311f"),
+  ("a0d5f3b2d77e1d0b52cf49bece79ac540480087094358f3b36e52324e44c9c2", "e321c4847bb9d9459d5f07fe883520f345a02601f9bdee8c25150961025e68c", "26c7fa1381fafc41f5208e52bf39bdf77d3cc7882a731027c1bc65a516ad7b0f"),
+];
+
+pub(crate) const BN128_EXP: [(&str, &str, &str); 25] = [
+  ("f2fe9c30992d6a701e9997cde7eff606b0bc3d6c6f116ac78919659a77d0b73", "0", "1"),
+  ("a061edfb33bce04afc16957daa33e3ab9548d3e0db7b29392070fc82c44de3c", "1", "a061edfb33bce04afc16957daa33e3ab9548d3e0db7b29392070fc82c44de3c"),
+  ("2a5637907ce38415677ee89e75acbcbe4a1981551c7ad3a5223e402a1b75d2f0", "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd45", "222aa4b548453489b0ebe0f8741446d03fefd8446d98fe7a75518dfe3e31f098"),
+  ("f9e313b76ba332ebd2db7e7654404724da01b08e9eb6131242fe64c60ae6450", "f86629cc5d29da15d7615ada3070dbf7ff4098d9f86f9b4670a8ff8188f1a054", "16d3b7c9d397cfc876765a3bb418c0b54efc9414c9df49fbf2fd3167de36ee4a"),
+  ("247cbb177b8c6f52fcfce2113b341ea55efd97c4c2360363538a38d3a63b18d6", "8770585127aa80782aa3c7facc8af570fcd459f7cba060948053a53078c85502", "18c5a5f1dbf632f89a9a28fad8a1f9e24048b02ac9749ac054fb87ef0bea1a6a"),
+  ("c1452b6698baa279ae5d7629d022a35ec3bf1ced0e1147659e5e78108ca91a", "c72211c9943ba50d534085222b302b8a10a48ce754b061a5df6feb6808b7c898", "d3c881c4b8d9e641e095bca3d567fab2e1186666498bf6dad897a04551e1abb"),
+  ("fdef1086d04be69d9ee8b1ee74e93034fa9c334207a94b49e640288112c58aa", "a300db77e9fba14d0e203240427958f9aafa2af0c10c0ee35057a18e8cd0ddda", "2c76e602bc2a2eba99b915c67d0ecac233dac7598a5379fc7df24e9211cee00a"),
+  ("395b206e13a47aef55b9b2974929c7c932a428d1ab5d99516e533358d22909", "2b963f062385d1517c9a1f2f5a49705a0b235d017619b846060a2df659015767", "11921343fb4e60af4a6bbe740a1073c5966a24b03b6c16c6a213cd31d68d321b"),
+  ("245c24b951bfa812e1400c967c0fc1e8b408d88ed03fef828a24e79fa041ca38", "fc6c679ac55b58e444fa7e79dc5f8bed1892033df115ce7e5ce320c9c96d7d7", "30c14cd0da314982425f8263a3d795e73e7ccb6b4985dc39b41be1de4d3f1bd"),
+  ("13c71f409535fe26e1ed5bee10ccb589b7b53dcdb8d793520ca3804745ef025a", "a4f3caae7b535d6b1cd71f83175020d0c13143df2578f7b3bd16f530e8959269", "209034f331ed4dfa65b411f1389ef88400d7bff688b8cd1faa20b99300408d7f"),
+  ("0", "44ec20f7191ecc5fe788077e4d95e1169db61e155497059b1ff25b6c1f3fca93", "0"),
+  ("bf996ba69fae795e1f992577ec909aa74160ec2953e83d355138dc64e77d01f", "7bba0a5f08649d5990d112c6d4fb503396759c7e8a4436d9329069b4fc2d666d", "7f4e38e1ce8748b6c095b469ae158e6ba304f64647b134e95da715306f28943"),
+  ("1228f2d1ec2de9e9a10f8b6e1f0d3fe315e0dae4c389b4f90524b6d84e17c2ac", "6ddc9a8f2a2f4ed57cb37a7f832d27db196f208b8e1d17ee81e4606b0276121e", "b8a80b318150387f9c952abfb822873a0b16d124d7a7476896a5280eb17ea55"),
+  ("e321c4847bb9d9459d5f07fe883520f345a02601f9bdee8c25150961025e68c", "6df747622516fb9a373235f26c5e897e734fea1dd624bb5f441381b0990b5043", "23027f3741a71e366afa0f51923c7067f6aa16f224e848cc4aac8fb84504448e"),
+  ("22c49ab2313d2614eee8448943a39c0bf68acef2dff7ac7dfac1457143b463ab", "3d37bf78ed5d6f802acc994ca2e76e6e6bc39e3d69b6cdfc928d17388bd41d3b", "2b958ccd64198410c10a304a1beda859b9b35381dfb183d8c93417cd6632a268"),
+  ("2c7caa86943cbfc4c00dbd0c11a7dce1c81659fad70e20ed1269fc806599e9bf", "63c3abc529fff431f33449782a0051df5ff5c823529f91a947f2e7282179efc3", "16eaa55a4b68b74c847e9ac43fe0d8c90dd88fb08a082612ec3b2beb8cb3fc81"),
+  ("2443f29df3540aeb1505e86b157aaae35b36ee5308757cf5904cccad93039e6f", "5a45b0f3650d291e0c777d71ff85ae35f200e00b5246e360252e19fb7236972", "10f1dde1715f616e05a4b7b9a6385359594646f3b79e42fd2b5809cd23a6c79"),
+  ("30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd46", "fc0eaf0e07f0dc923119db79b9eceb8d4c08c2935ecf7cbd9b72c494b7ea6fcf", "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd46"),
+  ("1", "27bb629284e0afb59c0bf119e235b4121c86cecd911708a2f5db8585bed1c0ca", "1"),
+  ("10d3a623981adf23bb57333c5904000e37d68c64a4d65939663f00aa93ef1ecb", "ae6104eb2eddab8d0dcb2391e744417549fc67de803ab314813a247313e76be4", "21abce8f284d09ac186105f8bf8835f430f6a6a9e66f4f1064e8ea2b6db50d82"),
+  ("11674dad8e90e08f314f8ae54121ef7d78ec827d9c357d3b1b11696d1d24ebf8", "75f102c531a25a1fb966c0d6a2a86059eaf39446f275931c3bc07d927f523da2", "4a16dde03076fb8dc72a5478b80869ae1f372129a6add6117d77cae22772ee7"),
+  ("165b4ca3199af201d1bafa9cc5238f95ab8c4552b41505d62bf622e32903cb7c", "23650ef697edc5fd4e33fb4f2614d10ebcaa392d3d2f793b4f4be54111a14c72", "f6351f0537e0da1995895d3db92aacb4f31b38728329bb913b0d824beb26c3f"),
+  ("a0d5f3b2d77e1d0b52cf49bece79ac540480087094358f3b36e52324e44c9c2", "c8bb3c54ea4d68f09cc061a6909af925571a1bb370b7a5425d1557169420068", "d857c1d7374ccafa3bdabd3db9acd0d99347612cc4a9d97995045faa00e09ee"),
+  ("127c2b954b1945ceed450c3eb2347c09ed014120b65a6c36e615f3a48a95d566", "bac701385da505f5faaac70c65612a047533ef18db1f87c993eddf0d6b452c74", "2ab94df061316b8664cdba51704cae86642ceca7ea4ab73fce47e29ebdba273f"),
+  ("9b510548330dc6f5bec4ef10494168db13ef87a92fa9d2fd00db3a49a727fb8", "ed8c3c54cabbb957bad69e9e572c7769ddf840f609167d6e120a1524cd1afc4a", "14134bcd690351d0bca4abaffb5466d4352192865923245d3b65aeb31a56a8ac"),
+];
+
+pub(crate) const BN128_INV: [(&str, &str); 25] = [
+  ("0", ""),
+  ("1", "1"),
+  ("30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd46", "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd46"),
+  ("a061edfb33bce04afc16957daa33e3ab9548d3e0db7b29392070fc82c44de3c", "184d53897ddd92f011e5ddb4377b3a75528b79a6abe26025f1c6f1fc5ee197b6"),
+  ("127c2b954b1945ceed450c3eb2347c09ed014120b65a6c36e615f3a48a95d566", "22e7e54514835cc776f1593c29c3d9fb98c6e4a422da186cb79301bf5098180f"),
+  ("9b510548330dc6f5bec4ef10494168db13ef87a92fa9d2fd00db3a49a727fb8", "25f5c2a888644cddcb19698153d263cd2a0dc6f1a365aef5dab9f85f71159ab1"),
+  ("e321c4847bb9d9459d5f07fe883520f345a02601f9bdee8c25150961025e68c", "1ab79cdd9dae37be91d3929ffad91a0c361fe43955f6e045042a527c69d9e1d5"),
+  ("2443f29df3540aeb1505e86b157aaae35b36ee5308757cf5904cccad93039e6f", "102c81cef9f6171d0a4b330591661bb4f9575de6a96fde14f62bbfbf8a54a8e2"),
+  ("395b206e13a47aef55b9b2974929c7c932a428d1ab5d99516e533358d22909", "9bdbe56aad9e14c50d8463e9c88119f065a1cbe229de27f730be23d23730785"),
+  ("bf996ba69fae795e1f992577ec909aa74160ec2953e83d355138dc64e77d01f", "ac6a6551b7f5fbf8488ec67b0f986f51d0a570fc22bc3e55d044cc92a810286"),
+  ("11674dad8e90e08f314f8ae54121ef7d78ec827d9c357d3b1b11696d1d24ebf8", "107aedb37e88341e2397d26898df6775a996d5aae3582a6c023225b83a16c8f4"),
+  ("247cbb177b8c6f52fcfce2113b341ea55efd97c4c2360363538a38d3a63b18d6", "16b40dd539e50ca5eac0090c1ca75ad80183255bbec63f494de9c1825e2f6b2b"),
+  ("13c71f409535fe26e1ed5bee10ccb589b7b53dcdb8d793520ca3804745ef025a", "844ba644b89a0e7cb19baf60bf89d5cf133e749e7d0ad7b6c1e9ac9f15f3e5a"),
+  ("10d3a623981adf23bb57333c5904000e37d68c64a4d65939663f00aa93ef1ecb", "2192c66e4d818037f5131ddea5054af33788a7f2cd05ebd8cc6b2cb2a086b6e0"),
+  ("fdef1086d04be69d9ee8b1ee74e93034fa9c334207a94b49e640288112c58aa", "2dcac99af1d49827f9b0f5f41dbb474caf1726ef57c9bf6de0c36ee6d3f33dde"),
+  ("245c24b951bfa812e1400c967c0fc1e8b408d88ed03fef828a24e79fa041ca38", "28574cc9fdc14a8a13cd48a9680a6057f32fd4a764be1027af1747a8771e1623"),
+  ("1228f2d1ec2de9e9a10f8b6e1f0d3fe315e0dae4c389b4f90524b6d84e17c2ac", "c802667822e54d1237c59f8c7e35bbbc01a9849776aa63385c12ebc5a615611"),
+  ("c1452b6698baa279ae5d7629d022a35ec3bf1ced0e1147659e5e78108ca91a", "2aeb579cf9d1147f02f77dd6edf3887c7cd87f6111f597d0acd911950a7f3ac7"),
+  ("2a5637907ce38415677ee89e75acbcbe4a1981551c7ad3a5223e402a1b75d2f0", "222aa4b548453489b0ebe0f8741446d03fefd8446d98fe7a75518dfe3e31f098"),
+  ("f2fe9c30992d6a701e9997cde7eff606b0bc3d6c6f116ac78919659a77d0b73", "252ce390bac9ea4a80dcc09f3a117da7e1761f39094ed5a527c00ed3bb0396a7"),
+  ("22c49ab2313d2614eee8448943a39c0bf68acef2dff7ac7dfac1457143b463ab", "e27e60d0f2f2ffdedb2fc6a8e6ecab9619b9ac75d4eb8eb393b0ccffbf97e5a"),
+  ("165b4ca3199af201d1bafa9cc5238f95ab8c4552b41505d62bf622e32903cb7c", "c747afce77e1a882754ae611b44369c25094c5d3dcd85820c1635c19ec94a0"),
+  ("f9e313b76ba332ebd2db7e7654404724da01b08e9eb6131242fe64c60ae6450", "255b959768c3c58a1397d18a43968e3a4f6bf9e75e6bd610f22a3290d4aeeed8"),
+  ("2c7caa86943cbfc4c00dbd0c11a7dce1c81659fad70e20ed1269fc806599e9bf", "f27bffd83f4197e13915cc2ac8240f1eb8b708683ccfc78a2e153636f5af247"),
+  ("a0d5f3b2d77e1d0b52cf49bece79ac540480087094358f3b36e52324e44c9c2", "1ba14ebcd2ed7f970ac59fe61212121b0ea8694f8297810fe01779d5d8957d1e"),
+];
+
+pub(crate) const BN128_SQRT: [(&str, &str); 25] = [
+  ("21276ecfd509d53c0e7655b4de5707beb72c6de7b52cba67eaaed94fa1a760da", "8d9660e2c2b76cde72b498e7dc10f8783f8807934222014a12afa81e1cdc36"),
+  ("1c66acede51d9d405f24b6ea8255eaf7086e22a07da995455f2677307d1d414", ""),
+  ("27ea8b64b8213dc3d237f545d7563e073f9605e95cf6bb5b5706b92430c24457", "7cea0809a81f94dc71793a0e6daf73e71933477771ef650facb24aa89f3b661"),
+  ("86c9380f24e0fff75af47ef677d4d4c1e71147708dc5ee165fcf63f6b877863", ""),
+  ("86ab62b30c5bfd9027724acd40d27318cc183a268b199b7f13a54ef0bcb30ac", "16f679f308b99a054e92b18a3e0b8748128ce0b831d67cb6fc6e93c8e638c2ea"),
+  ("ce4c6b23477f42d72967b5ec90735191eca465999b1c614bb092308a1d8ed8d", "6267a02014c210621cdb91f6f634633bfbc7c38f04fa958d602d3494c7d2fea"),
+  ("87f178a3045549ab78fb1118a16244d2f16a0a24d29d76646a1c2c4d744362c", "d41a47bbf31838f71809a305b6bbaf979cd1fba13c9d2c5daef1a257b12ce7b"),
+  ("2f9b27e1de5d0e794d3b05453026a53745adb060eddbd3c0073cafe30fb6ed19", ""),
+  ("164fb7b8074a9dbe0f79eb7d035454fa4d3f8c07ca8c2c1c335869da13dc2c55", "60071457ff85b1a6d9b51abfb40e8c56d38d96b816f8f40d195edf0f870f140"),
+  ("6905af6a6b9109b41da7e31629d1877afab67fe5a6b6ad8927c1c2324604f3f", "c51c06381c9f268aa9786a768e98c8dec5ab56e10f716c10bd713bce1174e12"),
+  ("17a448587d91e66f143d96cf8d1e7c88f849d2dfdb4255a90bd5917b4811ddc6", "84e4dbfb33884f4a446370db0620e9bf5bd5eb52c983637e8259adada065967"),
+  ("a4a8e7cf43dc7846dbe3cfa24628e44bfe3637ae7c6aa189105a80cc8837dfc", "154a2928dab868ef28cdc9686665f695aa1859049f450cbc432e22019c5b6255"),
+  ("1a3bdd83ce2d676c5600d6d52068e193bfd2165e40cb7ed840fc213740214a34", "143c109d9c1c738b39d4597e16b8620532d64fb426ce05ce98ea4dc2a605ea8d"),
+  ("1b50e467b26b7e553bcf8de3d9bb3fc46e936ad9685a8613805e4cfdd26e4370", "5f32e6116c6a6f51d8fa474cff9ea6024840321c1fb2262c25af396fca8633e"),
+  ("143d2d1cee5d9982796a9818c0ee6c64ed0d6b11525ab3a3aef8dc29fda16ea9", "83be2ce89dab294e37ec97e125c4bf8353f2391de99b4decee3cbc335d66fcc"),
+  ("4c157d8dd5cf3184766addebcd05d08be91ee8888348be10f98cd18aafeefc6", "34184aa1fba48969bd55ac4217f64619a177d6cbe1a3de1a6f21f9b18be070c"),
+  ("f7acb5bacbaef440615e685e7f60b01470da0bcebe677a9b5447ba5bc20461", "1250ca353f22873d6a8826af270d8b8a17e84f9cfca83060b8fc67a651236938"),
+  ("b983b24b518a9cc52ffb92584627eb684f03625acad40c901b18916c255b353", ""),
+  ("1b552b8813745f99af6f9a4e05a9203e7a6c15702f293da8b671796d94afea2f", "6d24e2f7333bcf6536716320a06ffc2506c9c20cbfea20a1b4563118fef74d1"),
+  ("c4a860c8acf636083dec5a034e95a3ef078bcc732d0077a694ff553ad148512", ""),
+  ("25dfd7277751cb9a0e9c396cb8fc5050a1e22592d8c7ebd7edcfe1b24f1ad01a", "cd27a20d8ab2ab28eec29f51d172222a47b85e3da9b2df0556e7d192c053717"),
+  ("2b9b7cc7da1ebd8634ab2014759a2b7227e5aa6d5d386ed58abff337c6d02765", "30cd90bebfc11757399fa45ddeb48f58050ae139d73817e74d1d0a6b3e6d862"),
+  ("fe680cf2a2c131c363531f7c71133824253e01e31af0ee2273fab42b7ef972c", "bbf025c5f41ddd8a65ddbe24de0e216dc102e9d59f8ab1c4f997a91e529ee4d"),
+  ("18c9c8f3d228b953fc117059c828b6dad51bb5682d4fa636263c8d24bef47e80", "82c6e7d320f00a82f37c859861ef00567ba05b9324f20989b22e2082d6d1ec5"),
+  ("29de77e95b9475cc2c41c4ab3c4c7968a390f06e7ac2cc6a5eafd47879f5905e", "11f831361d196b80776f899d4b08c8937d5a919c14b6c5e3209d1488893c5f64"),
+];
+
+pub(crate) const BN128_SCALAR_MUL: [(&str, &str, &str); 25] = [
+  ("0", "0", "0"),
+  ("1", "1", "2"),
+  ("2", "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3", "15ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4"),
+  ("30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000000", "1", "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd45"),
+  ("30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001", "0", "0"),
+  ("b15f71b47f519753cbf236097e3ec871509164c824edb178fd6085ea4b757eee", "20892175060a566156cfd6c56ea128e000e357fc9a2bcdd5374ce66591345e03", "32b0ab3977570d2ed57556ebec26a9f4d1bf5d254b8a119e47e3f0dbddda8f6"),
+  ("c1aea762c05163ada60bf28dc9c0190c9c6c422f4f1d09baeb041dd04af23437", "21cacc65325b50deca939ab9e1ae069ce0ca7308c11204464f8e9a3aa8734fca", "dc1da5fb20ecba396eb76cd1e835b9616c81b85db739da9b74f797c7056d56b"),
+  ("aefcd747deb382203f7a7eb6f1cbf9fddca881d5c5b9b569e5c9748c0784aa47", "1de59bd4224768ac99c5c93725401020166b1144efefe0de909c9665a09fe5d0", "3f027818e1fd6476cbb2ee51695b762311590daac98f93612beff882a37b58e"),
+  ("821c843d63a6e70cc93ea1de544d98ce4daae2b21b4ea4c40962a633b9f3a54c", "17dd18131b134df0f825f0f9520132c890b23f42b9357b2cf97ca081fba61e53", "1e395e00080a6405a999ae1bc9bbdf25fa1d1d4c64628bb6b6e93e6fba2336e6"),
+  ("f0b0b9f29071e1d6c5be4f807c62e9b5fae5d4b17ae3c029050e2b4a9da20173", "67dec7bf457d9a9f0b2f610782a7f63e1cf9666019140173df4c946582520", "2eec8a2dc371659b4dabe91a7d37721f7634c513c6e9440c475407a4ea7cdcfd"),
+  ("6def15a40dd3535861858c59860b223eda39c4fdb4bfef8945ad6e7b6b7e0520", "89580980403373b9aa63983534ae8bc66bb4dfe6bb5bad5b45413889df56525", "15a626ec3d7ee7fb684b096cca61f41b59a1f5f96e4ee37836171d92378c88ae"),
+  ("8cb2b2cf96c9346f10dc0207d7ea711921471eeba59d90d46c4f26c2c0d54b31", "9d8e5d420649849a255169b2287f34b845911f2d4ebea6716656100c2445f8", "2f99a2bc2e8e7d344b2e6bdbc108b7bba47e4ac8ed88f8721729c9ea742a95fa"),
+  ("51f7a2bc146604a70dd247477d22b00abb7845aeea5bfb93d8d28dc4b13f73c9", "1fc2a75d0df757c0cb4f72886253286b8d6141572508584f6781a76a4d4b93f9", "11888b2728487d7d3f0ac64bfd721a9811aecb1ac9e80f65037f1c0c8f19e363"),
+  ("610e833d8eab40ed77feadec7f92267b63e3ae00625bed654c0e7620e0b405b3", "11f5f968fe29ea1fd138938d32e91daef85e9fdb714d825bb699e73b8adf3688", "1997c1f4631d8e4974cc152bded9eae17910f43ba8970978643878fc04cd74e8"),
+  ("c88628a0b9c82b5f0471ee3ff66b5e33071a0a093d007cf823c4524a482c7549", "1aab6585e9d63a166f880e22a171327be193e33548e66310a62d6ca8de1e9dcc", "1c4be98e7baeafef27a38092d1c48045dba746d942f1ae802609700a122f4abb"),
+  ("a7d2d744066e56d17f721fdd7f693520b14589b4ec7bb4c2472c72b56ebe2368", "272c5b75a6c29e29629c90eaa733363ca52f7542054e0801b7880df9c52e3e92", "272ce5d3e778bcf9a42ca07789b71582ffad2bb08c9d38e17dacc1c2d71afb55"),
+  ("77fe009c38156fd665ad7b0cd38d5c1aaf7509a9e74119896a42954794388ee0", "262ca20f47aae311559c48c8e44cef7e1cc610a5a83b2f3de85d959c79cff300", "2ed3dfff37df9e29ef2fb1076f3c773052dd1a19d591acca655a8f241c721b2f"),
+  ("96d83f9bc73cb5f63339a96fca5b94435364c05bbd8b36db1dae29a221d7ccec", "a7fba6f9849c18c46571dcd643922b2e9d25fe5e32bb2154abc9487bb67b852", "f006a865ebe06144761235757e24e74a47dff532f6cd24f1a1d1082f76003c8"),
+  ("a62aef38de96427a934e3b82a15717445347c173b0333fcf3bb40c8e96103ba0", "25aa9a21895e41e3375b8cb5a31e103e36dac83424884ff7eec0a3cc1e1cf150", "cfdb313498eff7920570142362a0d273bf7e4da437bba80228a4065b8154b24"),
+  ("711b01c8008b4e120470c1216f2b948f7675ccff960b3d39d49dab22689149f7", "2feb8629f194459849834abc2a2c9fe9936e93c0d06ec11a1131adfcf89b5efe", "5083ade560078a1c6b1c68d0bed07edeb3dc928f943db8a20a620217196a7a5"),
+  ("b67cf643b232c646a009594be26d107aa2fa3f3d0ce9bbdfaeb4aa701aef41c6", "1700047bd0a5f2cfabd70f2fc064e1752a36f095ef746d0cb6a0d1e446dd2ac0", "666cad5764d87021e3bdcfb716477aa9b2cca33028dd51425288c6070592aa4"),
+  ("e31a477dd12b2090ca8c9649595af31a862143e6a97f60dc432bbccbc56b0e48", "28635c77046dd0d22daeefdbd63001e47179e67a5d923d88fe4b317d2394bcf8", "24392a2fe4e73f568b2e58d7c1057a846eade03ba5e54469f9493ff6c09a1378"),
+  ("6615e9e94a8d33eb32647bf24f2d929c04fb1b43be279c92c87ac53c57e69454", "f6557f68474469d9b572116fb26cf33521d02ecc6d3d5851cfa743a8a1223e3", "180f472132917d86d036ed62ffa47d03f6c43615e3e6169754bec47e5ab975f7"),
+  ("fb190d3cb0a399a6d25830933496ae9808a9044d3394b218802076338d21c27e", "2a9a7a1008e5ccbf7b4dcf1c3eeb3034d2e83ccf82d357bb361ab58125b1f027", "2f087718197aeafe9c8a8d89625c273e04e1dd1715f203f5ca2881e7be7c12ed"),
+  ("f3c9e487ff4162b41f549f59f5265d966c9121fc1cd8a8a7458de97821486367", "12e1a0aea9be30da2a8f82968ecd44c0906d9021044a70781ff59d5df4bf5918", "13465b2d80ba1d196f8d9cf33afc1befa53dca9e551d08bb0331a1aa74982ad1"),
+];
+
+pub(crate) const SECP256K1_MUL: [(&str, &str, &str); 25] = [
+  ("0", "f53a921c335ae50fb4dc7bcfc5159fe5416df9d38c3325879541200c774c264", "0"),
+  ("1", "1a8f34b180dae7dfb509d09b4468118e61cf1af7331849a1def53034c4a07517", "1a8f34b180dae7dfb509d09b4468118e61cf1af7331849a1def53034c4a07517"),
+  ("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e", "c4371b02a2b3b35c688be061ee9fce600fbd5ebdcd6634fefea23348d5651da5", "3bc8e4fd5d4c4ca397741f9e1160319ff042a1423299cb01015dccb62a9ade8a"),
+  ("f4d82d227562a095d2ea4bd8fc3d400abcf6280ab103037a7735b7751b6a58da", "845abbebbdc1b23e1142d820fa17a242b850f8ec308488b281a8b30b2777fb8", "ad60eedd21dd6eec6b16c7493b486185f6c04b7786ec43ad152fd45825a74884"),
+  ("9b92d982c54f756384523abc434fbe2b086ab0f48a0339dabcccaeb6a694c834", "8f9975beb364733688e2b17a1c086f110fe251a10b8da13654e04c315dbb9959", "89dcb84ddb510b6e77974040be566766a517dcf70068c3e5a2b072ab7ecdbc18"),
+  ("845abbebbdc1b23e1142d820fa17a242b850f8ec308488b281a8b30b2777fb8", "950379da7845a3682bbc29467d8d68499fd715f1fa91dc54a58ef16d072f70e5", "3add199c3b96cf75a477a5cc9ce054c83cc60f30dc8f70f26f8a92b7d01a060"),
+  ("12a5558d79b41e7c39743a81a368d0ab74f07e36a24489217565926e00b06d0f", "13d0c944ae01761cd76b4b81f2c646fc232dba6606a344b32dde91191141ac0", "a430a16b7442004b2a38d8104b37215a2f5ce7f0f202e6d5921eed93d6e27cd8"),
+  ("67e5f5d631f0deac155e2e9a51981faff7988fc72b32fc566d17e2b31a096cac", "1c8cf1476a4edb1d045a026277354a3477977085afd0aa218c6c8252f7582a09", "a8f5570bca6369705b1c0654af65ac8cd2e7d17b2e346c20a52710dc33da54b4"),
+  ("f85d11c1f348d790511a9c9e916f24e32953877c60eab3a894fc230e0f9c1356", "67c7f4d5a6df30ed89ecd82c8fa1226729b86bc65983f36a2bd059381f8abc94", "1e423d99cd3a99657832d41be3cb2545b3f14b643f12890bb2d0e52b0f171ce2"),
+  ("826f9be416ca2cabb8dd703bcc7372a04c4dcdc05e0416881d2a1aad0927d7b9", "826f9be416ca2cabb8dd703bcc7372a04c4dcdc05e0416881d2a1aad0927d7b9", "51995cbb602eb99fd8bfc691a8b4d6b2025fed239161cf8844b2fa946a88f52b"),
+  ("4c6ab5db510f28eb44c42f9798cbc1efdbec341e4bada725932f4cc49fdc905", "f85d11c1f348d790511a9c9e916f24e32953877c60eab3a894fc230e0f9c1356", "32220cfd9f87e08f9554dea3e4c927d8adbe875f42b76c7d76b0a351f15854bb"),
+  ("1a8f34b180dae7dfb509d09b4468118e61cf1af7331849a1def53034c4a07517", "e738f31071aed22143fd53ee902b221bbef42f019c200b25cfa7c60b2ca34f58", "2183250f00e52bb9e446cf1cb3c5d410965c3e8c55108dff0cf85bcca3403062"),
+  ("1c8cf1476a4edb1d045a026277354a3477977085afd0aa218c6c8252f7582a09", "4b7179860540092cbba2d2e0bd775abe2a807cab9cb6e0d9efb9992f5164a341", "f8e12f039e345241a0dc5e6d39b164b5a8c3dfdda7d1552d407e0afae654b612"),
+  ("67c7f4d5a6df30ed89ecd82c8fa1226729b86bc65983f36a2bd059381f8abc94", "e8b2b10c008a05e09254f3e3a5367d747e0ff6452655b0e839b5af643896fdf6", "dc4e544f4bea5506419467c2ace934ddc2ded18f7e2d48c7094fa0cdfc38bb3"),
+  ("13d0c944ae01761cd76b4b81f2c646fc232dba6606a344b32dde91191141ac0", "9b92d982c54f756384523abc434fbe2b086ab0f48a0339dabcccaeb6a694c834", "8176aee3ae546c7dc0e8d44552b0292d50fd4f315c1ac18622fab595a9253e54"),
+  ("950379da7845a3682bbc29467d8d68499fd715f1fa91dc54a58ef16d072f70e5", "4c6ab5db510f28eb44c42f9798cbc1efdbec341e4bada725932f4cc49fdc905", "b729a90e01e8836fa9a47694c832ad7a8d8245ec5dbd04a7548a0e49eebdb79b"),
+  ("e738f31071aed22143fd53ee902b221bbef42f019c200b25cfa7c60b2ca34f58", "67e5f5d631f0deac155e2e9a51981faff7988fc72b32fc566d17e2b31a096cac", "aaec566c95e472f36762c66095322c662da93cd92c850ca9265c10025f4638c0"),
+  ("4b7179860540092cbba2d2e0bd775abe2a807cab9cb6e0d9efb9992f5164a341", "49c9309c83fe9b384b09e69288c1dfe983da527e338e8ea0b30532777e4c720", "bcc35c7a3a32a523534bbb7cbebb25e01ab0c8c385d73abb1f126df6cce11396"),
+  ("8f9975beb364733688e2b17a1c086f110fe251a10b8da13654e04c315dbb9959", "40cfc2b6234b68690241a10aa65af7f1d2fcdf056110a3eb2e86b6449af0ef17", "7d6bcc2c93b9a9916e72a44dcdd35dbf329527b6f869c092c2f59145023522c1"),
+  ("49c9309c83fe9b384b09e69288c1dfe983da527e338e8ea0b30532777e4c720", "12a5558d79b41e7c39743a81a368d0ab74f07e36a24489217565926e00b06d0f", "6631b3e3753eaa231dc9724af6251c0e73bae0da270eadb037882a666644e4b3"),
+  ("40cfc2b6234b68690241a10aa65af7f1d2fcdf056110a3eb2e86b6449af0ef17", "1e9e0ba32ad9ba6f1fb74e79e01677dd223a4b2daff5f5be8c27e862e93b6a8f", "b4108caac15bd7696deecac39185d52e4f73983462ca86bacbfd9bd0699d61f9"),
+  ("f53a921c335ae50fb4dc7bcfc5159fe5416df9d38c3325879541200c774c264", "0", "0"),
+  ("1e9e0ba32ad9ba6f1fb74e79e01677dd223a4b2daff5f5be8c27e862e93b6a8f", "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e", "e161f45cd5264590e048b1861fe98822ddc5b4d2500a0a4173d8179c16c491a0"),
+  ("e8b2b10c008a05e09254f3e3a5367d747e0ff6452655b0e839b5af643896fdf6", "1", "e8b2b10c008a05e09254f3e3a5367d747e0ff6452655b0e839b5af643896fdf6"),
+  ("c4371b02a2b3b35c688be061ee9fce600fbd5ebdcd6634fefea23348d5651da5", "f4d82d227562a095d2ea4bd8fc3d400abcf6280ab103037a7735b7751b6a58da", "16355b497ee57e04ed8e9dec2de2ccc9d4eb470c4d90621316f33365123d1848"),
+];
+
+pub(crate) const SECP256K1_EXP: [(&str, &str, &str); 25] = [
+  ("1a8f34b180dae7dfb509d09b4468118e61cf1af7331849a1def53034c4a07517", "0", "1"),
+  ("f4d82d227562a095d2ea4bd8fc3d400abcf6280ab103037a7735b7751b6a58da", "1", "f4d82d227562a095d2ea4bd8fc3d400abcf6280ab103037a7735b7751b6a58da"),
+  ("12a5558d79b41e7c39743a81a368d0ab74f07e36a24489217565926e00b06d0f", "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2d", "b549bbe92d37a338e9a5244601945450338c51bf80a3dded8db74cae4caaaeea"),
+  ("e8b2b10c008a05e09254f3e3a5367d747e0ff6452655b0e839b5af643896fdf6", "8ea504ae579eac7144d278c4468671f245246d229915cc31fe564ec87b6fadf4", "2bdfba3f20836d59db251c2390bf4274f0360b6e959b75018968eaa417e3ee13"),
+  ("4c6ab5db510f28eb44c42f9798cbc1efdbec341e4bada725932f4cc49fdc905", "8f962a5c223b0d535db0fbd0368d58aca74b0163c70c0f5b18ce971c72825667", "d0396233eeafb419ebc781da61109e4b6f5a53b9bab8e1eb2854f3f63e241798"),
+  ("4b7179860540092cbba2d2e0bd775abe2a807cab9cb6e0d9efb9992f5164a341", "e2097a6bb666ef203888815a156f550d9a3096bbcf2e5f744534e16d8210e18d", "3ad644defe393515b823ca0ebd1a833345ccaa04409d15e46ab82cb64a210daa"),
+  ("826f9be416ca2cabb8dd703bcc7372a04c4dcdc05e0416881d2a1aad0927d7b9", "488cf7f7b9a515a3cc930ca6c758b2c3977636b270ad5799057a06ae2ba62d", "5609c5665b9fb6c4b72e3744723d6a346461db7bdb605873cea5e16e8b64e098"),
+  ("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e", "e1ec5f28a6d0eb919bd61203c78d0be495221bb7096702f217f4398889ca822f", "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e"),
+  ("1", "2ab94a662be286950dec0dc2851c91fdab099df0462caa3d111b423fb84aa94", "1"),
+  ("8f9975beb364733688e2b17a1c086f110fe251a10b8da13654e04c315dbb9959", "1a6f57905131fe8a97a1d2b1aabd5b6795ddbf812391c075f59a7276bf5284d7", "72e98eefaee865d51b7bcc8dbd455fa9e787f41c28058386b8d93d35a1dc9a98"),
+  ("950379da7845a3682bbc29467d8d68499fd715f1fa91dc54a58ef16d072f70e5", "781d050d0b71bac2d0d47a22fb30fda41b559befaf4fd2762d1f87affaccaa96", "7cad2f6ad192f74e14be2f3085be05cbd9e1598e69a0f585f67aedb05f2f6677"),
+  ("e738f31071aed22143fd53ee902b221bbef42f019c200b25cfa7c60b2ca34f58", "501dbf5b0729d01cbd8268eb3dde9a77740ab9dc56534c2506d9699a2427c208", "595037235d9e27c1f5fa49c59b009f1e58411026eb2922e543e4566667e0ffcb"),
+  ("49c9309c83fe9b384b09e69288c1dfe983da527e338e8ea0b30532777e4c720", "f5d6610181988ac211f8b0837b82c6fdc1c557db2beccaf38e4cfe6241318551", "2c18e8def4da1ed37dbc4423e87d4db373f0592f1ac0681121bc4d8919d7e8ef"),
+  ("1c8cf1476a4edb1d045a026277354a3477977085afd0aa218c6c8252f7582a09", "26755e0fdf2b285b5201760912fd712fd49747cf290ba77a51077d35b8cbb4e2", "20d90b28e7d511679912498a5217620df48500ed6e9d5b7a22e17ce32acca7"),
+  ("0", "e98b6e5bf41f41c8bb78d5e3d906a61d88619b77f1c286b41de39102dab01592", "0"),
+  ("40cfc2b6234b68690241a10aa65af7f1d2fcdf056110a3eb2e86b6449af0ef17", "6fe19231e1d914e139fad203cb521434e3170c966d78ad2f07eeae4714ba03a1", "e8f04039120f7d37923f8c134be8afda36c9346ce86242b269daa4d711519ade"),
+  ("845abbebbdc1b23e1142d820fa17a242b850f8ec308488b281a8b30b2777fb8", "14988bbf0fa1e04410be85fdf0184c014a901e1d8d0ce9f112af7f11e9af4341", "566b9ec99ce43b34f84b33014d93cc4c146abb61e317d354ba7c8ebb26f921ae"),
+  ("1e9e0ba32ad9ba6f1fb74e79e01677dd223a4b2daff5f5be8c27e862e93b6a8f", "b2bfa22339e81d59a474da6ed438a435b614be4c7aaf16f9ada5f8ae3ea81819", "324818bf5345f786cab3a3e81447f5e14987ecf6ef07b9771b2fd6555163f529"),
+  ("f53a921c335ae50fb4dc7bcfc5159fe5416df9d38c3325879541200c774c264", "7f7111cada54e65658ac878118600986be97977b1730e1f323631cfbbddf7c2f", "9b8c2b12b7dc8d46680a698ea5429fb4abee093e5c99bbdc9a96a2692a360033"),
+  ("13d0c944ae01761cd76b4b81f2c646fc232dba6606a344b32dde91191141ac0", "aa5369e99bb6a6b249df1ba8d846bd27ebf7189d414f223757120ceb5e096bb8", "a239af184c5cee895867f72a3d3dc0e68377ffc1cce9f9e894fd160bc0896a4e"),
+  ("9b92d982c54f756384523abc434fbe2b086ab0f48a0339dabcccaeb6a694c834", "1f50411a2c632fca6190527f005721dacc34c66fe35e71a13e217fd69c4e0b08", "bba6e60c64ea04dc9baad7d71aaec5d35abe877d7827b00ac7057adb606c2cf5"),
+  ("67e5f5d631f0deac155e2e9a51981faff7988fc72b32fc566d17e2b31a096cac", "d55f17a498a2e6fc1e774d014e67b7014cb243260f6152492e416ee671b3a9c7", "7494e1e9418e5d9f618f3f01399379740f6b3cb71c980c3126163f6e11631365"),
+  ("67c7f4d5a6df30ed89ecd82c8fa1226729b86bc65983f36a2bd059381f8abc94", "5afa3c0bbafd3390ec9a4922481e0cf1b0b3c71ecd47490bf047f0a99700c3c2", "6641176071ba1be2d66731f646b0453b1fd99ebd8cf856b90e9b34fa74eec8a5"),
+  ("f85d11c1f348d790511a9c9e916f24e32953877c60eab3a894fc230e0f9c1356", "85807354bd09fa6ff90a250a0408c231f40fef20ff7237d5de3f968ca9fcc251", "99e62f829fc0c7924d0b27f051b55a19225a62353a029e9750e6d6daa2d605b"),
+  ("c4371b02a2b3b35c688be061ee9fce600fbd5ebdcd6634fefea23348d5651da5", "18da846b95bc8d7a75e509450ec7820d8df63c36e2cb77d4a25cfdbe0ea08962", "146355432468347d13ea81aae97018fc6e297c14d6682cc67b09489a41d2e7dc"),
+];
+
+pub(crate) const SECP256K1_INV: [(&str, &str); 25] = [
+  ("0", ""),
+  ("1", "1"),
+  ("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e", "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e"),
+  ("f4d82d227562a095d2ea4bd8fc3d400abcf6280ab103037a7735b7751b6a58da", "df29951e892e78d0ade3b4fabfa246fbd779c9bc328c83c98eb48950c9d019e"),
+  ("9b92d982c54f756384523abc434fbe2b086ab0f48a0339dabcccaeb6a694c834", "9f0874549885b8d2ff22f28d0900870daaf16076357bd05e2bbb7548e875c017"),
+  ("845abbebbdc1b23e1142d820fa17a242b850f8ec308488b281a8b30b2777fb8", "38f26971759480c4879304b2f979ef397808fdc330dd78e1661f2379978c9324"),
+  ("12a5558d79b41e7c39743a81a368d0ab74f07e36a24489217565926e00b06d0f", "b549bbe92d37a338e9a5244601945450338c51bf80a3dded8db74cae4caaaeea"),
+  ("67e5f5d631f0deac155e2e9a51981faff7988fc72b32fc566d17e2b31a096cac", "14f6949d9840591afe750c800c53ee9d0649f094da87fa3eb33115f2041eda77"),
+  ("f85d11c1f348d790511a9c9e916f24e32953877c60eab3a894fc230e0f9c1356", "8e5340305a5223557b2d4ff2e6b1743539137d881174e8b3966668b9c422479c"),
+  ("826f9be416ca2cabb8dd703bcc7372a04c4dcdc05e0416881d2a1aad0927d7b9", "bb9ab0690ce446da8a78ec3da7934c7eb0186219f3a3b97872eefa3b45e8019d"),
+  ("4c6ab5db510f28eb44c42f9798cbc1efdbec341e4bada725932f4cc49fdc905", "69b9439ddd545de1dff378340e13f82e4074daf6daa55f623a76db0193237a0c"),
+  ("1a8f34b180dae7dfb509d09b4468118e61cf1af7331849a1def53034c4a07517", "4c9491dfde8ab0c5f4d6e36b4375d7b016f2b784dc8f3828c60f131c24586061"),
+  ("1c8cf1476a4edb1d045a026277354a3477977085afd0aa218c6c8252f7582a09", "934ee5d322acd64be906641683a70a93a802b202c5c0fef1c97d7408219a883a"),
+  ("67c7f4d5a6df30ed89ecd82c8fa1226729b86bc65983f36a2bd059381f8abc94", "5de897b51ca831b00e0fa2db7c58ad11b67aa5d14bec8e59aab5b96af42367ef"),
+  ("13d0c944ae01761cd76b4b81f2c646fc232dba6606a344b32dde91191141ac0", "f925255f96c6041a637245a48f6dec0ba6e69cb3c42e75f8c70f8dd84c1eaf7"),
+  ("950379da7845a3682bbc29467d8d68499fd715f1fa91dc54a58ef16d072f70e5", "1790ec2c08bfcba746dbfbc7a880c6e4c575f25a9b304daff66d4f7af87bea8b"),
+  ("e738f31071aed22143fd53ee902b221bbef42f019c200b25cfa7c60b2ca34f58", "4b5d74a5a0d7ca084204c7ee1ac59fc407d64779fe76f28f7dbcdb388bc6124a"),
+  ("4b7179860540092cbba2d2e0bd775abe2a807cab9cb6e0d9efb9992f5164a341", "ca18408f8efd821df130ea7837ce09f732ce81bae05947e1f50e5e826155a92"),
+  ("8f9975beb364733688e2b17a1c086f110fe251a10b8da13654e04c315dbb9959", "cc94331c939e54b890a672fd7e5cbeb82f4ad4febc33283787c721fb54759c1c"),
+  ("49c9309c83fe9b384b09e69288c1dfe983da527e338e8ea0b30532777e4c720", "ea832e73417a8a09675de1880ea22e2c5e6d6b9544ab75bbbd52d92703680502"),
+  ("40cfc2b6234b68690241a10aa65af7f1d2fcdf056110a3eb2e86b6449af0ef17", "59b9d646abfccbb9ab8bb6654aefb5f6043c8fd5458c88ea77b186d45d583aba"),
+  ("f53a921c335ae50fb4dc7bcfc5159fe5416df9d38c3325879541200c774c264", "f923f74ea13d0755c4bf8172d18bf3ef5d762ad273c464e03f0e8be2627e0c1e"),
+  ("1e9e0ba32ad9ba6f1fb74e79e01677dd223a4b2daff5f5be8c27e862e93b6a8f", "58980013c89fa35e8c61bb959d63957f0e0acc404560d84e2e1c7cd9b3dfcebe"),
+  ("e8b2b10c008a05e09254f3e3a5367d747e0ff6452655b0e839b5af643896fdf6", "86bcc5d00b9aaff02d9ad273fca8ea7cada16b405ec5104d58f7ab98fd08c85f"),
+  ("c4371b02a2b3b35c688be061ee9fce600fbd5ebdcd6634fefea23348d5651da5", "3328f28cabb00b3483c7719372cfbf9f71df07ea400a00df8d5c40f9ac74ec5e"),
+];
+
+pub(crate) const SECP256K1_SQRT: [(&str, &str); 25] = [
+  ("78cdc62f2740453d10b66e15d3bde107c73e6a9e7ad27dc51106d83f1b22a865", "599aa2cb29006bfa6f852459b9a33ee0959b02a3c1ecbd34cb34c70553a547d8"),
+  ("7c24b7577d7c659de7eb8e2ebac9d61a57bd4501af85e51da89808d7e53f6058", "3c2ad2600f0f23a870bdec58133e29031d5df434424173b55ee4fdf1b88d360d"),
+  ("b529e5467ad0762c8b06bf2b8957e543e136a9bf2fd9e477afc3fdb5ab999c7", "2d2143f75939a190a79d74d0275ad1fc91cecf4a0ab4dcc788cebc95005f01e6"),
+  ("e0c734478cadf6714d1bc2966bddbb23eb022dfba272d171d4c4f9629f10f0ab", "4dbd0827127a68bf82452a9e2641226d64067b30ab05b83ec8d3d3fdb233f4a1"),
+  ("19d6d3a4df108ba5108ad97bcbd17d48488628244b830d490ecf5ddd7bc9e022", "11bc5ae9bc90bc58e3dc0ce28703feb86835bd6ddba1b3a03ed489969a9915ab"),
+  ("4e1844d520c2a4f89dbcd302422a052911e8bad9f4c8f0dade54762f7e1ea5a2", "7b92f57ebb0c665c9a2b5932782891c27838c84d0ee0ef56c79548ef005e10e0"),
+  ("86fc720f50705190454a4d0589bb79da8529305611cf464476e32e531e189004", "6397b3e4577e959865626e4bc049cbf0c5e48a1a037b497d088a80ce1be05065"),
+  ("d354d5cda19239712c2192ca4a6b8da69444511dfa6c4db092c55b01a4058429", ""),
+  ("6afae131e5ccefabbe1dc21bd6e609a48a70d4e5297b801f0cf723b4bd5e43ad", "738d44184ea7cd57678f4846160d33b8add502c88de92dedc8fd17361b38a669"),
+  ("446e17b64a83d89c9a7be3dbb335aeb1b23f9bfed90fedd56f9d918726aa2e36", "762b278cd76967af4da39388bea3df8262b5b7b7269292fd6be4a3ad63977e9f"),
+  ("4afe4f50c7234a65ceca60541c43c469a1289633eb6bf60c4d75bdb5a7fc49b2", "8a2045f8da327553dfa671161ed8cae9f7c47338d3c27d62f7d08657e72d9b8"),
+  ("722059c487cd9fc0ee45670814236f4b5595ed93220ca69c501329aaeda94331", ""),
+  ("d6400b6333944364ab3cdb3b7fb1fb2d46a684fd066711a8f719dff1780bdb30", "609088d7a0f5f8e4c0d1268bf07df616a419b206892c532130525e77b4f58b"),
+  ("be55183dcb3b09c67a02bbbd79a6eeb3e7497b097a39694221bb89ce2546f800", "3671a17ab2109344ebc7db65fb85daa2a9bc5afa97a867cae6e3d999f9fb497c"),
+  ("c70e356662882e9211741e68bede5815f77aa5c7a902d868b8422cb249be268a", "48471a20df7eb20d819b9b3d605fd9a086c0b262a645c74a9e81237147f58020"),
+  ("3706ac88b40d21c3682b91c8d73043aff39040fbcf4622d72fd9ee5e54d88e7f", "6795812720f7e3176a416acf40f8b4737ac1d449102e7d916e7afae57490a53c"),
+  ("bb4f71f6f533ae64c347b2f77fbb360bd6bc684ef1af4ae97a5a9815c8e4a465", "57b583374517220d00f598ff2c910635aa7eb0103b2eff3867ce9625f57bee27"),
+  ("e72ad0c610315ad93070fd624467b2edd2d677c5e86bb3f93a8e3770baa85421", "764cdfafd4a1545aa0928e4f4759e3cc85117bad05aab05b9aaa4c2d1f687efc"),
+  ("76c9200c595d437c1d8fe24cf1acf5e79071597c3de7a83fb070b656bb4870ab", "4eba8c627e0817ace8ea95b8915782de78087e7b1651eaddfba176646c45ec11"),
+  ("a60e658d4e69a9c63f94e2a60bb82eccd72e4238241c0a0fb03f4d9d14df1155", "19ff6ef1c1c55f9457a7f6cc4bb68135929910b0c7b72f4c3f61384cb676c284"),
+  ("fb65867458e1b554058c1944476b1540932bfa6926a5eb350611b8ab668e4160", "4ba8464e9dad23ef20633195b80d76420c6cb9a4fb785a08547f204d95df9ea7"),
+  ("3d050e88ed74b25ee84135fa5d9ea0990cbeab84b8f1025dce975463f7b1480b", "373e8a5fa399ba7301d4733ca20878ee93a2b391cb04f6f6fce56bbba50a4f60"),
+  ("a4213b6461f18837fb5c63ba651ac964bb9df78ef8289bac2c45c0696851c812", "34cb1ceba90df8be967f9bb32cc2588e8283c83c75b66efd6f397e870ce5d7db"),
+  ("5945d07ae1143017b064a71a15498a73ecd989e5f8dc219e83fedc31c49ed465", "14981a909f4c7984f66cc4d18b860b287db04e92527001f8c5621e7bd510f945"),
+  ("527e1cb8f88cfd057457b57e42aad87e66d93018f9b029ea529c9bb79bba7c6", "197eb5d27c4c2173c8f2c4768537f73212448bfc7231513b135ef139749e6c2c"),
+];
+
+pub(crate) const SECP256K1_SCALAR_MUL: [(&str, &str, &str); 25] = [
+  ("0", "0", "0"),
+  ("1", "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798", "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8"),
+  ("2", "c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5", "1ae168fea63dc339a3c58419466ceaeef7f632653266d0e1236431a950cfe52a"),
+  ("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140", "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798", "b7c52588d95c3b9aa25b0403f1eef75702e84bb7597aabe663b82f6f04ef2777"),
+  ("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141", "0", "0"),
+  ("b0babccf0d6e00a15aa2e73b8d8c835a0c19ef6c60d89be663b7bc590023dd1b", "5edcba2ab75b3a3a41c4060577a433306fcd4d637fe94cc7bc796aef3547d457", "d840ac31aa9fc4d6d9b05b50b66db3a1b18bfbbe436d1ee914f7ff15fc1b3ea1"),
+  ("c585b560d10ec4d7f9430a2c842f812360aed8c0bbd7c0ec3f61418fa240ae3a", "741f3775e366236cec962cf0c1a41bc7432bbee1ccf52bf09354240f1dc93d33", "8935c6c30fb4ed9dfa240e7d752a33a3f361bb141d2925bc6cd1da493fba42a5"),
+  ("63f0a97a56b4111125dc1b75bde20316a39f976111453e91999aacab1acd2d6a", "cdaf6287daf1efceb706de81b98d3efc5aefc91a008ae57e805927ac46edc912", "75356c65b972d1d66c7f012f70664cede8f0c7ad6bdafb962d7f505045557e3a"),
+  ("bd140fd38e8642eab1cea3876388cd258a4a1b21f097804544b43345084b4b2", "2adb2678f85880de91c46a552f4d641a4c36bc6c4906c82d00414a08a7c0e658", "55cef93b0d7bc1ef374d6b102864465b01f81d62930b836832c348502a5fd6ba"),
+  ("31f64c30ded17f0a12e44901c4a8c01c3bf9c4dceb106998363f05dc628ebf74", "c880782c6f75eb3e45d49bbe0f569cc56cb4cb33087a0561f63c12abedbbe5d9", "7b243562e57e8d1d7d1d1adfdc7cc5b3f17c830490e5f365429d57ca196c2282"),
+  ("915f45484610a254f0e1a62fc75acb15678cdcf6247f82e36b805b90c163be2d", "b37f69581157670f02afab7e1ac032c574a754c0ed0f4f7153801294c12bbdf7", "e320d69bdafcd0008af661d2a2416dcfe93034ed22e4a9a740128b30b6b23268"),
+  ("cc957df0993bf164e2046dcf5abb1334578fd8db1f52e1395c3c8a7f0d346a22", "952ba33b686319f2f221def00f9a1744e7245c7f707510ba64f33de47bfb716b", "3fef1f69378467bcdb922fc3c2ceeb6fc3f3cd543447dbd6c5f68eec7768d0b6"),
+  ("3f8de7bddef511db09aa73b8e70a9835f9c598e4c33dd81476028c34fbc9f264", "d82a9380e5274e020f8340b2f8c5b72791e2d690d748f66769983b623aad9881", "6a0e020c9f3c7b4bbe63090cd1d3a6c52fd608ca48cec2c0a40fe83dd09d0b46"),
+  ("1552216fff740b342449f7c89c0a144c298e3aabd1040f66beb50939beb6397e", "86d8540a278e670f536772f05e6334bc21cb25188836eebfb8d16518cb676fe0", "9d29c52754829a2698c3ab3e2a52dea950fd64904c8c0b8ec0d4e7c300cb9ffa"),
+  ("ff28d75a3eab33380bda1d168953648c1579fb41155513b5669c94da18b64560", "2a9e8fe2be68ddb8abf6cd85184142bc4066539f335ed7ad6a9d0f33a7e82a91", "cf96c4574763e18f01d3e73b6c3f338efd8dd36da5ffb9919c1f0f66f206c9b2"),
+  ("4ca65a6d3c188eaaa00e74e4f2854c1cb5382702e06ce62cdf4c781788ef19b", "673b044f40e4272ed941c7b53d11e2bdc6edbe86d3204ae73dd858bc74daf837", "68670db91a0889174e189b1756e897c9cbd2d40c08ba0a648990fd5aa9751629"),
+  ("2c57ba3ab468d9288d21ee684ddd114236f207ebb1c87a09da71f2a471e51011", "6917badc1f97d550378aa7a34ddebc502625eb5cdde23e9d854e8cbd378a8616", "8b15994c9c1f9e56d9b770f2a86fbdd2d3d67fe2fbeb5c756ebff88197ccf1b1"),
+  ("36eac811c7126fda51c4e741739a70ef06195c68a9aba78a595fdb20085bdd58", "b45b75fb434c12c790f02dda8801e3603ecd97fda628a808f710c920962b4751", "c88e8fcf3c9c39ceea98296af80dd5a49da578d9a144de0e083da417c8a9c1e"),
+  ("80fab4e4c927434e3c62c7e86733abacf6d8269a6d1af8bb549fda8832f974ee", "66fd42cf2680f836939ee457da673f1d74fb511c1164314f0e6f20e9986349fe", "47dbdd7eada76f4c7fe3cf565e110ca45033273f72d827d1130bf55bfb7f0ee1"),
+  ("8d98a34af1c08f2f27aaa81fd709bd9f7a5faf10de157b2204fa7dd5ab3cb054", "f268e777e5086dc65406fa34561e1bef3e391949580e1c69fe10424e472a1da4", "9473024d3d7cbc5e1c9ddad37e117b500eb8e231896699db078d9439c6930c22"),
+  ("23a7b6fc436b71f1279e3920fd07ba3f73d245be6899e39ba83c9492100cac45", "f4efabc6bfd47a254a918bc5ef045a2973d0c214dbe10177aa44c7efbd7ae9a", "493c596ca5f33691f391c8d2ee32de986b83ad8732c8906288f9a4902863cd41"),
+  ("11bb995d9d71ca13805deafea963ded55c27e9ca67be34aefd83eb9b625d816b", "921fc7a64c606f2135f7ddd28d8843dd79e35fcdd0018fa262ebf0260bc59c7b", "e292127b7b8ce85f202ec8d1c3850de73d1a965630853dd63a72d10793395a0f"),
+  ("9634a50ebce56a72edbc191f763574de8904b6a27c03b1761fa3c48dadf96f75", "c9e12909e96eff26a6e5eaf9db035c7e2de21ac898ca78f678abdcaddd38e795", "e2f37d2f20571b93afae5d39e9cac349df6e644627b774c1ad1d79c8fc1abe2a"),
+  ("fa372963672bf10beee2aa893f91697cd8662a4b222b4d183c1bd83e7ec1840b", "b88ff022a7151f959fa2b36c0ee636e17f75b4ce16f8654a1388912f140152dc", "595a5aef9e785c3b19da7239bc19baaa71499b80e4b03605090676a6c76aa31a"),
+  ("f60a95f4e37e297bdfba19050799445e306a21cbcd3510cfa03b41ab00f311ae", "bd01ba4a47d3ade4f95b01396286132d83d381f84ba0f412aa0f4171419d1d6a", "ea12c5c66884c522ea5ec216fe948cc58a69743d30b99b335a5d010cbc24340d"),
+];
+
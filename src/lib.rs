@@ -1,4 +1,21 @@
 pub mod mod_math;
 pub mod galois_field;
 pub mod number_mod;
-pub mod curves;
\ No newline at end of file
+pub mod curves;
+pub mod group;
+pub mod util;
+pub mod random;
+mod error;
+mod self_test;
+
+pub use error::{Error, ErrorDetail};
+pub use self_test::{self_test, SelfTestError};
+
+mod conformance_test;
+mod error_test;
+mod equivalence_test;
+mod decode_test;
+mod self_test_test;
+#[cfg(test)]
+mod kat_vectors;
+mod kat_test;
@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+
+use primitive_types::U256;
+
+use super::{IntoU256, ModMath};
+
+/// `ModMathCache` keeps one `ModMath` per modulus, for workloads that run the same
+/// computation under many moduli (e.g. CRT-based tricks) and come back to each of them.
+///
+/// An instance is built on the first request for its modulus, and later requests return
+/// it together with whatever it has cached since, such as the primality of the modulus.
+///
+/// # Examples
+///
+/// ```
+/// use modular_math::mod_math::ModMathCache;
+/// use primitive_types::U256;
+///
+/// let mut cache = ModMathCache::new();
+/// let residues: Vec<U256> = [101u64, 103, 107].iter().map(|&m| cache.get(m).exp(2, 100)).collect();
+/// assert_eq!(residues[0], U256::from(1)); // 2^100 = 1 (mod 101)
+/// assert_eq!(cache.len(), 3);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ModMathCache {
+    instances: HashMap<U256, ModMath>,
+}
+
+impl ModMathCache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the `ModMath` for `modulus`, creating it on the first request.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is zero.
+    pub fn get<T: IntoU256>(&mut self, modulus: T) -> &ModMath {
+        let modulus = modulus.into_u256();
+        self.instances.entry(modulus).or_insert_with(|| ModMath::new(modulus))
+    }
+
+    /// Returns the number of moduli in the cache.
+    pub fn len(&self) -> usize {
+        self.instances.len()
+    }
+
+    /// Checks if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.instances.is_empty()
+    }
+
+    /// Removes all instances from the cache.
+    pub fn clear(&mut self) {
+        self.instances.clear()
+    }
+}
@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod tests {
+    use primitive_types::U256;
+
+    use crate::mod_math::mod_math::FIELD_PARAMS_BUILT;
+    use crate::mod_math::{ModMath, ModMathCache};
+
+    #[test]
+    fn test_cache_builds_each_modulus_once() {
+        let mut cache = ModMathCache::new();
+        assert!(cache.is_empty());
+        let moduli: Vec<u64> = (0..50).map(|i| (1u64 << 63) + 2 * i + 1).collect();
+
+        let before = FIELD_PARAMS_BUILT.with(|count| count.get());
+        for _ in 0..3 {
+            for &m in &moduli {
+                assert_eq!(cache.get(m).modulus, U256::from(m));
+            }
+        }
+        assert_eq!(FIELD_PARAMS_BUILT.with(|count| count.get()), before + moduli.len());
+        assert_eq!(cache.len(), moduli.len());
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_cache_matches_new() {
+        let mut cache = ModMathCache::new();
+        let (a, b) = (U256::MAX - 999, U256::from(0x1234_5678_u64) << 64);
+        for m in [3u64, 65537, 18446744073709551557] {
+            let fresh = ModMath::new(m);
+            assert_eq!(cache.get(m).mul(a, b), fresh.mul(a, b));
+            assert_eq!(cache.get(m).exp(a, b), fresh.exp(a, b));
+            assert_eq!(cache.get(m).inv(a), fresh.inv(a));
+        }
+
+        // cached state such as primality is kept between requests
+        assert!(cache.get(65537u64).is_prime_modulus());
+        let params = cache.get(65537u64).params().clone();
+        assert!(std::sync::Arc::ptr_eq(cache.get(65537u64).params(), &params));
+    }
+
+    #[test]
+    #[should_panic(expected = "Modulus Cannot be Zero")]
+    fn test_cache_zero_modulus() {
+        ModMathCache::new().get(0);
+    }
+}
@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod tests {
+    use primitive_types::U256;
+    use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+    use crate::mod_math::{InvError, ModMath};
+
+    // 2, 4, 100, 2^64 and a 256-bit even number
+    fn moduli() -> Vec<U256> {
+        vec![
+            U256::from(2),
+            U256::from(4),
+            U256::from(100),
+            U256::one() << 64,
+            (U256::MAX - U256::from(1000)) & !U256::one(),
+        ]
+    }
+
+    // small values, values around the modulus and random values
+    fn values(modulus: U256, rng: &mut StdRng) -> Vec<U256> {
+        let mut values: Vec<U256> = (0..12u64).map(U256::from).collect();
+        values.extend([modulus - U256::one(), modulus, modulus + U256::one(), U256::MAX]);
+        values.extend((0..8).map(|_| {
+            let mut bytes = [0u8; 32];
+            rng.fill_bytes(&mut bytes);
+            U256::from_big_endian(&bytes)
+        }));
+        values
+    }
+
+    fn gcd(a: U256, b: U256) -> U256 {
+        if b.is_zero() { a } else { gcd(b, a % b) }
+    }
+
+    #[test]
+    fn test_inverses_agree() {
+        let mut rng = StdRng::seed_from_u64(958);
+        for modulus in moduli() {
+            let math = ModMath::new(modulus);
+            for a in values(modulus, &mut rng) {
+                let inv = math.inv(a);
+                assert_eq!(math.is_invertible(a), inv.is_some(), "{} mod {}", a, modulus);
+                assert_eq!(math.inv_detailed(a).ok(), inv, "{} mod {}", a, modulus);
+                match inv {
+                    Some(inv) => assert_eq!(math.mul(a, inv), U256::one(), "{} mod {}", a, modulus),
+                    None => {
+                        let g = gcd(modulus, a % modulus);
+                        let expected = if g == modulus { InvError::Zero } else { InvError::NotCoprime(g) };
+                        assert_eq!(math.checked_div(U256::one(), a), Err(expected), "{} mod {}", a, modulus);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_division() {
+        let mut rng = StdRng::seed_from_u64(959);
+        for modulus in moduli() {
+            let math = ModMath::new(modulus);
+            let values = values(modulus, &mut rng);
+            for (&a, &b) in values.iter().zip(values.iter().rev()) {
+                if let Ok(q) = math.checked_div(a, b) {
+                    assert_eq!(math.mul(q, b), math.reduce(a), "{} / {} mod {}", a, b, modulus);
+                    assert_eq!(math.div(a, b), q);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_add_inv_eq_exp() {
+        let mut rng = StdRng::seed_from_u64(960);
+        for modulus in moduli() {
+            let math = ModMath::new(modulus);
+            for a in values(modulus, &mut rng) {
+                let minus_a = math.add_inv(a);
+                assert!(minus_a < modulus);
+                assert_eq!(math.add(a, minus_a), U256::zero(), "{} mod {}", a, modulus);
+                assert!(math.eq(math.reduce(a), a));
+                assert!(!math.eq(a, math.reduce(a) + U256::one()), "{} mod {}", a, modulus);
+
+                assert_eq!(math.exp(a, U256::zero()), U256::one() % modulus);
+                assert_eq!(math.exp(a, U256::one()), math.reduce(a));
+                assert_eq!(math.exp(a, U256::from(3)), math.mul(math.square(a), a), "{} mod {}", a, modulus);
+            }
+        }
+        // 2^64 is zero mod 2^64, and 3 has order 2^62 mod 2^64
+        let math = ModMath::new(U256::one() << 64);
+        assert_eq!(math.exp(2, 64), U256::zero());
+        assert_eq!(math.exp(U256::from(3), U256::one() << 62), U256::one());
+    }
+
+    #[test]
+    #[should_panic(expected = "5 is not invertible mod 100: gcd = 5")]
+    fn test_div_panic_names_gcd() {
+        ModMath::new(100).div(1, 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "0 is not invertible mod 4: gcd = 4")]
+    fn test_div_by_zero_panic_names_gcd() {
+        ModMath::new(4).div(1, 0);
+    }
+}
@@ -0,0 +1,164 @@
+use primitive_types::U256;
+
+use super::{ArithError, ModMath};
+
+impl ModMath {
+    /// Computes the cyclic convolution of `a` and `b` under the prime modulus, i.e. the
+    /// product of the polynomials with these coefficients modulo `x^n - 1`.
+    ///
+    /// Uses a number-theoretic transform when `n` is a power of two dividing `p - 1`, and
+    /// the quadratic algorithm otherwise.
+    ///
+    /// Returns `ArithError::LengthMismatch` if the slices have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// let math = ModMath::new(17);
+    /// let a = [U256::from(1), U256::from(2)];
+    /// let b = [U256::from(3), U256::from(4)];
+    /// // (1 + 2x)(3 + 4x) = 3 + 10x + 8x^2 = 11 + 10x (mod x^2 - 1)
+    /// assert_eq!(math.cyclic_convolution(&a, &b), Ok(vec![U256::from(11), U256::from(10)]));
+    /// ```
+    pub fn cyclic_convolution(&self, a: &[U256], b: &[U256]) -> Result<Vec<U256>, ArithError> {
+        if a.len() != b.len() {
+            return Err(ArithError::LengthMismatch);
+        }
+        match self.ntt_root(a.len()) {
+            Some(w) => Ok(self.ntt_convolution(a, b, w)),
+            None => Ok(self.quadratic_convolution(a, b, false)),
+        }
+    }
+
+    /// Computes the negacyclic convolution of `a` and `b` under the prime modulus, i.e.
+    /// the product of the polynomials with these coefficients modulo `x^n + 1`.
+    ///
+    /// Uses a number-theoretic transform, twisted by a primitive `2n`-th root of unity,
+    /// when `n` is a power of two and `2n` divides `p - 1`, and the quadratic algorithm
+    /// otherwise.
+    ///
+    /// Returns `ArithError::LengthMismatch` if the slices have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// let math = ModMath::new(17);
+    /// let a = [U256::from(1), U256::from(2)];
+    /// let b = [U256::from(3), U256::from(4)];
+    /// // 3 + 10x + 8x^2 = -5 + 10x = 12 + 10x (mod x^2 + 1)
+    /// assert_eq!(math.negacyclic_convolution(&a, &b), Ok(vec![U256::from(12), U256::from(10)]));
+    /// ```
+    pub fn negacyclic_convolution(&self, a: &[U256], b: &[U256]) -> Result<Vec<U256>, ArithError> {
+        if a.len() != b.len() {
+            return Err(ArithError::LengthMismatch);
+        }
+        let n = a.len();
+        let Some(psi) = self.ntt_root(2 * n) else {
+            return Ok(self.quadratic_convolution(a, b, true));
+        };
+        // a(x) b(x) mod x^n + 1 is a cyclic convolution of the inputs scaled by psi^i
+        let powers = self.powers(psi, n);
+        let twist = |v: &[U256]| -> Vec<U256> { v.iter().zip(&powers).map(|(&x, &p)| self.mul(x, p)).collect() };
+        let product = self.ntt_convolution(&twist(a), &twist(b), self.square(psi));
+        let psi_inv = self.inv(psi).expect("a root of unity is invertible");
+        Ok(product.iter().zip(self.powers(psi_inv, n)).map(|(&x, p)| self.mul(x, p)).collect())
+    }
+
+    // A primitive n-th root of unity for a power of two n > 1 dividing p - 1, if the
+    // modulus is prime
+    pub(super) fn ntt_root(&self, n: usize) -> Option<U256> {
+        if n < 2 || !n.is_power_of_two() || !self.is_prime_modulus() {
+            return None;
+        }
+        let group_order = self.modulus - U256::one();
+        if group_order % U256::from(n) != U256::zero() {
+            return None;
+        }
+        // c^((p - 1) / n) has order exactly n unless its (n/2)-th power is 1
+        let cofactor = group_order / U256::from(n);
+        let half = U256::from(n / 2);
+        let mut candidate = U256::from(2);
+        loop {
+            let w = self.exp(candidate, cofactor);
+            if self.exp(w, half) != U256::one() {
+                return Some(w);
+            }
+            candidate += U256::one();
+        }
+    }
+
+    // [1, w, w^2, ..., w^(n-1)]
+    pub(super) fn powers(&self, w: U256, n: usize) -> Vec<U256> {
+        let mut powers = Vec::with_capacity(n);
+        let mut current = U256::one();
+        for _ in 0..n {
+            powers.push(current);
+            current = self.mul(current, w);
+        }
+        powers
+    }
+
+    fn ntt_convolution(&self, a: &[U256], b: &[U256], w: U256) -> Vec<U256> {
+        let n = a.len();
+        let mut a_hat = a.iter().map(|&x| self.reduce(x)).collect::<Vec<_>>();
+        let mut b_hat = b.iter().map(|&x| self.reduce(x)).collect::<Vec<_>>();
+        self.ntt(&mut a_hat, w);
+        self.ntt(&mut b_hat, w);
+        let mut product: Vec<U256> = a_hat.iter().zip(&b_hat).map(|(&x, &y)| self.mul(x, y)).collect();
+
+        let w_inv = self.inv(w).expect("a root of unity is invertible");
+        self.ntt(&mut product, w_inv);
+        let n_inv = self.inv(U256::from(n)).expect("n divides p - 1, so it is below p");
+        product.iter().map(|&x| self.mul(x, n_inv)).collect()
+    }
+
+    // In-place iterative radix-2 transform, evaluating at the powers of the n-th root w
+    pub(super) fn ntt(&self, values: &mut [U256], w: U256) {
+        let n = values.len();
+        if n < 2 {
+            return;
+        }
+        let bits = n.trailing_zeros();
+        for i in 0..n {
+            let j = i.reverse_bits() >> (usize::BITS - bits);
+            if i < j {
+                values.swap(i, j);
+            }
+        }
+
+        let mut len = 2;
+        while len <= n {
+            let step = self.exp(w, U256::from(n / len));
+            let twiddles = self.powers(step, len / 2);
+            for start in (0..n).step_by(len) {
+                for (k, &twiddle) in twiddles.iter().enumerate() {
+                    let u = values[start + k];
+                    let v = self.mul(values[start + k + len / 2], twiddle);
+                    values[start + k] = self.add(u, v);
+                    values[start + k + len / 2] = self.sub(u, v);
+                }
+            }
+            len *= 2;
+        }
+    }
+
+    // The schoolbook product, wrapping x^n around to 1 (cyclic) or to -1 (negacyclic)
+    fn quadratic_convolution(&self, a: &[U256], b: &[U256], negacyclic: bool) -> Vec<U256> {
+        let n = a.len();
+        let mut result = vec![U256::zero(); n];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                let term = self.mul(x, y);
+                let k = (i + j) % n;
+                result[k] = if negacyclic && i + j >= n { self.sub(result[k], term) } else { self.add(result[k], term) };
+            }
+        }
+        result
+    }
+}
@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+    use primitive_types::U256;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use crate::mod_math::{ArithError, ModMath};
+
+    // 998244353 = 119 * 2^23 + 1
+    const NTT_PRIME: u64 = 998244353;
+
+    fn naive(math: &ModMath, a: &[U256], b: &[U256], negacyclic: bool) -> Vec<U256> {
+        let n = a.len();
+        let mut result = vec![U256::zero(); n];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                let term = math.mul(x, y);
+                let k = (i + j) % n;
+                if negacyclic && i + j >= n {
+                    result[k] = math.sub(result[k], term);
+                } else {
+                    result[k] = math.add(result[k], term);
+                }
+            }
+        }
+        result
+    }
+
+    fn random_vector(rng: &mut StdRng, n: usize, modulus: U256) -> Vec<U256> {
+        (0..n).map(|_| U256::from(rng.gen::<u64>()) * U256::from(rng.gen::<u64>()) % modulus).collect()
+    }
+
+    fn check_against_naive(math: &ModMath, modulus: U256, lengths: &[usize]) {
+        let mut rng = StdRng::seed_from_u64(968);
+        for &n in lengths {
+            let a = random_vector(&mut rng, n, modulus);
+            let b = random_vector(&mut rng, n, modulus);
+            assert_eq!(math.cyclic_convolution(&a, &b).unwrap(), naive(math, &a, &b, false), "cyclic, n = {}", n);
+            assert_eq!(math.negacyclic_convolution(&a, &b).unwrap(), naive(math, &a, &b, true), "negacyclic, n = {}", n);
+        }
+    }
+
+    #[test]
+    fn test_convolution_ntt_friendly_prime() {
+        let math = ModMath::new(NTT_PRIME);
+        check_against_naive(&math, U256::from(NTT_PRIME), &[1, 2, 8, 1024]);
+
+        // the BN128 scalar field, with 2-adicity 28
+        let r = U256::from_dec_str("21888242871839275222246405745257275088548364400416034343698204186575808495617").unwrap();
+        check_against_naive(&ModMath::new(r), r, &[8, 64]);
+    }
+
+    #[test]
+    fn test_convolution_fallback() {
+        // p - 1 = 2 * odd, so no NTT of length 4 or more, nor a negacyclic one of length 2
+        let secp256k1_p = U256::from_dec_str("115792089237316195423570985008687907853269984665640564039457584007908834671663").unwrap();
+        check_against_naive(&ModMath::new(secp256k1_p), secp256k1_p, &[2, 8, 12]);
+        // lengths that are not a power of two
+        check_against_naive(&ModMath::new(NTT_PRIME), U256::from(NTT_PRIME), &[3, 6, 100]);
+    }
+
+    #[test]
+    fn test_convolution_length_mismatch() {
+        let math = ModMath::new(NTT_PRIME);
+        let (a, b) = (vec![U256::one(); 4], vec![U256::one(); 3]);
+        assert_eq!(math.cyclic_convolution(&a, &b), Err(ArithError::LengthMismatch));
+        assert_eq!(math.negacyclic_convolution(&a, &b), Err(ArithError::LengthMismatch));
+        assert_eq!(math.cyclic_convolution(&[], &[]), Ok(vec![]));
+        assert_eq!(math.negacyclic_convolution(&[], &[]), Ok(vec![]));
+    }
+}
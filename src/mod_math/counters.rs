@@ -0,0 +1,92 @@
+#[cfg(feature = "instrumentation")]
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Counts of the field operations an instance has performed, see `ModMath::counters`.
+///
+/// Operations built on others count those too: `exp` counts its multiplications and
+/// squarings, and `div` an inversion and a multiplication.
+#[cfg(feature = "instrumentation")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OpCounters {
+    /// Modular multiplications
+    pub muls: u64,
+    /// Modular squarings
+    pub squares: u64,
+    /// Modular inversions
+    pub inversions: u64,
+    /// Modular additions and subtractions
+    pub additions: u64,
+}
+
+// Zero-sized unless the `instrumentation` feature is enabled. Atomics rather than `Cell`s
+// keep the arithmetic types `Sync`, which the rayon variants rely on.
+#[derive(Debug, Default)]
+pub(crate) struct Counters {
+    #[cfg(feature = "instrumentation")]
+    muls: AtomicU64,
+    #[cfg(feature = "instrumentation")]
+    squares: AtomicU64,
+    #[cfg(feature = "instrumentation")]
+    inversions: AtomicU64,
+    #[cfg(feature = "instrumentation")]
+    additions: AtomicU64,
+}
+
+impl Counters {
+    #[inline]
+    pub(crate) fn mul(&self) {
+        #[cfg(feature = "instrumentation")]
+        self.muls.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub(crate) fn square(&self) {
+        #[cfg(feature = "instrumentation")]
+        self.squares.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub(crate) fn inversion(&self) {
+        #[cfg(feature = "instrumentation")]
+        self.inversions.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub(crate) fn addition(&self) {
+        #[cfg(feature = "instrumentation")]
+        self.additions.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[cfg(feature = "instrumentation")]
+    pub(crate) fn snapshot(&self) -> OpCounters {
+        OpCounters {
+            muls: self.muls.load(Ordering::Relaxed),
+            squares: self.squares.load(Ordering::Relaxed),
+            inversions: self.inversions.load(Ordering::Relaxed),
+            additions: self.additions.load(Ordering::Relaxed),
+        }
+    }
+
+    #[cfg(feature = "instrumentation")]
+    pub(crate) fn reset(&self) {
+        for counter in [&self.muls, &self.squares, &self.inversions, &self.additions] {
+            counter.store(0, Ordering::Relaxed);
+        }
+    }
+}
+
+// A copy starts from the same counts
+impl Clone for Counters {
+    fn clone(&self) -> Self {
+        let counters = Counters::default();
+        #[cfg(feature = "instrumentation")]
+        {
+            let snapshot = self.snapshot();
+            counters.muls.store(snapshot.muls, Ordering::Relaxed);
+            counters.squares.store(snapshot.squares, Ordering::Relaxed);
+            counters.inversions.store(snapshot.inversions, Ordering::Relaxed);
+            counters.additions.store(snapshot.additions, Ordering::Relaxed);
+        }
+        counters
+    }
+}
@@ -0,0 +1,152 @@
+use primitive_types::U256;
+
+use super::{ArithError, IntoU256, ModMath};
+
+/// The evaluation domain `{1, w, w^2, ..., w^(n-1)}` of the `n`-th roots of unity under
+/// a prime modulus `p`, for a power of two `n` dividing `p - 1`.
+///
+/// A polynomial of degree below `n` is determined by its values on the domain, and
+/// converting between values and coefficients is a number-theoretic transform.
+///
+/// # Examples
+///
+/// ```
+/// use modular_math::mod_math::{Domain, ModMath};
+/// use primitive_types::U256;
+///
+/// let math = ModMath::new(17);
+/// let domain = Domain::new(&math, 4).unwrap();
+/// // the values of 1 + 2x on the domain
+/// let values: Vec<U256> = domain.elements().iter().map(|&w| math.add(1, math.mul(2, w))).collect();
+/// let coefficients = domain.interpolate(&values).unwrap();
+/// assert_eq!(coefficients, vec![U256::from(1), U256::from(2), U256::zero(), U256::zero()]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Domain {
+    math: ModMath,
+    elements: Vec<U256>,
+    // w^-1 and n^-1, for the inverse transform
+    root_inv: U256,
+    size_inv: U256,
+}
+
+impl Domain {
+    /// Creates the domain of the `size`-th roots of unity under the modulus of `math`.
+    ///
+    /// Returns None unless the modulus is prime and `size` is a power of two, at least
+    /// 2, that divides `p - 1`.
+    pub fn new(math: &ModMath, size: usize) -> Option<Self> {
+        let root = math.ntt_root(size)?;
+        Some(Domain {
+            math: math.clone(),
+            elements: math.powers(root, size),
+            root_inv: math.inv(root)?,
+            size_inv: math.inv(U256::from(size))?,
+        })
+    }
+
+    /// Returns the number of elements `n`.
+    pub fn size(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns the elements `w^i` in order.
+    pub fn elements(&self) -> &[U256] {
+        &self.elements
+    }
+
+    /// Returns the arithmetic under the modulus of the domain.
+    pub fn math(&self) -> &ModMath {
+        &self.math
+    }
+
+    /// Returns the coefficients, lowest degree first, of the polynomial of degree below
+    /// `n` that takes `values[i]` at `w^i`, by an inverse transform.
+    ///
+    /// Returns `ArithError::LengthMismatch` unless there are exactly `n` values.
+    pub fn interpolate(&self, values: &[U256]) -> Result<Vec<U256>, ArithError> {
+        if values.len() != self.size() {
+            return Err(ArithError::LengthMismatch);
+        }
+        let mut coefficients: Vec<U256> = values.iter().map(|&v| self.math.reduce(v)).collect();
+        self.math.ntt(&mut coefficients, self.root_inv);
+        Ok(coefficients.iter().map(|&c| self.math.mul(c, self.size_inv)).collect())
+    }
+
+    /// Evaluates the polynomial with the given coefficients, lowest degree first, at
+    /// every element of the domain. The inverse of `interpolate`.
+    ///
+    /// Returns `ArithError::LengthMismatch` unless there are exactly `n` coefficients.
+    pub fn evaluate(&self, coefficients: &[U256]) -> Result<Vec<U256>, ArithError> {
+        if coefficients.len() != self.size() {
+            return Err(ArithError::LengthMismatch);
+        }
+        let mut values: Vec<U256> = coefficients.iter().map(|&c| self.math.reduce(c)).collect();
+        self.math.ntt(&mut values, self.elements[1]);
+        Ok(values)
+    }
+
+    /// Returns the Lagrange basis of the domain: the coefficients of each `L_i`, the
+    /// polynomial of degree below `n` that is 1 at `w^i` and 0 at the other elements.
+    ///
+    /// `L_i` is the interpolation of the `i`-th unit vector, so a polynomial with the
+    /// values `v` is `v[0] * L_0 + ... + v[n-1] * L_(n-1)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::{Domain, ModMath};
+    /// use primitive_types::U256;
+    ///
+    /// let domain = Domain::new(&ModMath::new(17), 4).unwrap();
+    /// let basis = domain.lagrange_basis();
+    /// assert_eq!(domain.evaluate(&basis[1]).unwrap(), vec![U256::zero(), U256::one(), U256::zero(), U256::zero()]);
+    /// ```
+    pub fn lagrange_basis(&self) -> Vec<Vec<U256>> {
+        let n = self.size();
+        (0..n)
+            .map(|i| {
+                let mut unit = vec![U256::zero(); n];
+                unit[i] = U256::one();
+                self.math.ntt(&mut unit, self.root_inv);
+                unit.iter().map(|&c| self.math.mul(c, self.size_inv)).collect()
+            })
+            .collect()
+    }
+
+    /// Evaluates every Lagrange basis polynomial at `z` without computing the basis, by
+    /// the barycentric formula `L_i(z) = w^i (z^n - 1) / (n (z - w^i))`.
+    ///
+    /// The `n` differences `z - w^i` are inverted with a single batch inversion. For `z`
+    /// in the domain, where the formula divides by zero, this is the unit vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::{Domain, ModMath};
+    /// use primitive_types::U256;
+    ///
+    /// let math = ModMath::new(17);
+    /// let domain = Domain::new(&math, 4).unwrap();
+    /// let at_five = domain.evaluate_lagrange_basis(5);
+    /// // the basis sums to the constant polynomial 1
+    /// assert_eq!(at_five.iter().fold(U256::zero(), |sum, &l| math.add(sum, l)), U256::one());
+    /// ```
+    pub fn evaluate_lagrange_basis<T: IntoU256>(&self, z: T) -> Vec<U256> {
+        let z = self.math.reduce(z);
+        let differences: Vec<U256> = self.elements.iter().map(|&w| self.math.sub(z, w)).collect();
+        match self.math.batch_inv(&differences) {
+            Some(inverses) => {
+                let n = U256::from(self.size());
+                let scale = self.math.mul(self.math.sub(self.math.exp(z, n), U256::one()), self.size_inv);
+                self.elements
+                    .iter()
+                    .zip(inverses)
+                    .map(|(&w, inverse)| self.math.mul(self.math.mul(w, inverse), scale))
+                    .collect()
+            }
+            // only a zero difference has no inverse, so z is in the domain
+            None => self.elements.iter().map(|&w| if w == z { U256::one() } else { U256::zero() }).collect(),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod tests {
+    use primitive_types::U256;
+    use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+    use crate::mod_math::{ArithError, Domain, ModMath};
+
+    fn bn128_r() -> U256 {
+        U256::from_dec_str("21888242871839275222246405745257275088548364400416034343698204186575808495617").unwrap()
+    }
+
+    fn horner(math: &ModMath, coefficients: &[U256], x: U256) -> U256 {
+        coefficients.iter().rev().fold(U256::zero(), |acc, &c| math.add(math.mul(acc, x), c))
+    }
+
+    // prod_(j != i) (x - w_j) / (w_i - w_j), multiplied out one linear factor at a time
+    fn naive_lagrange(math: &ModMath, elements: &[U256], i: usize) -> Vec<U256> {
+        let mut coefficients = vec![U256::one()];
+        let mut denominator = U256::one();
+        for (j, &w) in elements.iter().enumerate() {
+            if j == i {
+                continue;
+            }
+            let mut next = vec![U256::zero(); coefficients.len() + 1];
+            for (k, &c) in coefficients.iter().enumerate() {
+                next[k + 1] = math.add(next[k + 1], c);
+                next[k] = math.sub(next[k], math.mul(c, w));
+            }
+            coefficients = next;
+            denominator = math.mul(denominator, math.sub(elements[i], w));
+        }
+        let scale = math.inv(denominator).unwrap();
+        coefficients.iter().map(|&c| math.mul(c, scale)).collect()
+    }
+
+    #[test]
+    fn test_lagrange_basis_matches_naive_interpolation() {
+        for (modulus, size) in [(U256::from(17), 8), (U256::from(97), 16), (bn128_r(), 16)] {
+            let math = ModMath::new(modulus);
+            let domain = Domain::new(&math, size).unwrap();
+            let basis = domain.lagrange_basis();
+            assert_eq!(basis.len(), size);
+            for (i, basis_polynomial) in basis.iter().enumerate() {
+                assert_eq!(basis_polynomial, &naive_lagrange(&math, domain.elements(), i), "L_{} mod {}", i, modulus);
+            }
+        }
+    }
+
+    #[test]
+    fn test_domain_elements() {
+        let math = ModMath::new(bn128_r());
+        let domain = Domain::new(&math, 16).unwrap();
+        assert_eq!(domain.elements()[0], U256::one());
+        assert_eq!(math.exp(domain.elements()[1], U256::from(16)), U256::one());
+        assert_eq!(math.exp(domain.elements()[1], U256::from(8)), math.add_inv(1));
+
+        // 17 - 1 = 16, so there is no 32nd root of unity; 3 is not a power of two
+        assert!(Domain::new(&ModMath::new(17), 32).is_none());
+        assert!(Domain::new(&ModMath::new(17), 3).is_none());
+        assert!(Domain::new(&ModMath::new(15), 2).is_none());
+    }
+
+    #[test]
+    fn test_interpolate_and_evaluate() {
+        let math = ModMath::new(bn128_r());
+        let domain = Domain::new(&math, 16).unwrap();
+        let mut rng = StdRng::seed_from_u64(977);
+        let values: Vec<U256> = (0..16).map(|_| math.reduce(U256::from(rng.next_u64()) << 128)).collect();
+        let coefficients = domain.interpolate(&values).unwrap();
+        for (&w, &v) in domain.elements().iter().zip(&values) {
+            assert_eq!(horner(&math, &coefficients, w), v);
+        }
+        assert_eq!(domain.evaluate(&coefficients).unwrap(), values);
+        assert_eq!(domain.interpolate(&values[1..]), Err(ArithError::LengthMismatch));
+        assert_eq!(domain.evaluate(&values[1..]), Err(ArithError::LengthMismatch));
+    }
+
+    #[test]
+    fn test_evaluate_lagrange_basis() {
+        let math = ModMath::new(bn128_r());
+        let domain = Domain::new(&math, 16).unwrap();
+        let basis = domain.lagrange_basis();
+        for z in [U256::from(5), U256::from(123456789), bn128_r() - 1] {
+            let expected: Vec<U256> = basis.iter().map(|l| horner(&math, l, z)).collect();
+            assert_eq!(domain.evaluate_lagrange_basis(z), expected);
+        }
+        let mut unit = vec![U256::zero(); 16];
+        unit[3] = U256::one();
+        assert_eq!(domain.evaluate_lagrange_basis(domain.elements()[3]), unit);
+    }
+}
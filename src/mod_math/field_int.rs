@@ -0,0 +1,328 @@
+use std::fmt::Debug;
+
+use primitive_types::{U256, U512};
+
+use super::counters::Counters;
+#[cfg(feature = "instrumentation")]
+use super::counters::OpCounters;
+use super::mod_math::ModMath;
+use u1024::U1024;
+
+// Lints fire inside the `construct_uint!` expansion, which we don't control
+#[allow(clippy::all)]
+mod u1024 {
+    uint::construct_uint! {
+        /// Holds the full product of two `U512` values.
+        pub(super) struct U1024(16);
+    }
+}
+
+/// An unsigned integer type that `Curve` and `ECPoint` can be built over.
+///
+/// Implemented for `U256`, whose arithmetic is done by `ModMath`, and for `U512`,
+/// whose arithmetic is done by `WideModMath`.
+pub trait FieldInt: Copy + Debug + Eq + Ord {
+    /// The modular arithmetic used for this integer type
+    type Math: FieldMath<Self>;
+
+    fn from_u64(value: u64) -> Self;
+
+    /// Returns the number of bits needed to represent the value.
+    fn bits(&self) -> usize;
+
+    /// Returns the bit at `index`, counting from the least significant bit.
+    fn bit(&self, index: usize) -> bool;
+
+    fn zero() -> Self {
+        Self::from_u64(0)
+    }
+
+    fn one() -> Self {
+        Self::from_u64(1)
+    }
+}
+
+/// The modular arithmetic `Curve` needs from its field.
+pub trait FieldMath<T: Copy>: Clone {
+    /// Creates the arithmetic for the given modulus.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is zero.
+    fn with_modulus(modulus: T) -> Self;
+
+    fn reduce(&self, a: T) -> T;
+
+    fn add(&self, a: T, b: T) -> T;
+
+    fn sub(&self, a: T, b: T) -> T;
+
+    fn mul(&self, a: T, b: T) -> T;
+
+    /// # Panics
+    ///
+    /// Panics if `b` has no inverse.
+    fn div(&self, a: T, b: T) -> T;
+
+    fn square(&self, a: T) -> T {
+        self.mul(a, a)
+    }
+
+    /// Returns the number of field operations performed so far.
+    #[cfg(feature = "instrumentation")]
+    fn counters(&self) -> OpCounters;
+
+    /// Sets all operation counters back to zero.
+    #[cfg(feature = "instrumentation")]
+    fn reset_counters(&self);
+}
+
+impl FieldInt for U256 {
+    type Math = ModMath;
+
+    fn from_u64(value: u64) -> Self {
+        U256::from(value)
+    }
+
+    fn bits(&self) -> usize {
+        U256::bits(self)
+    }
+
+    fn bit(&self, index: usize) -> bool {
+        U256::bit(self, index)
+    }
+}
+
+impl FieldMath<U256> for ModMath {
+    fn with_modulus(modulus: U256) -> Self {
+        ModMath::new(modulus)
+    }
+
+    fn reduce(&self, a: U256) -> U256 {
+        ModMath::reduce(self, a)
+    }
+
+    fn add(&self, a: U256, b: U256) -> U256 {
+        ModMath::add(self, a, b)
+    }
+
+    fn sub(&self, a: U256, b: U256) -> U256 {
+        ModMath::sub(self, a, b)
+    }
+
+    fn mul(&self, a: U256, b: U256) -> U256 {
+        ModMath::mul(self, a, b)
+    }
+
+    fn div(&self, a: U256, b: U256) -> U256 {
+        ModMath::div(self, a, b)
+    }
+
+    fn square(&self, a: U256) -> U256 {
+        ModMath::square(self, a)
+    }
+
+    #[cfg(feature = "instrumentation")]
+    fn counters(&self) -> OpCounters {
+        ModMath::counters(self)
+    }
+
+    #[cfg(feature = "instrumentation")]
+    fn reset_counters(&self) {
+        ModMath::reset_counters(self)
+    }
+}
+
+impl FieldInt for U512 {
+    type Math = WideModMath;
+
+    fn from_u64(value: u64) -> Self {
+        U512::from(value)
+    }
+
+    fn bits(&self) -> usize {
+        U512::bits(self)
+    }
+
+    fn bit(&self, index: usize) -> bool {
+        U512::bit(self, index)
+    }
+}
+
+/// `WideModMath` is modular arithmetic over `U512`, for fields wider than 256 bits.
+///
+/// It covers the operations curve arithmetic needs; use `ModMath` for the full
+/// `U256` API.
+///
+/// # Examples
+///
+/// ```
+/// use modular_math::mod_math::WideModMath;
+/// use primitive_types::U512;
+///
+/// let math = WideModMath::new(U512::from(97));
+/// assert_eq!(math.mul(U512::from(10), U512::from(20)), U512::from(6));
+/// ```
+#[derive(Clone, Debug)]
+pub struct WideModMath {
+    modulus: U512,
+    counters: Counters,
+}
+
+impl WideModMath {
+    /// Creates a new `WideModMath` with the given modulus.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is zero.
+    pub fn new(modulus: U512) -> Self {
+        if modulus == U512::zero() {
+            panic!("Modulus Cannot be Zero");
+        }
+        WideModMath { modulus, counters: Counters::default() }
+    }
+
+    /// Reduces a `U512` number modulo the modulus.
+    pub fn reduce(&self, a: U512) -> U512 {
+        a % self.modulus
+    }
+
+    /// Reduces `a` modulo the modulus, like `reduce`.
+    #[deprecated(note = "use `reduce`; the name `modulus` reads like a getter")]
+    pub fn modulus(&self, a: U512) -> U512 {
+        self.reduce(a)
+    }
+
+    /// Adds two `U512` numbers under the modulus.
+    pub fn add(&self, a: U512, b: U512) -> U512 {
+        self.counters.addition();
+        let (a, b) = (self.reduce(a), self.reduce(b));
+        let (sum, overflow) = a.overflowing_add(b);
+        if overflow || sum >= self.modulus {
+            sum.overflowing_sub(self.modulus).0
+        } else {
+            sum
+        }
+    }
+
+    /// Subtracts the second `U512` number from the first one under the modulus.
+    pub fn sub(&self, a: U512, b: U512) -> U512 {
+        self.counters.addition();
+        let (a, b) = (self.reduce(a), self.reduce(b));
+        if b > a {
+            self.modulus - (b - a)
+        } else {
+            a - b
+        }
+    }
+
+    /// Multiplies two `U512` numbers under the modulus.
+    pub fn mul(&self, a: U512, b: U512) -> U512 {
+        self.counters.mul();
+        let product = to_u1024(a) * to_u1024(b);
+        from_u1024(product % to_u1024(self.modulus))
+    }
+
+    /// Squares a `U512` number under the modulus.
+    pub fn square(&self, a: U512) -> U512 {
+        self.counters.square();
+        let a = to_u1024(a);
+        from_u1024((a * a) % to_u1024(self.modulus))
+    }
+
+    /// Calculates the multiplicative inverse of a `U512` number under the modulus.
+    ///
+    /// Returns `None` if the number is not coprime to the modulus.
+    pub fn inv(&self, a: U512) -> Option<U512> {
+        self.counters.inversion();
+        let (mut r0, mut r1) = (self.modulus, self.reduce(a));
+        let (mut t0, mut t1) = (U512::zero(), U512::one());
+        while r1 != U512::zero() {
+            let q = r0 / r1;
+            (r0, r1) = (r1, r0 - q * r1);
+            (t0, t1) = (t1, self.sub(t0, self.mul(q, t1)));
+        }
+        if r0 == U512::one() {
+            Some(self.reduce(t0))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of field operations performed by this instance so far.
+    #[cfg(feature = "instrumentation")]
+    pub fn counters(&self) -> OpCounters {
+        self.counters.snapshot()
+    }
+
+    /// Sets all operation counters back to zero.
+    #[cfg(feature = "instrumentation")]
+    pub fn reset_counters(&self) {
+        self.counters.reset()
+    }
+
+    /// Divides the first `U512` number by the second one under the modulus.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor has no inverse.
+    pub fn div(&self, a: U512, b: U512) -> U512 {
+        let b_inv = self.inv(b).unwrap_or_else(|| {
+            panic!("Cannot find Inverse of {}", b);
+        });
+        self.mul(a, b_inv)
+    }
+}
+
+impl FieldMath<U512> for WideModMath {
+    fn with_modulus(modulus: U512) -> Self {
+        WideModMath::new(modulus)
+    }
+
+    fn reduce(&self, a: U512) -> U512 {
+        WideModMath::reduce(self, a)
+    }
+
+    fn add(&self, a: U512, b: U512) -> U512 {
+        WideModMath::add(self, a, b)
+    }
+
+    fn sub(&self, a: U512, b: U512) -> U512 {
+        WideModMath::sub(self, a, b)
+    }
+
+    fn mul(&self, a: U512, b: U512) -> U512 {
+        WideModMath::mul(self, a, b)
+    }
+
+    fn div(&self, a: U512, b: U512) -> U512 {
+        WideModMath::div(self, a, b)
+    }
+
+    fn square(&self, a: U512) -> U512 {
+        WideModMath::square(self, a)
+    }
+
+    #[cfg(feature = "instrumentation")]
+    fn counters(&self) -> OpCounters {
+        WideModMath::counters(self)
+    }
+
+    #[cfg(feature = "instrumentation")]
+    fn reset_counters(&self) {
+        WideModMath::reset_counters(self)
+    }
+}
+
+fn to_u1024(a: U512) -> U1024 {
+    let mut limbs = [0u64; 16];
+    limbs[..8].copy_from_slice(&a.0);
+    U1024(limbs)
+}
+
+// Only called on values known to fit in 512 bits
+fn from_u1024(a: U1024) -> U512 {
+    let mut limbs = [0u64; 8];
+    limbs.copy_from_slice(&a.0[..8]);
+    U512(limbs)
+}
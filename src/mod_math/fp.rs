@@ -0,0 +1,111 @@
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use primitive_types::U256;
+
+use super::{IntoU256, ModMath};
+
+/// `Fp` is a reduced value together with the `ModMath` it lives under, so that values can
+/// be combined with the arithmetic operators. Unlike `NumberUnderMod` it borrows the
+/// arithmetic instead of rebuilding it for every operation.
+///
+/// # Panics
+///
+/// The binary operators panic if the operands are under different moduli, and division
+/// panics if the divisor is not invertible.
+///
+/// # Examples
+///
+/// ```
+/// use modular_math::mod_math::ModMath;
+/// use primitive_types::U256;
+///
+/// let math = ModMath::new(13);
+/// let (a, b) = (math.elem(5), math.elem(9));
+/// assert_eq!((a + b).value(), U256::one());
+/// assert_eq!((a * b - a / b).value(), math.sub(math.mul(5, 9), math.div(5, 9)));
+/// ```
+#[derive(Clone, Copy)]
+pub struct Fp<'a> {
+    math: &'a ModMath,
+    value: U256,
+}
+
+impl ModMath {
+    /// Wraps `a`, reduced, in an `Fp` that supports the arithmetic operators.
+    pub fn elem<T: IntoU256>(&self, a: T) -> Fp<'_> {
+        Fp { math: self, value: self.reduce(a) }
+    }
+}
+
+impl<'a> Fp<'a> {
+    /// Returns the reduced value.
+    pub fn value(&self) -> U256 {
+        self.value
+    }
+
+    /// Returns the arithmetic the value lives under.
+    pub fn math(&self) -> &'a ModMath {
+        self.math
+    }
+
+    fn combine(self, other: Self, op: impl FnOnce(&ModMath, U256, U256) -> U256) -> Self {
+        assert!(
+            std::ptr::eq(self.math, other.math) || self.math.modulus == other.math.modulus,
+            "Cannot combine values under different moduli"
+        );
+        Fp { math: self.math, value: op(self.math, self.value, other.value) }
+    }
+}
+
+impl Add for Fp<'_> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.combine(other, ModMath::add)
+    }
+}
+
+impl Sub for Fp<'_> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.combine(other, ModMath::sub)
+    }
+}
+
+impl Mul for Fp<'_> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.combine(other, ModMath::mul)
+    }
+}
+
+impl Div for Fp<'_> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        self.combine(other, ModMath::div)
+    }
+}
+
+impl Neg for Fp<'_> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Fp { math: self.math, value: self.math.add_inv(self.value) }
+    }
+}
+
+impl PartialEq for Fp<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value && self.math.modulus == other.math.modulus
+    }
+}
+
+impl fmt::Debug for Fp<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (mod {})", self.value, self.math.modulus)
+    }
+}
@@ -1,4 +1,34 @@
 
+#[allow(clippy::module_inception)]
 mod mod_math;
+mod field_int;
+mod montgomery;
+mod fp;
+mod convolution;
+mod cache;
+mod series;
+mod domain;
+mod table;
+mod tower;
+mod counters;
 mod mod_math_test;
-pub use mod_math::{ModMath, IntoU256};
\ No newline at end of file
+mod composite_test;
+mod convolution_test;
+mod cache_test;
+mod reference_test;
+mod series_test;
+mod small_field_test;
+mod domain_test;
+mod table_test;
+mod tower_test;
+pub use mod_math::{ModMath, MAX_ROOTS_OF_UNITY, FieldParams, IntoU256, FromU256, TryIntoU256, IntoU256Error, ArithError, InvError, SqrtError, RootConvention};
+pub use field_int::{FieldInt, FieldMath, WideModMath};
+pub use cache::ModMathCache;
+pub use domain::Domain;
+pub use table::MAX_TABLE_MODULUS;
+pub use montgomery::Montgomery;
+pub use fp::Fp;
+#[cfg(test)]
+pub(crate) use mod_math::{BROKEN_REDUCTION, FIELD_PARAMS_BUILT, NON_RESIDUE_SEARCHES};
+#[cfg(feature = "instrumentation")]
+pub use counters::OpCounters;
@@ -1,192 +1,1953 @@
 use primitive_types::{U256, U512};
+use std::collections::HashMap;
+use crate::random::RandomSource;
+use std::fmt;
+use std::sync::{Arc, OnceLock};
+
+use crate::util::{fingerprint, trailing_zeros};
+use super::counters::Counters;
+#[cfg(feature = "instrumentation")]
+use super::counters::OpCounters;
+
+#[cfg(test)]
+thread_local! {
+    // number of additions that fell back to U512, inspected by tests
+    pub(crate) static WIDE_ADDITIONS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+    // number of `FieldParams` built, inspected by tests
+    pub(crate) static FIELD_PARAMS_BUILT: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+    // number of searches for a quadratic non-residue by `tonelli_shanks`, inspected by tests
+    pub(crate) static NON_RESIDUE_SEARCHES: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+    // makes `reduce_wide` off by one, so tests can check that `self_test` catches a broken backend
+    pub(crate) static BROKEN_REDUCTION: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+    // exponent used by the last `exp_blinded` call, inspected by tests
+    pub(crate) static LAST_BLINDED_EXPONENT: std::cell::Cell<U512> = const { std::cell::Cell::new(U512::zero()) };
+}
+
+/// The largest `n` for which `nth_roots_of_unity` lists the roots, 32 MiB of `U256`s.
+pub const MAX_ROOTS_OF_UNITY: u64 = 1 << 20;
 
 /// `ModMath` is a struct that provides modular arithmetic operations.
 ///
 /// It operates on unsigned 256-bit integers (`U256`) and performs operations under a given modulus.
 /// The modulus is provided when creating a new `ModMath` instance and cannot be zero.
+///
+/// The data precomputed for the modulus lives in a shared `FieldParams`, so cloning a
+/// `ModMath` is cheap and clones can be sent to other threads. Operation counters are
+/// not shared: each clone starts from a copy of them.
+#[derive(Clone)]
 pub struct ModMath {
+    // copy of `params.modulus`, read by every operation
+    pub(crate) modulus: U256,
+    params: Arc<FieldParams>,
+    counters: Counters,
+}
+
+/// `FieldParams` holds what `ModMath` precomputes for its modulus. It is immutable once
+/// built, apart from the primality of the modulus, which is computed at most once.
+#[derive(Debug)]
+pub struct FieldParams {
     modulus: U256,
+    pub(crate) reduction: Reduction,
+    // whether the modulus is prime, set by `new_prime` or by the first primality check
+    assume_prime: OnceLock<bool>,
+    // the Tonelli-Shanks setup, computed by the first square root that needs it, or None
+    // if the search for a non-residue gave up on a modulus that is not prime after all
+    tonelli_shanks: OnceLock<Option<TonelliShanksParams>>,
+}
+
+// `p - 1 = s * 2^e` with `s` odd, and `g = q^s` for a quadratic non-residue `q`
+#[derive(Debug)]
+struct TonelliShanksParams {
+    s: U256,
+    e: U256,
+    g: U256,
+    #[cfg(test)]
+    q: U256,
+}
+
+impl FieldParams {
+    fn new(modulus: U256) -> Self {
+        #[cfg(test)]
+        FIELD_PARAMS_BUILT.with(|count| count.set(count.get() + 1));
+
+        FieldParams {
+            modulus,
+            reduction: Reduction::select(modulus),
+            assume_prime: OnceLock::new(),
+            tonelli_shanks: OnceLock::new(),
+        }
+    }
+
+    /// Returns the modulus.
+    pub fn modulus(&self) -> U256 {
+        self.modulus
+    }
+}
+
+impl fmt::Debug for ModMath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ModMath").field("modulus", &self.modulus).finish()
+    }
+}
+
+/// How `ModMath` reduces double-width (`U512`) intermediate results.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Reduction {
+    /// Long division by the modulus.
+    Generic,
+    /// The modulus is `2^k - c` with a small `c` (e.g. the secp256k1 `p` and `n`), so the
+    /// bits above `k` can be folded back in with multiplications by `c`.
+    PseudoMersenne { k: usize, c: U256 },
+    /// Barrett reduction on 64-bit limbs with `mu = floor(2^512 / modulus)`, for an odd
+    /// modulus of more than 192 bits without that form, e.g. the BN128 `p` and `r`.
+    Barrett { mu: [u64; 5] },
+}
+
+impl Reduction {
+    // Picks the special-form reduction when the modulus is `2^k - c` with `c` at most
+    // about half as wide as the modulus, and Barrett reduction for the other odd moduli
+    // of four limbs, such as the BN128 primes.
+    fn select(modulus: U256) -> Self {
+        let k = modulus.bits();
+        if k < 128 {
+            return Reduction::Generic;
+        }
+        let c = (U512::one() << k) - U512::from(modulus);
+        if c.bits() <= k / 2 + 1 {
+            Reduction::PseudoMersenne { k, c: ModMath::u512_to_u256(c) }
+        } else if k > 192 && modulus.bit(0) {
+            // an odd modulus does not divide 2^512, so this is floor(2^512 / modulus),
+            // and it is below 2^320 as the modulus is above 2^192
+            let mu = U512::max_value() / U512::from(modulus);
+            Reduction::Barrett { mu: [mu.0[0], mu.0[1], mu.0[2], mu.0[3], mu.0[4]] }
+        } else {
+            Reduction::Generic
+        }
+    }
+}
+
+// Barrett reduction of `x` (HAC 14.42 with base 2^64 and a modulus of 4 limbs).
+fn barrett_reduce(x: U512, modulus: U256, mu: &[u64; 5]) -> U256 {
+    // q = floor(floor(x / 2^192) * mu / 2^320), which is at most 2 below floor(x / modulus)
+    let mut product = [0u64; 10];
+    for (i, &a) in x.0[3..].iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, &b) in mu.iter().enumerate() {
+            let t = a as u128 * b as u128 + product[i + j] as u128 + carry;
+            product[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        product[i + 5] = carry as u64;
+    }
+    let q = &product[5..];
+
+    // x - q * modulus is below 3 * modulus < 2^320, so the low 5 limbs are enough
+    let mut qm = [0u64; 5];
+    for (i, &a) in q.iter().enumerate() {
+        let mut carry = 0u128;
+        for j in 0..5 - i {
+            let b = if j < 4 { modulus.0[j] } else { 0 };
+            let t = a as u128 * b as u128 + qm[i + j] as u128 + carry;
+            qm[i + j] = t as u64;
+            carry = t >> 64;
+        }
+    }
+    let mut r = [0u64; 8];
+    let mut borrow = false;
+    for i in 0..5 {
+        let (d, b1) = x.0[i].overflowing_sub(qm[i]);
+        let (d, b2) = d.overflowing_sub(borrow as u64);
+        r[i] = d;
+        borrow = b1 || b2;
+    }
+
+    let (mut r, modulus) = (U512(r), U512::from(modulus));
+    while r >= modulus {
+        r -= modulus;
+    }
+    ModMath::u512_to_u256(r)
 }
 
 impl ModMath {
     /// Creates a new `ModMath` instance with the given modulus.
     ///
-    /// # Panics
+    /// Construction is cheap: it picks the reduction for the modulus, a few operations on
+    /// 512-bit integers, and makes one allocation for the shared `FieldParams`. Whether
+    /// the modulus is prime and the square root setup are only computed on first use.
+    /// The `reduction` benchmark builds 10k instances with 64-bit moduli this way, with
+    /// `with_modulus` and with a `ModMathCache`; on a recent x86-64 machine an instance
+    /// takes about 60 ns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is zero.
+    pub fn new<T: IntoU256>(modulus: T) -> Self {
+        let modulus = modulus.into_u256();
+        if modulus == U256::zero() {
+            panic!("Modulus Cannot be Zero");
+        }
+        ModMath {
+            modulus,
+            params: Arc::new(FieldParams::new(modulus)),
+            counters: Counters::default(),
+        }
+    }
+
+    /// Creates a new `ModMath` from a modulus written in hex, as returned by `modulus_hex`.
+    /// The `0x` prefix is optional.
+    ///
+    /// Returns `Error::Parse` if the string is not a hex number below 2^256 and
+    /// `Error::InvalidInput` for a zero modulus.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    ///
+    /// let math = ModMath::from_modulus_hex("0x65").unwrap();
+    /// assert_eq!(math.modulus_hex(), "0x65");
+    /// assert!(ModMath::from_modulus_hex("0x0").is_err());
+    /// assert!(ModMath::from_modulus_hex("101").is_ok()); // 0x101 = 257
+    /// assert!(ModMath::from_modulus_hex("0xg").is_err());
+    /// ```
+    #[deny(clippy::unwrap_used, clippy::expect_used)]
+    pub fn from_modulus_hex(s: &str) -> Result<Self, crate::Error> {
+        let digits = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")).unwrap_or(s);
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(IntoU256Error::new(s).into());
+        }
+        let modulus = U256::from_str_radix(digits, 16).map_err(|_| IntoU256Error::new(s))?;
+        if modulus == U256::zero() {
+            return Err(ArithError::ZeroModulus.into());
+        }
+        Ok(Self::new(modulus))
+    }
+
+    /// Returns the modulus in lowercase hex with a `0x` prefix and no leading zeros, which
+    /// `from_modulus_hex` reads back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    ///
+    /// assert_eq!(ModMath::new(255).modulus_hex(), "0xff");
+    /// ```
+    pub fn modulus_hex(&self) -> String {
+        Self::to_hex_string(self.modulus, false)
+    }
+
+    /// Returns the data precomputed for the modulus, shared by all clones of this `ModMath`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use std::sync::Arc;
+    ///
+    /// let math = ModMath::new(101);
+    /// let clone = math.clone();
+    /// assert!(Arc::ptr_eq(math.params(), clone.params()));
+    /// ```
+    pub fn params(&self) -> &Arc<FieldParams> {
+        &self.params
+    }
+
+    /// Creates a new `ModMath` like `new`, for a modulus the caller knows to be prime.
+    ///
+    /// The operations that need a prime modulus (`sqrt`, `legendre`, `inv_prime`) then
+    /// skip the Miller-Rabin test they would otherwise run on first use.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is zero.
+    pub fn new_prime<T: IntoU256>(modulus: T) -> Self {
+        let math = Self::new(modulus);
+        let _ = math.params.assume_prime.set(true);
+        math
+    }
+
+    /// Returns a `ModMath` for `new_modulus`, computing the same results as
+    /// `ModMath::new(new_modulus)`.
+    ///
+    /// Nothing precomputed for a modulus is valid for another, so for a different modulus
+    /// this builds fresh `FieldParams` and operation counters. For the same modulus it
+    /// returns a clone, which keeps the cached primality and square root setup. To reuse
+    /// instances across many moduli, see `ModMathCache`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new modulus is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    /// use std::sync::Arc;
+    ///
+    /// let math = ModMath::new(101);
+    /// let other = math.with_modulus(103);
+    /// assert_eq!(other.mul(50, 3), ModMath::new(103).mul(50, 3));
+    /// assert!(Arc::ptr_eq(math.with_modulus(101).params(), math.params()));
+    /// ```
+    pub fn with_modulus<T: IntoU256>(&self, new_modulus: T) -> Self {
+        let new_modulus = new_modulus.into_u256();
+        if new_modulus == self.modulus {
+            self.clone()
+        } else {
+            Self::new(new_modulus)
+        }
+    }
+
+    /// Returns the number of field operations performed by this instance so far.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    ///
+    /// let math = ModMath::new(101);
+    /// math.exp(3, 10); // 10 = 0b1010
+    /// assert_eq!(math.counters().squares, 4);
+    /// assert_eq!(math.counters().muls, 2);
+    /// ```
+    #[cfg(feature = "instrumentation")]
+    pub fn counters(&self) -> OpCounters {
+        self.counters.snapshot()
+    }
+
+    /// Sets all operation counters back to zero.
+    #[cfg(feature = "instrumentation")]
+    pub fn reset_counters(&self) {
+        self.counters.reset()
+    }
+
+    /// Returns a fingerprint of the modulus, for binding protocol transcripts to the field.
+    ///
+    /// The fingerprint is a fixed mix of a domain tag and the modulus, stable across
+    /// platforms and versions. It is not a cryptographic hash.
+    pub fn fingerprint(&self) -> [u8; 32] {
+        fingerprint("modular_math/ModMath", &[self.modulus])
+    }
+
+    /// Checks if the modulus is prime, with `is_probable_prime`. The answer is cached.
+    pub fn is_prime_modulus(&self) -> bool {
+        *self.params.assume_prime.get_or_init(|| Self::is_probable_prime(self.modulus))
+    }
+
+    /// Checks if `n` is prime with the Miller-Rabin test over the first 20 primes as bases.
+    ///
+    /// The answer is exact below 3.3 * 10^24; above, a composite passes with
+    /// probability at most `4^-20`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// assert!(ModMath::is_probable_prime(U256::from(101)));
+    /// assert!(!ModMath::is_probable_prime(U256::from(561))); // a Carmichael number
+    /// ```
+    pub fn is_probable_prime(n: U256) -> bool {
+        const BASES: [u64; 20] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71];
+        if n < U256::from(2) {
+            return false;
+        }
+        for &base in BASES.iter() {
+            if n == U256::from(base) {
+                return true;
+            }
+            if n % U256::from(base) == U256::zero() {
+                return false;
+            }
+        }
+
+        let math = Self::new(n);
+        let n_minus_one = n - U256::one();
+        let s = n_minus_one.trailing_zeros();
+        let d = n_minus_one >> s;
+        'witness: for &base in BASES.iter() {
+            let mut x = math.exp(U256::from(base), d);
+            if x == U256::one() || x == n_minus_one {
+                continue;
+            }
+            for _ in 1..s {
+                x = math.square(x);
+                if x == n_minus_one {
+                    continue 'witness;
+                }
+            }
+            return false;
+        }
+        true
+    }
+
+    /// Returns the greatest common divisor of all `values`, or 0 for an empty slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// let values = [U256::from(12), U256::from(18), U256::from(24)];
+    /// assert_eq!(ModMath::gcd_many(&values), U256::from(6));
+    /// ```
+    pub fn gcd_many(values: &[U256]) -> U256 {
+        values.iter().fold(U256::zero(), |acc, &v| Self::gcd(acc, v))
+    }
+
+    /// Returns the least common multiple of all `values`, or 1 for an empty slice.
+    /// The result is 0 if any value is 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the least common multiple does not fit in a `U256`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// let values = [U256::from(4), U256::from(6), U256::from(10)];
+    /// assert_eq!(ModMath::lcm_many(&values), U256::from(60));
+    /// ```
+    pub fn lcm_many(values: &[U256]) -> U256 {
+        values.iter().fold(U256::one(), |acc, &v| {
+            if acc.is_zero() || v.is_zero() {
+                return U256::zero();
+            }
+            (acc / Self::gcd(acc, v)).checked_mul(v).expect("lcm overflows U256")
+        })
+    }
+
+    /// Divides `a` by `b` over the integers, returning the quotient and the remainder.
+    /// Unlike `div`, this does not use the modulus.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `b` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// assert_eq!(ModMath::int_divmod(U256::from(17), U256::from(5)), (U256::from(3), U256::from(2)));
+    /// ```
+    pub fn int_divmod(a: U256, b: U256) -> (U256, U256) {
+        assert!(b != U256::zero(), "Division by zero error");
+        a.div_mod(b)
+    }
+
+    /// Reduces `a` modulo the modulus.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// assert_eq!(ModMath::new(7).reduce(20), U256::from(6));
+    /// ```
+    pub fn reduce<T: IntoU256>(&self, a: T) -> U256 {
+        a.into_u256() % self.modulus
+    }
+
+    /// Reduces `a` modulo the modulus, like `reduce`.
+    #[deprecated(note = "use `reduce`; the name `modulus` reads like a getter")]
+    pub fn modulus<T: IntoU256>(&self, a: T) -> U256 {
+        self.reduce(a)
+    }
+
+    /// Adds two `U256` numbers under the modulus. The operands may be of different types.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an operand cannot be converted, see `IntoU256`. `try_add` returns an
+    /// error instead.
+    pub fn add<A: IntoU256, B: IntoU256>(&self, a: A, b: B) -> U256 {
+        self.counters.addition();
+        let a = a.into_u256() % self.modulus;
+        let b = b.into_u256() % self.modulus;
+        match a.checked_add(b) {
+            Some(sum) => sum % self.modulus,
+            None => {
+                #[cfg(test)]
+                WIDE_ADDITIONS.with(|count| count.set(count.get() + 1));
+
+                self.reduce_wide(U512::from(a) + U512::from(b))
+            }
+        }
+    }
+
+    /// Subtracts the second `U256` number from the first one under the modulus. The
+    /// operands may be of different types.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an operand cannot be converted, see `IntoU256`. `try_sub` returns an
+    /// error instead.
+    pub fn sub<A: IntoU256, B: IntoU256>(&self, a: A, b: B) -> U256 {
+        self.counters.addition();
+        let a = a.into_u256() % self.modulus;
+        let b = b.into_u256() % self.modulus;
+        if b > a {
+            // both operands are reduced so this cannot overflow
+            self.modulus - (b - a)
+        } else {
+            a - b
+        }
+    }
+
+    /// Subtracts like `sub`, but returns the difference of the reduced operands as a
+    /// magnitude and a sign instead of wrapping around the modulus: `(|a - b|, a < b)`.
+    ///
+    /// The magnitude is below the modulus, and `sub` gives `magnitude` for a positive
+    /// result and `modulus - magnitude` for a negative one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// let math = ModMath::new(13);
+    /// assert_eq!(math.sub_signed(3, 10), (U256::from(7), true));
+    /// assert_eq!(math.sub_signed(10, 3), (U256::from(7), false));
+    /// assert_eq!(math.sub(3, 10), U256::from(6));
+    /// ```
+    pub fn sub_signed<A: IntoU256, B: IntoU256>(&self, a: A, b: B) -> (U256, bool) {
+        self.counters.addition();
+        let a = self.reduce(a);
+        let b = self.reduce(b);
+        if a < b {
+            (b - a, true)
+        } else {
+            (a - b, false)
+        }
+    }
+
+    /// Multiplies two `U256` numbers under the modulus. The operands may be of different
+    /// types.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an operand cannot be converted, see `IntoU256`. `try_mul` returns an
+    /// error instead.
+    pub fn mul<A: IntoU256, B: IntoU256>(&self, a: A, b: B) -> U256 {
+        self.counters.mul();
+        let a_mod = a.into_u256() % self.modulus;
+        let b_mod = b.into_u256() % self.modulus;
+
+        if self.modulus.bits() <= 128 {
+            // both operands are below 2^128, so the product fits in a U256
+            (a_mod * b_mod) % self.modulus
+        } else {
+            // for wider moduli the product usually overflows, so widen up front
+            // instead of trying checked_mul first
+            self.reduce_wide(a_mod.full_mul(b_mod))
+        }
+    }
+
+    /// Adds two numbers under the modulus like `add`, returning the sum as any type that
+    /// implements `FromU256`, e.g. a `NumberUnderMod` under the same modulus.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use modular_math::number_mod::NumberUnderMod;
+    /// use primitive_types::U256;
+    ///
+    /// let math = ModMath::new(7);
+    /// assert_eq!(math.add_as::<U256, _, _>(5, 4), U256::from(2));
+    /// assert_eq!(math.add_as::<NumberUnderMod, _, _>(5, 4), NumberUnderMod::new(2, 7));
+    /// ```
+    pub fn add_as<O: FromU256, A: IntoU256, B: IntoU256>(&self, a: A, b: B) -> O {
+        O::from_u256(self.add(a, b), self)
+    }
+
+    /// Multiplies two numbers under the modulus like `mul`, returning the product as any
+    /// type that implements `FromU256`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use modular_math::number_mod::NumberUnderMod;
+    ///
+    /// let math = ModMath::new(7);
+    /// let product: NumberUnderMod = math.mul_as(5, 4);
+    /// assert_eq!(product, NumberUnderMod::new(6, 7));
+    /// ```
+    pub fn mul_as<O: FromU256, A: IntoU256, B: IntoU256>(&self, a: A, b: B) -> O {
+        O::from_u256(self.mul(a, b), self)
+    }
+
+    /// Raises the base to the power of the exponent under the modulus.
+    ///
+    /// Everything is 0 modulo 1, so under modulus 1 the result is 0 even for a zero exponent.
+    pub fn exp<T: IntoU256>(&self, base: T, exponent: T) -> U256 {
+        if self.modulus == U256::one() {
+            return U256::zero();
+        }
+        // canonical base, and exactly one step per bit of the exponent
+        let mut base = self.reduce(base);
+        let exponent = exponent.into_u256();
+        let mut result = U256::one();
+        for i in 0..exponent.bits() {
+            if exponent.bit(i) {
+                result = self.mul(result, base)
+            }
+            base = self.square(base);
+        }
+        result
+    }
+
+    /// Raises every base to the same exponent under the modulus.
+    ///
+    /// The bits of the exponent are extracted once and reused for every base.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// let math = ModMath::new(13);
+    /// let powers = math.exp_shared_exponent(&[U256::from(2), U256::from(3)], U256::from(3));
+    /// assert_eq!(powers, vec![U256::from(8), U256::from(1)]);
+    /// ```
+    pub fn exp_shared_exponent(&self, bases: &[U256], exponent: U256) -> Vec<U256> {
+        let bits = Self::exponent_bits(exponent);
+        bases.iter().map(|&base| self.exp_with_bits(base, &bits)).collect()
+    }
+
+    /// Raises every base to the same exponent like `exp_shared_exponent`, spreading the
+    /// bases over the rayon thread pool.
+    #[cfg(feature = "rayon")]
+    pub fn par_exp_shared_exponent(&self, bases: &[U256], exponent: U256) -> Vec<U256> {
+        use rayon::prelude::*;
+
+        let bits = Self::exponent_bits(exponent);
+        bases.par_iter().map(|&base| self.exp_with_bits(base, &bits)).collect()
+    }
+
+    /// Raises every base to the same exponent under the modulus, with a fixed window of
+    /// 4 bits.
+    ///
+    /// The exponent is split into its window digits once, and each base then takes one
+    /// squaring per bit but only one multiplication per non-zero digit, after building a
+    /// table of its first 16 powers. This beats `exp_shared_exponent` for long exponents.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// let math = ModMath::new(13);
+    /// let powers = math.exp_many(&[U256::from(2), U256::from(3)], U256::from(3));
+    /// assert_eq!(powers, vec![U256::from(8), U256::from(1)]);
+    /// ```
+    pub fn exp_many(&self, bases: &[U256], exponent: U256) -> Vec<U256> {
+        let digits = Self::exponent_window_digits(exponent);
+        bases.iter().map(|&base| self.exp_with_window_digits(base, &digits)).collect()
+    }
+
+    /// Raises every base to the same exponent like `exp_many`, spreading the bases over
+    /// the rayon thread pool.
+    #[cfg(feature = "rayon")]
+    pub fn par_exp_many(&self, bases: &[U256], exponent: U256) -> Vec<U256> {
+        use rayon::prelude::*;
+
+        let digits = Self::exponent_window_digits(exponent);
+        bases.par_iter().map(|&base| self.exp_with_window_digits(base, &digits)).collect()
+    }
+
+    // The 4-bit digits of the exponent, most significant first
+    fn exponent_window_digits(exponent: U256) -> Vec<u8> {
+        let windows = exponent.bits().div_ceil(4);
+        (0..windows).rev().map(|i| ((exponent >> (4 * i)).low_u32() & 0xf) as u8).collect()
+    }
+
+    // Left-to-right fixed-window exponentiation over precomputed digits, as in `exp_many`
+    fn exp_with_window_digits(&self, base: U256, digits: &[u8]) -> U256 {
+        if self.modulus == U256::one() {
+            return U256::zero();
+        }
+        let mut table = [U256::one(); 16];
+        table[1] = self.reduce(base);
+        for i in 2..16 {
+            table[i] = self.mul(table[i - 1], table[1]);
+        }
+        let mut result = U256::one();
+        for &digit in digits {
+            for _ in 0..4 {
+                result = self.square(result);
+            }
+            if digit != 0 {
+                result = self.mul(result, table[digit as usize]);
+            }
+        }
+        result
+    }
+
+    fn exponent_bits(exponent: U256) -> Vec<bool> {
+        (0..exponent.bits()).map(|i| exponent.bit(i)).collect()
+    }
+
+    // Right-to-left square-and-multiply over precomputed exponent bits, as in `exp`
+    fn exp_with_bits(&self, base: U256, bits: &[bool]) -> U256 {
+        if self.modulus == U256::one() {
+            return U256::zero();
+        }
+        let mut result = U256::one();
+        let mut base = self.reduce(base);
+        for &bit in bits {
+            if bit {
+                result = self.mul(result, base);
+            }
+            base = self.square(base);
+        }
+        result
+    }
+
+    /// Computes the product of `base^exponent` over all pairs under the modulus.
+    ///
+    /// The exponentiations are interleaved left-to-right over the longest exponent, so all
+    /// bases share one squaring per bit instead of one each.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// let math = ModMath::new(13);
+    /// // 2^3 * 3^2 = 72 = 7 (mod 13)
+    /// let pairs = [(U256::from(2), U256::from(3)), (U256::from(3), U256::from(2))];
+    /// assert_eq!(math.pow_product(&pairs), U256::from(7));
+    /// ```
+    pub fn pow_product(&self, pairs: &[(U256, U256)]) -> U256 {
+        let bits = pairs.iter().map(|(_, exponent)| exponent.bits()).max().unwrap_or(0);
+        let bases: Vec<U256> = pairs.iter().map(|&(base, _)| self.reduce(base)).collect();
+        // the empty product is 1, or 0 under modulus 1
+        let mut result = self.reduce(U256::one());
+        for i in (0..bits).rev() {
+            result = self.square(result);
+            for (base, (_, exponent)) in bases.iter().zip(pairs) {
+                if exponent.bit(i) {
+                    result = self.mul(result, *base);
+                }
+            }
+        }
+        result
+    }
+
+    /// Raises the base to a signed exponent under the modulus, where a negative exponent
+    /// raises the inverse of the base. The exponent is given as a sign and a magnitude.
+    ///
+    /// Returns `None` if the exponent is negative and the base has no inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// let math = ModMath::new(13);
+    /// // 2^-1 = 7 (mod 13)
+    /// assert_eq!(math.exp_signed(U256::from(2), true, U256::from(2)), Some(U256::from(10)));
+    /// assert_eq!(math.exp_signed(U256::from(2), false, U256::from(2)), Some(U256::from(4)));
+    /// assert_eq!(math.exp_signed(U256::zero(), true, U256::one()), None);
+    /// ```
+    pub fn exp_signed(&self, base: U256, exponent_negative: bool, exponent: U256) -> Option<U256> {
+        // a negative zero is zero, which needs no inverse
+        if exponent_negative && !exponent.is_zero() {
+            Some(self.exp(self.inv(base)?, exponent))
+        } else {
+            Some(self.exp(base, exponent))
+        }
+    }
+
+    /// Raises the base to the power of an exponent of any length, given as big-endian bytes.
+    ///
+    /// Leading zero bits are skipped, and an empty or all-zero exponent gives 1 (0 under
+    /// modulus 1).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// let math = ModMath::new(U256::from(13));
+    /// assert_eq!(math.exp_bytes(U256::from(2), &[0x01, 0x00]), math.exp(U256::from(2), U256::from(256)));
+    /// ```
+    pub fn exp_bytes<T: IntoU256>(&self, base: T, exponent_be: &[u8]) -> U256 {
+        if self.modulus == U256::one() {
+            return U256::zero();
+        }
+        let base = self.reduce(base);
+        let Some(start) = exponent_be.iter().position(|&byte| byte != 0) else {
+            return U256::one();
+        };
+        // one step per bit, starting from the most significant set bit
+        let mut result = U256::one();
+        let mut top_bit = 7 - exponent_be[start].leading_zeros();
+        for &byte in &exponent_be[start..] {
+            for i in (0..=top_bit).rev() {
+                result = self.square(result);
+                if (byte >> i) & 1 == 1 {
+                    result = self.mul(result, base);
+                }
+            }
+            top_bit = 7;
+        }
+        result
+    }
+
+    /// Raises the base to the power of a `U512` exponent under the modulus.
+    pub fn exp_u512<T: IntoU256>(&self, base: T, exponent: U512) -> U256 {
+        let mut exponent_be = [0u8; 64];
+        exponent.to_big_endian(&mut exponent_be);
+        self.exp_bytes(base, &exponent_be)
+    }
+
+    /// Raises the base to the power of a big-endian exponent of any length, like
+    /// `exp_bytes`, first reducing the exponent modulo `p - 1` when the modulus `p` is
+    /// prime and the base is coprime to it. By Fermat's little theorem the result is the
+    /// same, and at most 256 squarings are needed however long the exponent is.
+    ///
+    /// For a composite modulus, or a base divisible by the modulus, this is `exp_bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// let math = ModMath::new(101);
+    /// let exponent = [0xff; 100]; // 2^800 - 1
+    /// assert_eq!(math.exp_fermat_reduced(3, &exponent), math.exp_bytes(3, &exponent));
+    /// assert_eq!(math.exp_fermat_reduced(202, &exponent), U256::zero());
+    /// ```
+    pub fn exp_fermat_reduced<T: IntoU256>(&self, base: T, exponent_be: &[u8]) -> U256 {
+        let base = base.into_u256() % self.modulus;
+        if base.is_zero() || !self.is_prime_modulus() {
+            return self.exp_bytes(base, exponent_be);
+        }
+        // Horner's rule modulo p - 1, one byte at a time
+        let group_order = Self::new(self.modulus - U256::one());
+        let radix = U256::from(256);
+        let exponent = exponent_be
+            .iter()
+            .fold(U256::zero(), |acc, &byte| group_order.add(group_order.mul(acc, radix), byte as u32));
+        self.exp(base, exponent)
+    }
+
+    /// Raises the base to a secret exponent with exponent blinding: computes
+    /// `base^(e + k * group_order)` for a random 64-bit `k`, so the bit pattern of the
+    /// exponent used changes on every call.
+    ///
+    /// The result equals `exp(base, secret_exponent)` when `base^group_order` is 1, e.g.
+    /// for any base coprime to a prime modulus `p` with `group_order = p - 1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// let math = ModMath::new(101);
+    /// let blinded = math.exp_blinded(3, U256::from(45), U256::from(100), &mut rand::thread_rng());
+    /// assert_eq!(blinded, math.exp(3, 45));
+    /// ```
+    pub fn exp_blinded<T: IntoU256, R: RandomSource + ?Sized>(
+        &self,
+        base: T,
+        secret_exponent: U256,
+        group_order: U256,
+        rng: &mut R,
+    ) -> U256 {
+        let mut k = [0u8; 8];
+        rng.fill_bytes(&mut k);
+        let k = U256::from(u64::from_le_bytes(k));
+        // at most 2^256 + 2^320, well within a U512
+        let exponent = U512::from(secret_exponent) + group_order.full_mul(k);
+        #[cfg(test)]
+        LAST_BLINDED_EXPONENT.with(|last| last.set(exponent));
+        self.exp_u512(base, exponent)
+    }
+
+    /// Raises the base to the power of the exponent like `exp`, with a Montgomery ladder
+    /// that does the same multiplication and squaring for each of the 256 exponent bits,
+    /// whatever their value, and swaps its registers without branching on them.
+    ///
+    /// The `U256` arithmetic underneath is not constant time itself, so this removes the
+    /// timing differences of the exponent bits but is not a guarantee.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// let math = ModMath::new(101);
+    /// assert_eq!(math.exp_ct(U256::from(3), U256::from(45)), math.exp(3, 45));
+    /// ```
+    pub fn exp_ct(&self, base: U256, exponent: U256) -> U256 {
+        // r0 = base^k and r1 = base^(k + 1) for the bits k seen so far
+        let mut r0 = self.reduce(U256::one());
+        let mut r1 = self.reduce(base);
+        for i in (0..256).rev() {
+            let mask = U256::zero().overflowing_sub(U256::from(exponent.bit(i) as u8)).0;
+            Self::conditional_swap(&mut r0, &mut r1, mask);
+            r1 = self.mul(r0, r1);
+            r0 = self.square(r0);
+            Self::conditional_swap(&mut r0, &mut r1, mask);
+        }
+        r0
+    }
+
+    // Swaps `a` and `b` when `mask` is all ones and leaves them when it is zero
+    fn conditional_swap(a: &mut U256, b: &mut U256, mask: U256) {
+        let t = (*a ^ *b) & mask;
+        *a ^= t;
+        *b ^= t;
+    }
+
+    /// Calculates the modular multiplicative inverse of a `U256` number under the modulus.
+    ///
+    /// Returns `None` if the inverse does not exist.
+    pub fn inv<T: IntoU256>(&self, a: T) -> Option<U256> {
+        self.counters.inversion();
+        let (mut m, mut x0, mut x1) = (self.modulus, U256::zero(), U256::one());
+        let mut a = a.into_u256() % self.modulus;
+        // zero is never invertible, and the loop below must not be relied on to say so
+        if a == U256::zero() || self.modulus == U256::one() {
+            return None;
+        }
+    
+        // m reaches zero before a reaches one when a is not coprime to the modulus
+        while a > U256::one() && m != U256::zero() {
+            let q = a / m;
+            let mut temp = m;
+    
+            m = a % m;
+            a = temp;
+            temp = x0;
+            let t = self.mul(q, x0);
+            x0 = self.sub(x1, t);
+            x1 = temp;
+        }
+    
+        if x1 < U256::zero() {
+            x1 = self.add(x1, self.modulus);
+        }
+    
+        if a != U256::one() {
+            None
+        } else {
+            Some(x1)
+        }
+    }
+
+    /// Finds the modular inverse like `inv`, but tells why there is none:
+    /// `InvError::Zero` if `a` is zero under the modulus and `InvError::NotCoprime(gcd)`
+    /// if `a` shares the factor `gcd` with the modulus.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::{InvError, ModMath};
+    /// use primitive_types::U256;
+    ///
+    /// let math = ModMath::new(15);
+    /// assert_eq!(math.inv_detailed(2), Ok(U256::from(8)));
+    /// assert_eq!(math.inv_detailed(30), Err(InvError::Zero));
+    /// assert_eq!(math.inv_detailed(6), Err(InvError::NotCoprime(U256::from(3))));
+    /// ```
+    pub fn inv_detailed<T: IntoU256>(&self, a: T) -> Result<U256, InvError> {
+        let a = a.into_u256() % self.modulus;
+        if a == U256::zero() {
+            return Err(InvError::Zero);
+        }
+        let gcd = Self::gcd(self.modulus, a);
+        if gcd != U256::one() {
+            return Err(InvError::NotCoprime(gcd));
+        }
+        self.inv(a).ok_or(InvError::NotCoprime(gcd))
+    }
+
+    /// Checks if `a` has an inverse under the modulus, that is if it is coprime to the
+    /// modulus and not zero. Agrees with `inv(a).is_some()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    ///
+    /// let math = ModMath::new(100);
+    /// assert!(math.is_invertible(3));
+    /// assert!(!math.is_invertible(5));
+    /// ```
+    pub fn is_invertible<T: IntoU256>(&self, a: T) -> bool {
+        let a = a.into_u256() % self.modulus;
+        a != U256::zero() && Self::gcd(self.modulus, a) == U256::one()
+    }
+
+    /// Divides the first `U256` number by the second one under the modulus.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the second number is not invertible under the modulus, with the gcd of the
+    /// two in the message, e.g. `5 is not invertible mod 100: gcd = 5`.
+    pub fn div<T: IntoU256>(&self, a: T, b: T) -> U256 {
+        let b = b.into_u256();
+        self.checked_div(a.into_u256(), b).unwrap_or_else(|_| {
+            let gcd = Self::gcd(self.modulus, b % self.modulus);
+            panic!("{} is not invertible mod {}: gcd = {}", b, self.modulus, gcd);
+        })
+    }
+
+    /// Divides like `div`, but returns the reason from `inv_detailed` instead of panicking
+    /// when the divisor is not invertible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::{InvError, ModMath};
+    /// use primitive_types::U256;
+    ///
+    /// let math = ModMath::new(100);
+    /// assert_eq!(math.checked_div(6, 3), Ok(U256::from(2)));
+    /// assert_eq!(math.checked_div(6, 5), Err(InvError::NotCoprime(U256::from(5))));
+    /// ```
+    pub fn checked_div<T: IntoU256>(&self, a: T, b: T) -> Result<U256, InvError> {
+        let b_inv = self.inv_detailed(b)?;
+        Ok(self.mul(a.into_u256(), b_inv))
+    }
+
+    /// Divides `a` by 2 under an odd modulus, without an inversion: `a / 2` for even `a`
+    /// and `(a + p) / 2` for odd `a`.
+    ///
+    /// Returns `ArithError::EvenModulus` under an even modulus, where 2 has no inverse.
+    /// In particular halving is undefined in GF(2), where 2 is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::{ArithError, ModMath};
+    /// use primitive_types::U256;
+    ///
+    /// assert_eq!(ModMath::new(13).halve(5), Ok(U256::from(9)));
+    /// assert_eq!(ModMath::new(2).halve(1), Err(ArithError::EvenModulus));
+    /// ```
+    pub fn halve<T: IntoU256>(&self, a: T) -> Result<U256, ArithError> {
+        if !self.modulus.bit(0) {
+            return Err(ArithError::EvenModulus);
+        }
+        let a = self.reduce(a);
+        if a.bit(0) {
+            // (a + p) / 2 without overflowing, as both are odd
+            Ok((a >> 1) + (self.modulus >> 1) + U256::one())
+        } else {
+            Ok(a >> 1)
+        }
+    }
+
+    /// Inverts every value under the modulus with a single modular inversion
+    /// (Montgomery's trick), at the cost of three multiplications per value.
+    ///
+    /// Returns `None` if any of the values has no inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// let math = ModMath::new(13);
+    /// let inverses = math.batch_inv(&[U256::from(2), U256::from(5)]).unwrap();
+    /// assert_eq!(inverses, vec![U256::from(7), U256::from(8)]);
+    /// assert!(math.batch_inv(&[U256::from(2), U256::zero()]).is_none());
+    /// ```
+    pub fn batch_inv(&self, values: &[U256]) -> Option<Vec<U256>> {
+        let mut prefix_products = Vec::with_capacity(values.len());
+        let mut product = U256::one();
+        for &value in values {
+            product = self.mul(product, value);
+            prefix_products.push(product);
+        }
+
+        // a value without an inverse makes the whole product non-invertible
+        let mut inverse = self.inv(product)?;
+        let mut inverses = vec![U256::zero(); values.len()];
+        for i in (0..values.len()).rev() {
+            inverses[i] = if i == 0 { inverse } else { self.mul(inverse, prefix_products[i - 1]) };
+            inverse = self.mul(inverse, values[i]);
+        }
+        Some(inverses)
+    }
+
+    /// Adds the slices element by element under the modulus, writing the sums to `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the three slices do not have the same length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// let math = ModMath::new(13);
+    /// let mut out = [U256::zero(); 2];
+    /// math.add_slices(&[U256::from(5), U256::from(9)], &[U256::from(10), U256::from(4)], &mut out);
+    /// assert_eq!(out, [U256::from(2), U256::zero()]);
+    /// ```
+    pub fn add_slices(&self, a: &[U256], b: &[U256], out: &mut [U256]) {
+        Self::assert_same_lengths(a, b, out);
+        for ((o, &x), &y) in out.iter_mut().zip(a).zip(b) {
+            *o = self.add(x, y);
+        }
+    }
+
+    /// Subtracts the slices element by element under the modulus like `add_slices`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the three slices do not have the same length.
+    pub fn sub_slices(&self, a: &[U256], b: &[U256], out: &mut [U256]) {
+        Self::assert_same_lengths(a, b, out);
+        for ((o, &x), &y) in out.iter_mut().zip(a).zip(b) {
+            *o = self.sub(x, y);
+        }
+    }
+
+    /// Multiplies the slices element by element under the modulus like `add_slices`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the three slices do not have the same length.
+    pub fn mul_slices(&self, a: &[U256], b: &[U256], out: &mut [U256]) {
+        Self::assert_same_lengths(a, b, out);
+        for ((o, &x), &y) in out.iter_mut().zip(a).zip(b) {
+            *o = self.mul(x, y);
+        }
+    }
+
+    fn assert_same_lengths(a: &[U256], b: &[U256], out: &[U256]) {
+        assert!(
+            a.len() == b.len() && a.len() == out.len(),
+            "Cannot combine slices of different lengths: {}, {} and {}", a.len(), b.len(), out.len()
+        );
+    }
+
+    /// Calculates the additive inverse of a given `U256` under modulus
+    pub fn add_inv<T: IntoU256>(&self, a: T) -> U256 {
+      let a = a.into_u256() % self.modulus;
+      if a == U256::zero() {
+        U256::zero()
+      } else {
+        self.modulus - a
+      }
+    }
+    
+    /// Checks if two `U256` numbers are equivalent under the modulus. The operands may be
+    /// of different types.
+    ///
+    /// This is congruence, not equality of representatives: values that differ by a
+    /// multiple of the modulus are equal. Compare reduced values with `==` when the
+    /// representative matters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    ///
+    /// let math = ModMath::new(13);
+    /// assert!(math.eq(3, 16));
+    /// assert!(!math.eq(3, 4));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if an operand cannot be converted, see `IntoU256`. `try_eq` returns an
+    /// error instead.
+    pub fn eq<A: IntoU256, B: IntoU256>(&self, a: A, b: B) -> bool {
+        a.into_u256() % self.modulus == b.into_u256() % self.modulus
+    }
+
+    /// Adds like `add`, but returns an error for an operand that is not a number, e.g. a
+    /// malformed string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// let math = ModMath::new(13);
+    /// assert_eq!(math.try_add("12", 3u64), Ok(U256::from(2)));
+    /// assert_eq!(math.try_add("1two", 3u64).unwrap_err().input(), "1two");
+    /// ```
+    pub fn try_add<A: TryIntoU256, B: TryIntoU256>(&self, a: A, b: B) -> Result<U256, IntoU256Error> {
+        Ok(self.add(a.try_into_u256()?, b.try_into_u256()?))
+    }
+
+    /// Subtracts like `sub`, but returns an error for an operand that is not a number.
+    pub fn try_sub<A: TryIntoU256, B: TryIntoU256>(&self, a: A, b: B) -> Result<U256, IntoU256Error> {
+        Ok(self.sub(a.try_into_u256()?, b.try_into_u256()?))
+    }
+
+    /// Multiplies like `mul`, but returns an error for an operand that is not a number.
+    pub fn try_mul<A: TryIntoU256, B: TryIntoU256>(&self, a: A, b: B) -> Result<U256, IntoU256Error> {
+        Ok(self.mul(a.try_into_u256()?, b.try_into_u256()?))
+    }
+
+    /// Compares like `eq`, but returns an error for an operand that is not a number.
+    pub fn try_eq<A: TryIntoU256, B: TryIntoU256>(&self, a: A, b: B) -> Result<bool, IntoU256Error> {
+        Ok(self.eq(a.try_into_u256()?, b.try_into_u256()?))
+    }
+
+    /// Squares a given U256 number under modulus
+    ///
+    /// Gives the same result as `mul(a, a)` but reduces the operand only once.
+    pub fn square<T: IntoU256>(&self, a: T) -> U256 {
+        self.counters.square();
+        let a = a.into_u256() % self.modulus;
+        if a.bits() <= 128 {
+            // the square fits in a U256
+            (a * a) % self.modulus
+        } else {
+            self.reduce_wide(a.full_mul(a))
+        }
+    }
+
+    /// Cubes a given U256 number under modulus
+    ///
+    /// Gives the same result as `mul(square(a), a)` but reduces the operand only once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// assert_eq!(ModMath::new(11).cube(15), U256::from(9)); // 4^3 = 64
+    /// ```
+    pub fn cube<T: IntoU256>(&self, a: T) -> U256 {
+        let a = self.reduce(a);
+        self.mul(self.square(a), a)
+    }
+
+    /// Returns the cube root of `a` under a prime modulus `p` where cubing is a bijection,
+    /// i.e. `gcd(3, p - 1) = 1`, so the root is unique: `a^d` with `3d = 1 (mod p - 1)`.
+    ///
+    /// Returns `None` if the modulus is not prime or `p = 1 (mod 3)`, where an element has
+    /// either no cube root or three of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// let math = ModMath::new(11);
+    /// assert_eq!(math.cube_root(9), Some(U256::from(4)));
+    /// assert_eq!(ModMath::new(13).cube_root(8), None);
+    /// ```
+    pub fn cube_root<T: IntoU256>(&self, a: T) -> Option<U256> {
+        if !self.is_prime_modulus() {
+            return None;
+        }
+        let exponent = match (self.modulus % 3).low_u32() {
+            // p = 3k + 2 and d = 2k + 1
+            2 => (self.modulus - 2) / 3 * 2 + 1,
+            // p = 3, where cubing is the identity
+            0 => U256::one(),
+            _ => return None,
+        };
+        Some(self.exp(self.reduce(a), exponent))
+    }
+
+    /// Factors `n` into primes by trial division, returning a map of prime to exponent.
+    ///
+    /// Only practical when the second largest prime factor of `n` is small. The map is
+    /// empty for 1, and for 0, which has no factorization.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// let factors = ModMath::prime_factors(U256::from(360));
+    /// assert_eq!(factors[&U256::from(2)], U256::from(3));
+    /// assert!(ModMath::prime_factors(U256::zero()).is_empty());
+    /// ```
+    pub fn prime_factors(mut n: U256) -> HashMap<U256, U256> {
+        let mut factors = HashMap::new();
+        if n.is_zero() {
+            return factors;
+        }
+        let mut count: U256;
+
+        count = U256::zero();
+        while n % U256::from(2) == U256::zero() {
+            count += U256::one();
+            n /= U256::from(2);
+        }
+        if count > U256::zero() {
+            factors.insert(U256::from(2), count);
+        }
+
+        let mut i = U256::from(3);
+        while i * i <= n {
+            count = U256::zero();
+            while n % i == U256::zero() {
+                count += U256::one();
+                n /= i;
+            }
+            if count > U256::zero() {
+                factors.insert(i, count);
+            }
+            i += U256::from(2);
+        }
+
+        if n > U256::from(2) {
+            factors.insert(n, U256::from(1));
+        }
+    
+        factors
+    }
+
+    /// Counts the divisors of `n` given its prime factorization, as returned by `prime_factors`.
+    ///
+    /// Returns `ArithError::Overflow` if the count does not fit in a `U256`.
+    pub fn num_divisors(factors: &HashMap<U256, U256>) -> Result<U256, ArithError> {
+        let mut count = U256::one();
+        for &e in factors.values() {
+            let term = e.checked_add(U256::one()).ok_or(ArithError::Overflow)?;
+            count = count.checked_mul(term).ok_or(ArithError::Overflow)?;
+        }
+        Ok(count)
+    }
+
+    /// Sums the divisors of `n` given its prime factorization, as returned by `prime_factors`.
+    ///
+    /// Returns `ArithError::Overflow` if the sum does not fit in a `U256`.
+    pub fn sum_divisors(factors: &HashMap<U256, U256>) -> Result<U256, ArithError> {
+        let max = U512::from(U256::max_value());
+        let mut sum = U512::one();
+        for (&p, &e) in factors {
+            // 1 + p + p^2 + ... + p^e
+            let p = U512::from(p);
+            let mut term = U512::one();
+            let mut power = U512::one();
+            let mut i = U256::zero();
+            while i < e {
+                power = power.checked_mul(p).filter(|x| *x <= max).ok_or(ArithError::Overflow)?;
+                term += power;
+                i += U256::one();
+            }
+            sum = sum.checked_mul(term).filter(|x| *x <= max).ok_or(ArithError::Overflow)?;
+        }
+        Ok(ModMath::u512_to_u256(sum))
+    }
+
+    /// Computes the Mobius function of `n` given its prime factorization, as returned by `prime_factors`.
+    ///
+    /// Returns 0 if `n` has a squared prime factor, otherwise `(-1)^k` for `k` distinct prime factors.
+    pub fn mobius(factors: &HashMap<U256, U256>) -> i8 {
+        if factors.values().any(|&e| e > U256::one()) {
+            return 0;
+        }
+        if factors.values().filter(|&&e| e == U256::one()).count() % 2 == 0 {
+            1
+        } else {
+            -1
+        }
+    }
+
+    /// Computes the Carmichael function `lambda(n)`, the exponent of the multiplicative
+    /// group modulo `n`, given the prime factorization of `n` as returned by `prime_factors`.
+    ///
+    /// `lambda` is the lcm of `lambda(p^k)` over the prime powers, which is
+    /// `p^(k-1) (p - 1)` for an odd `p`, and 1, 2 and `2^(k-2)` for `2`, `4` and higher
+    /// powers of two. An empty factorization, for `n = 1`, gives 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// // 1000 = 2^3 * 5^3: lcm(2, 100)
+    /// assert_eq!(ModMath::carmichael(&ModMath::prime_factors(U256::from(1000))), U256::from(100));
+    /// ```
+    pub fn carmichael(factors: &HashMap<U256, U256>) -> U256 {
+        let two = U256::from(2);
+        let lambdas: Vec<U256> = factors
+            .iter()
+            .map(|(&p, &k)| {
+                if p == two && k >= U256::from(3) {
+                    two.pow(k - 2)
+                } else {
+                    p.pow(k - 1) * (p - 1)
+                }
+            })
+            .collect();
+        Self::lcm_many(&lambdas)
+    }
+
+    /// Checks if `w` is a primitive `n`-th root of unity under the modulus,
+    /// i.e. `w^n = 1` and `w^(n/q) != 1` for every prime `q` dividing `n`.
+    ///
+    /// The prime factors of `n` are found by trial division, use
+    /// `is_nth_root_of_unity_with_factors` when `n` has large prime factors.
+    pub fn is_nth_root_of_unity<T: IntoU256>(&self, w: T, n: T) -> bool {
+        let n = n.into_u256();
+        if n == U256::zero() {
+            return false;
+        }
+        let factors: Vec<U256> = Self::prime_factors(n).into_keys().collect();
+        self.is_nth_root_of_unity_with_factors(w.into_u256(), n, &factors)
+    }
+
+    /// Checks if `w` is a primitive `n`-th root of unity given the distinct prime factors of `n`.
+    pub fn is_nth_root_of_unity_with_factors(&self, w: U256, n: U256, prime_factors: &[U256]) -> bool {
+        if n == U256::zero() || self.exp(w, n) != U256::one() {
+            return false;
+        }
+        prime_factors.iter().all(|&q| self.exp(w, n / q) != U256::one())
+    }
+
+    /// Enumerates all `n`-th roots of unity `[1, w, w^2, ..., w^(n-1)]` for a primitive root `w`.
+    ///
+    /// Returns `None` if the modulus is not prime, or if `n` is zero, does not divide `p - 1`,
+    /// or is above `MAX_ROOTS_OF_UNITY`, which keeps the list from taking gigabytes.
+    /// Under a composite modulus the search for `w` could step through every residue.
+    pub fn nth_roots_of_unity<T: IntoU256>(&self, n: T) -> Option<Vec<U256>> {
+        let n = n.into_u256();
+        let group_order = self.modulus - U256::one();
+        if n == U256::zero() || n > U256::from(MAX_ROOTS_OF_UNITY) || group_order % n != U256::zero() {
+            return None;
+        }
+        if !self.is_prime_modulus() {
+            return None;
+        }
+
+        let factors: Vec<U256> = Self::prime_factors(n).into_keys().collect();
+        let cofactor = group_order / n;
+        let mut candidate = U256::one();
+        let w = loop {
+            if candidate >= self.modulus {
+                return None;
+            }
+            let w = self.exp(candidate, cofactor);
+            if self.is_nth_root_of_unity_with_factors(w, n, &factors) {
+                break w;
+            }
+            candidate += U256::one();
+        };
+
+        let mut roots = Vec::with_capacity(n.as_usize());
+        let mut current = U256::one();
+        while U256::from(roots.len()) < n {
+            roots.push(current);
+            current = self.mul(current, w);
+        }
+        Some(roots)
+    }
+
+    /// Checks if `g` generates the whole multiplicative group under a prime modulus `p`,
+    /// i.e. `g^((p-1)/q) != 1` for every prime `q` dividing `p - 1`.
+    ///
+    /// The prime factors of `p - 1` are found by trial division.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    ///
+    /// let math = ModMath::new(11);
+    /// assert!(math.is_primitive_root(2));
+    /// assert!(!math.is_primitive_root(3));
+    /// ```
+    pub fn is_primitive_root<T: IntoU256>(&self, g: T) -> bool {
+        self.is_nth_root_of_unity(g.into_u256(), self.modulus - U256::one())
+    }
+
+    /// Reduces a `U512` value under the modulus, using the special-form or Barrett
+    /// reduction selected in `new` when the modulus allows it.
+    pub fn reduce_wide(&self, x: U512) -> U256 {
+        #[cfg(test)]
+        if BROKEN_REDUCTION.with(|broken| broken.get()) {
+            return self.reduce_wide_generic(x.saturating_add(U512::one()));
+        }
+        match self.params.reduction {
+            Reduction::Generic => self.reduce_wide_generic(x),
+            Reduction::PseudoMersenne { k, c } => {
+                // x = hi * 2^k + lo = hi * c + lo (mod 2^k - c)
+                let mut x = x;
+                if k == 256 {
+                    // split on limbs and use the narrower 256 x 256 multiplication
+                    while x.0[4] | x.0[5] | x.0[6] | x.0[7] != 0 {
+                        let hi = U256([x.0[4], x.0[5], x.0[6], x.0[7]]);
+                        let lo = U256([x.0[0], x.0[1], x.0[2], x.0[3]]);
+                        x = hi.full_mul(c) + U512::from(lo);
+                    }
+                } else {
+                    let mask = (U512::one() << k) - U512::one();
+                    while !(x >> k).is_zero() {
+                        // hi < 2^(512 - k) and c < 2^(k/2 + 1), so the product fits for k >= 128
+                        x = (x >> k) * U512::from(c) + (x & mask);
+                    }
+                }
+                // x < 2^k = modulus + c, so one subtraction is enough
+                let modulus = U512::from(self.modulus);
+                if x >= modulus {
+                    x -= modulus;
+                }
+                ModMath::u512_to_u256(x)
+            }
+            Reduction::Barrett { ref mu } => barrett_reduce(x, self.modulus, mu),
+        }
+    }
+
+    pub(crate) fn reduce_wide_generic(&self, x: U512) -> U256 {
+        ModMath::u512_to_u256(x % U512::from(self.modulus))
+    }
+
+    /// Formats a `U256` in decimal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// assert_eq!(ModMath::to_dec_string(U256::from(255)), "255");
+    /// ```
+    pub fn to_dec_string(a: U256) -> String {
+        a.to_string()
+    }
+
+    /// Formats a `U256` in lowercase hex with a `0x` prefix, zero-padded to 64 digits
+    /// if `fixed_width` is set.
+    ///
+    /// The result parses back through `IntoU256` for `&str`.
+    ///
+    /// # Examples
     ///
-    /// Panics if the modulus is zero.
-    pub fn new<T: IntoU256>(modulus: T) -> Self {
-        let modulus = modulus.into_u256();
-        if modulus == U256::zero() {
-            panic!("Modulus Cannot be Zero");
+    /// ```
+    /// use modular_math::mod_math::{IntoU256, ModMath};
+    /// use primitive_types::U256;
+    ///
+    /// assert_eq!(ModMath::to_hex_string(U256::from(255), false), "0xff");
+    /// assert_eq!(ModMath::to_hex_string(U256::from(255), true).len(), 66);
+    /// assert_eq!(ModMath::to_hex_string(U256::from(255), true).as_str().into_u256(), U256::from(255));
+    /// ```
+    pub fn to_hex_string(a: U256, fixed_width: bool) -> String {
+        if fixed_width {
+            format!("0x{:0>64}", format!("{:x}", a))
+        } else {
+            format!("0x{:x}", a)
         }
-        ModMath {
-            modulus
+    }
+
+    fn u512_to_u256(result: U512) -> U256 {
+        let mut result_little_endian = [0_u8; 64];
+        result.to_little_endian(&mut result_little_endian);
+        U256::from_little_endian(&result_little_endian[..32])
+    }
+
+    /// Computes `a^((p - 1) / 2)` under the odd prime modulus `p`, the value behind Euler's
+    /// criterion: 1 if `a` is a non-zero square, `p - 1` if it is not a square and 0 if
+    /// `a` is zero.
+    ///
+    /// Under modulus 2 the exponent is zero, so this gives 0 for zero and 1 for one, which
+    /// are both squares.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// let math = ModMath::new(7);
+    /// assert_eq!(math.euler_criterion(2), U256::one());
+    /// assert_eq!(math.euler_criterion(3), U256::from(6));
+    /// assert_eq!(math.euler_criterion(7), U256::zero());
+    /// ```
+    pub fn euler_criterion<T: IntoU256>(&self, a: T) -> U256 {
+        let a = self.reduce(a);
+        if a.is_zero() {
+            // 0^0 would be 1 under modulus 2
+            return U256::zero();
         }
+        let exponent = (self.modulus - U256::one()) / U256::from(2);
+        self.exp(a, exponent)
     }
 
-    pub fn modulus<T: IntoU256>(&self, a: T) -> U256 {
-        a.into_u256() % self.modulus
+    /// Checks if `a` is a quadratic residue, a square under the modulus that is coprime
+    /// to it.
+    ///
+    /// Under a prime modulus this is the Legendre symbol. A composite modulus goes through
+    /// `is_square`, which factors it by trial division, as the Legendre and Jacobi symbols
+    /// do not tell its squares apart.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    ///
+    /// let math = ModMath::new(7);
+    /// assert!(math.is_quadratic_residue(2)); // 3^2 = 2 (mod 7)
+    /// assert!(!math.is_quadratic_residue(3));
+    /// assert!(!math.is_quadratic_residue(0));
+    /// // 2^((15 - 1) / 2) = 8, but the Jacobi symbol (2/15) is 1
+    /// assert!(!ModMath::new(15).is_quadratic_residue(2));
+    /// assert!(ModMath::new(15).is_quadratic_residue(4));
+    /// ```
+    pub fn is_quadratic_residue<T: IntoU256>(&self, a: T) -> bool {
+        let a = a.into_u256() % self.modulus;
+        if self.is_prime_modulus() {
+            return self.legendre_symbol(a) == 1;
+        }
+        !a.is_zero() && Self::gcd(a, self.modulus) == U256::one() && self.is_square(a)
     }
 
-    /// Adds two `U256` numbers under the modulus.
-    pub fn add<T: IntoU256>(&self, a: T, b: T) -> U256 {
-        let a = a.into_u256();
-        let b = b.into_u256();
-        match a.checked_add(b) {
-            Some(sum) => sum % self.modulus,
-            None => {
-                let a_512 = U512::from(a);
-                let b_512 = U512::from(b);
-                let modulus_512 = U512::from(self.modulus);
-                let result = (a_512 + b_512) % modulus_512;
+    /// Checks if `a` is a square under the modulus, which need not be prime. Zero is a
+    /// square.
+    ///
+    /// The Jacobi symbol rules out some non-squares cheaply, but a Jacobi symbol of 1
+    /// does not make `a` a square under a composite modulus, so the modulus is factored
+    /// by trial division and `a` checked modulo each prime power. Only intended for
+    /// moduli with small prime factors or a large prime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    ///
+    /// let math = ModMath::new(15);
+    /// assert!(math.is_square(4));
+    /// // the Jacobi symbol (2/15) is 1, but 2 is not a square mod 3 or mod 5
+    /// assert!(!math.is_square(2));
+    /// ```
+    pub fn is_square<T: IntoU256>(&self, a: T) -> bool {
+        let a = a.into_u256() % self.modulus;
+        if a == U256::zero() || a == U256::one() {
+            return true;
+        }
+        if self.modulus.bit(0) && Self::jacobi(a, self.modulus) == -1 {
+            return false;
+        }
+        if self.is_prime_modulus() {
+            return self.legendre_symbol(a) == 1;
+        }
+        Self::prime_factors(self.modulus).iter().all(|(&p, &k)| {
+            let prime_power = p.pow(k);
+            Self::is_square_mod_prime_power(a % prime_power, p, k.as_usize())
+        })
+    }
 
-                ModMath::u512_to_u256(result)
-            }
+    // a is a square mod p^k exactly when a = 0, or a = p^v * u with u coprime to p, v even
+    // and u a square mod p (or, for p = 2, u = 1 mod 2^min(k - v, 3))
+    fn is_square_mod_prime_power(a: U256, p: U256, k: usize) -> bool {
+        if a == U256::zero() {
+            return true;
+        }
+        let (mut u, mut v) = (a, 0);
+        while u % p == U256::zero() {
+            u /= p;
+            v += 1;
+        }
+        if v % 2 == 1 {
+            return false;
         }
+        if p == U256::from(2) {
+            let bits = (k - v).min(3);
+            return u.low_u64() & ((1 << bits) - 1) == 1;
+        }
+        Self::new(p).legendre_symbol(u % p) == 1
     }
 
-    /// Subtracts the second `U256` number from the first one under the modulus.
-    pub fn sub<T: IntoU256>(&self, a: T, b: T) -> U256 {
-        let a = a.into_u256();
-        let b = b.into_u256();
-        if b > a {
-            // (self.modulus + a - b) % self.modulus
-            match self.modulus.checked_add(a) {
-                Some(sum) => (sum - b) % self.modulus,
-                None => {
-                    let a_512 = U512::from(a);
-                    let b_512 = U512::from(b);
-                    let modulus_512 = U512::from(self.modulus);
-                    let result = (modulus_512 + a_512 - b_512) % modulus_512;
-
-                    ModMath::u512_to_u256(result)
+    // The Jacobi symbol (a/n) for odd n, by quadratic reciprocity
+    fn jacobi(mut a: U256, mut n: U256) -> i32 {
+        let mut result = 1;
+        a %= n;
+        while a != U256::zero() {
+            while !a.bit(0) {
+                a >>= 1;
+                let n_mod_8 = n.low_u64() & 7;
+                if n_mod_8 == 3 || n_mod_8 == 5 {
+                    result = -result;
                 }
             }
-        } else {
-            (a - b) % self.modulus
+            std::mem::swap(&mut a, &mut n);
+            if a.low_u64() & 3 == 3 && n.low_u64() & 3 == 3 {
+                result = -result;
+            }
+            a %= n;
         }
+        if n == U256::one() { result } else { 0 }
     }
 
-    /// Multiplies two `U256` numbers under the modulus.
-    pub fn mul<T: IntoU256>(&self, a: T, b: T) -> U256 {
-        let a_mod = a.into_u256() % self.modulus;
-        let b_mod = b.into_u256() % self.modulus;
-    
-        // Use checked_mul for safe multiplication
-        match a_mod.checked_mul(b_mod) {
-            Some(product) => product % self.modulus,
-            None => {
-                let a_mod_u512 = U512::from(a_mod);
-                let b_mod_u512 = U512::from(b_mod);
-                let result  = a_mod_u512 * b_mod_u512 % U512::from(self.modulus);
-
-                ModMath::u512_to_u256(result)
-            },
+    /// Returns a random non-zero quadratic residue under the odd prime modulus, as the
+    /// square of a random non-zero element. Under modulus 1 the only element is 0, which
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    ///
+    /// let math = ModMath::new(101);
+    /// let residue = math.random_residue(&mut rand::thread_rng());
+    /// assert!(math.is_quadratic_residue(residue));
+    /// ```
+    pub fn random_residue<R: RandomSource + ?Sized>(&self, rng: &mut R) -> U256 {
+        if self.modulus == U256::one() {
+            return U256::zero();
         }
+        self.square(self.random_nonzero(rng))
     }
-    
 
-    /// Raises the base to the power of the exponent under the modulus.
-    pub fn exp<T: IntoU256>(&self, base: T, exponent: T) -> U256 {
-        let mut result = U256::one();
-        let mut base = base.into_u256() % self.modulus;
-        let mut exponent = exponent.into_u256();
-        while exponent != U256::zero() {
-            if exponent % U256::from(2) != U256::zero() {
-                result = self.mul(result, base)
+    /// Returns a random quadratic non-residue under the odd prime modulus, by sampling
+    /// non-zero elements until the Legendre symbol is -1. Half the elements qualify, so
+    /// this takes two tries on average.
+    ///
+    /// The modulus must be prime; debug builds check this, as a composite modulus would
+    /// return an element whose Jacobi symbol is -1.
+    ///
+    /// # Panics
+    ///
+    /// Panics under modulus 2, which has no non-residues.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    ///
+    /// let math = ModMath::new(101);
+    /// let non_residue = math.random_non_residue(&mut rand::thread_rng());
+    /// assert!(!math.is_quadratic_residue(non_residue));
+    /// ```
+    pub fn random_non_residue<R: RandomSource + ?Sized>(&self, rng: &mut R) -> U256 {
+        assert!(self.modulus != U256::from(2), "every non-zero element is a square under modulus 2");
+        debug_assert!(self.is_prime_modulus(), "{} is not prime", self.modulus);
+        loop {
+            let candidate = self.random_nonzero(rng);
+            if self.legendre_symbol(candidate) == -1 {
+                return candidate;
             }
-            base = self.square(base);
-            exponent /= U256::from(2);
         }
-        result
     }
 
-    /// Calculates the modular multiplicative inverse of a `U256` number under the modulus.
+    /// Returns a uniformly random element of `[0, modulus)`, by rejection sampling over
+    /// the bit length of the modulus, which keeps the result unbiased.
     ///
-    /// Returns `None` if the inverse does not exist.
-    pub fn inv<T: IntoU256>(&self, a: T) -> Option<U256> {
-        let (mut m, mut x0, mut x1) = (self.modulus, U256::zero(), U256::one());
-        let mut a = a.into_u256() % self.modulus;
-        if self.modulus == U256::one() {
-            return None;
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use modular_math::random::OsRandom;
+    /// use primitive_types::U256;
+    ///
+    /// let element = ModMath::new(101).random(&mut OsRandom);
+    /// assert!(element < U256::from(101));
+    /// ```
+    pub fn random<R: RandomSource + ?Sized>(&self, rng: &mut R) -> U256 {
+        let bits = self.modulus.bits();
+        loop {
+            let mut bytes = [0u8; 32];
+            rng.fill_bytes(&mut bytes);
+            let candidate = U256::from_big_endian(&bytes) >> (256 - bits);
+            if candidate < self.modulus {
+                return candidate;
+            }
         }
-    
-        while a > U256::one() {
-            let q = a / m;
-            let mut temp = m;
-    
-            m = a % m;
-            a = temp;
-            temp = x0;
-            let t = self.mul(q, x0);
-            x0 = self.sub(x1, t);
-            x1 = temp;
+    }
+
+    // As `random`, also rejecting zero. Panics under modulus 1, which has no non-zero element.
+    pub(crate) fn random_nonzero<R: RandomSource + ?Sized>(&self, rng: &mut R) -> U256 {
+        assert!(self.modulus > U256::one(), "Modulus must be greater than 1");
+        loop {
+            let candidate = self.random(rng);
+            if candidate != U256::zero() {
+                return candidate;
+            }
         }
-    
-        if x1 < U256::zero() {
-            x1 = self.add(x1, self.modulus);
+    }
+
+    /// Find the square root of a given `U256` under modulus
+    ///
+    /// Uses the closed form `a^((p+1)/4)` when `p = 3 (mod 4)`, Atkin's algorithm when
+    /// `p = 5 (mod 8)` and falls back to tonelli-shanks for `p = 1 (mod 8)`.
+    /// Returns `SqrtError::NonResidue` if no sqrt exists.
+    ///
+    /// The tonelli-shanks setup, including the search for a non-residue, is done by the
+    /// first call and shared with every clone, such as the arithmetic stored in a `Curve`.
+    ///
+    /// These algorithms need a prime modulus. Under a composite modulus this returns
+    /// `SqrtError::NotCoprime` if `a` shares a factor with the modulus,
+    /// `SqrtError::NonResidue` if the Jacobi symbol shows that `a` is not a square, and
+    /// `SqrtError::NotPrime` otherwise, rather than a wrong root. Zero is its own root
+    /// under every modulus.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::{ModMath, SqrtError};
+    /// use primitive_types::U256;
+    ///
+    /// let math = ModMath::new(7);
+    /// assert_eq!(math.sqrt(2), Ok(U256::from(4)));
+    /// assert_eq!(math.sqrt(3), Err(SqrtError::NonResidue));
+    /// assert_eq!(ModMath::new(15).sqrt(6), Err(SqrtError::NotCoprime(U256::from(3))));
+    /// ```
+    pub fn sqrt<T: IntoU256>(&self, a: T) -> Result<U256, SqrtError> {
+        let a = a.into_u256() % self.modulus;
+        if !self.is_prime_modulus() {
+            return if a.is_zero() { Ok(a) } else { Err(self.composite_sqrt_error(a)) };
         }
-    
-        if a != U256::one() {
-            None
+
+        if self.modulus % U256::from(4) == U256::from(3) { // p = 4k + 3
+            let exponent = Self::floor_div(self.modulus + U256::one(), U256::from(4));
+            let root = self.exp(a, exponent);
+            // a non-residue yields a value whose square is -a
+            if self.square(root) == a {
+                Ok(root)
+            } else {
+                Err(SqrtError::NonResidue)
+            }
+        } else if self.modulus % U256::from(8) == U256::from(5) { // p = 8k + 5
+            self.atkin(a).ok_or(SqrtError::NonResidue)
         } else {
-            Some(x1)
+            // Tonelli Shanks Algorithm
+            self.tonelli_shanks(a)
         }
     }
 
-    /// Divides the first `U256` number by the second one under the modulus.
+    /// Finds a square root like `sqrt`, returning None for every reason `sqrt` fails.
     ///
-    /// # Panics
+    /// # Examples
     ///
-    /// Panics if the second number is zero or if its inverse does not exist under the modulus.
-    pub fn div<T: IntoU256>(&self, a: T, b: T) -> U256 {
-        let b = b.into_u256();
-        let b_inv = self.inv(b).unwrap_or_else(|| {
-            panic!("Cannot find Inverse of {}", b);
-        });
-         self.mul(a.into_u256(), b_inv)
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// let math = ModMath::new(7);
+    /// assert_eq!(math.sqrt_opt(2), Some(U256::from(4)));
+    /// assert_eq!(math.sqrt_opt(3), None);
+    /// ```
+    pub fn sqrt_opt<T: IntoU256>(&self, a: T) -> Option<U256> {
+        self.sqrt(a).ok()
     }
 
-    /// Calculates the additive inverse of a given `U256` under modulus
-    pub fn add_inv<T: IntoU256>(&self, a: T) -> U256 {
-      let a = a.into_u256();
-      if a == U256::zero() {
-        U256::zero()
-      } else {
-        self.modulus - a
-      }
-    }
-    
-    /// Checks if two `U256` numbers are equivalent under the modulus.
-    pub fn eq<T: IntoU256>(&self, a: T, b: T) -> bool {
-        a.into_u256() % self.modulus == b.into_u256() % self.modulus
+    // Why `sqrt` finds no root of a non-zero reduced `a` under a composite modulus. Only
+    // the Jacobi symbol is used, as deciding squareness needs the factors of the modulus.
+    fn composite_sqrt_error(&self, a: U256) -> SqrtError {
+        let factor = Self::gcd(self.modulus, a);
+        if factor != U256::one() {
+            SqrtError::NotCoprime(factor)
+        } else if self.modulus.bit(0) && Self::jacobi(a, self.modulus) == -1 {
+            SqrtError::NonResidue
+        } else {
+            SqrtError::NotPrime
+        }
     }
 
-    /// Squares a given U256 number under modulus
-    pub fn square<T: IntoU256>(&self, a: T) -> U256 {
-        let a = a.into_u256();
-        self.mul(a, a)
+    /// Finds the square root of `a` that follows the convention, out of the two roots
+    /// `r` and `p - r` that `sqrt` chooses between.
+    ///
+    /// Zero is its own negation and is returned under every convention. Returns None
+    /// if no sqrt exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::{ModMath, RootConvention};
+    /// use primitive_types::U256;
+    ///
+    /// // 4 has the roots 2 and 11 mod 13
+    /// let math = ModMath::new(13);
+    /// assert_eq!(math.sqrt_normalized(4, RootConvention::EvenY), Some(U256::from(2)));
+    /// assert_eq!(math.sqrt_normalized(4, RootConvention::OddY), Some(U256::from(11)));
+    /// assert_eq!(math.sqrt_normalized(4, RootConvention::Larger), Some(U256::from(11)));
+    /// ```
+    pub fn sqrt_normalized<T: IntoU256>(&self, a: T, convention: RootConvention) -> Option<U256> {
+        let root = self.sqrt_opt(a)?;
+        let negated = self.add_inv(root);
+        let choose_root = match convention {
+            RootConvention::EvenY => !root.bit(0),
+            RootConvention::OddY => root.bit(0),
+            RootConvention::Smaller => root <= negated,
+            RootConvention::Larger => root >= negated,
+        };
+        Some(if choose_root { root } else { negated })
     }
 
-    fn u512_to_u256(result: U512) -> U256 {
-        let mut result_little_endian = [0_u8; 64];
-        result.to_little_endian(&mut result_little_endian);
-        U256::from_little_endian(&result_little_endian[..32])
+    /// Finds a square root like `sqrt`, computing the root for `p = 3 (mod 4)` with
+    /// `exp_ct`, so the timing does not depend on the bits of a secret `a` beyond whether
+    /// it is a square.
+    ///
+    /// Other primes fall back to `sqrt`; Atkin's algorithm and Tonelli-Shanks are not
+    /// constant time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    ///
+    /// let math = ModMath::new(103);
+    /// assert_eq!(math.sqrt_ct(4), math.sqrt_opt(4));
+    /// assert_eq!(math.sqrt_ct(5), None);
+    /// ```
+    pub fn sqrt_ct<T: IntoU256>(&self, a: T) -> Option<U256> {
+        let a = a.into_u256() % self.modulus;
+        if !self.is_prime_modulus() || self.modulus % U256::from(4) != U256::from(3) {
+            return self.sqrt_opt(a);
+        }
+        let exponent = Self::floor_div(self.modulus + U256::one(), U256::from(4));
+        let root = self.exp_ct(a, exponent);
+        if self.square(root) == a {
+            Some(root)
+        } else {
+            None
+        }
     }
 
-    /// Find the square root of a given `U256` under modulus using tonelli-shanks algorithm
-    /// returns None if no sqrt exists
-    pub fn sqrt<T: IntoU256>(&self, a: T) -> Option<U256> {
-       
-       let a = a.into_u256();
+    // Atkin's square root for p = 5 (mod 8), a single exponentiation
+    fn atkin(&self, a: U256) -> Option<U256> {
+        let a = a % self.modulus;
+        if a == U256::zero() {
+            return Some(U256::zero());
+        }
+
+        let two_a = self.add(a, a);
+        let exponent = (self.modulus - U256::from(5)) / U256::from(8);
+        let b = self.exp(two_a, exponent);
+        let i = self.mul(two_a, self.square(b));
+        let x = self.mul(self.mul(a, b), self.sub(i, U256::one()));
 
-       if self.modulus % U256::from(4) == U256::from(3) { // p = 4k + 3
-        let exponent = Self::floor_div(self.modulus + U256::one(), U256::from(4));
-        return Some(self.exp(a, exponent));
-       } else {
-        // Tonelli Shanks Algorithm
-        return self.tonelli_shanks(a);
-       }
+        if self.square(x) == a {
+            Some(x)
+        } else {
+            None
+        }
     }
 
     fn floor_div(a: U256, b: U256) -> U256 {
@@ -202,9 +1963,9 @@ impl ModMath {
     // utility function to find gcd 
     fn gcd(a: U256, b: U256) -> U256 {
         if b == U256::zero() {
-            return a;
+            a
         } else {
-            return Self::gcd(b, a % b)
+            Self::gcd(b, a % b)
         }
     }
 
@@ -223,19 +1984,67 @@ impl ModMath {
         }
     }
 
-    fn convertx2e(mut x: U256) -> (U256, U256) {
-        let mut z = U256::zero();
-        while x % U256::from(2) == U256::zero() {
-            x = x / U256::from(2);
-            z += U256::one();
-        } 
-        (x, z)
+    fn convertx2e(x: U256) -> (U256, U256) {
+        let z = trailing_zeros(x);
+        (x >> z, U256::from(z))
+    }
+
+    /// Computes the Legendre symbol of `a`: 1 for a non-zero square, -1 for a non-square
+    /// and 0 for zero, as the Jacobi symbol, which agrees with it under a prime modulus.
+    ///
+    /// Returns `ArithError::NotPrime` if the modulus is not prime, where the Jacobi
+    /// symbol does not tell squares apart.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::{ArithError, ModMath};
+    ///
+    /// assert_eq!(ModMath::new(7).legendre(2), Ok(1));
+    /// assert_eq!(ModMath::new(7).legendre(3), Ok(-1));
+    /// assert_eq!(ModMath::new(15).legendre(4), Err(ArithError::NotPrime));
+    /// ```
+    pub fn legendre<T: IntoU256>(&self, a: T) -> Result<i32, ArithError> {
+        if !self.is_prime_modulus() {
+            return Err(ArithError::NotPrime);
+        }
+        Ok(self.legendre_symbol(a.into_u256() % self.modulus))
+    }
+
+    /// Finds the modular inverse by Fermat's little theorem, `a^(p-2)`.
+    ///
+    /// Returns `ArithError::NotPrime` if the modulus is not prime, where the theorem does
+    /// not hold, and `ArithError::NotInvertible` if `a` is zero under the modulus. Use
+    /// `inv` for composite moduli.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::{ArithError, ModMath};
+    /// use primitive_types::U256;
+    ///
+    /// assert_eq!(ModMath::new_prime(13).inv_prime(2), Ok(U256::from(7)));
+    /// assert_eq!(ModMath::new(15).inv_prime(2), Err(ArithError::NotPrime));
+    /// ```
+    pub fn inv_prime<T: IntoU256>(&self, a: T) -> Result<U256, ArithError> {
+        if !self.is_prime_modulus() {
+            return Err(ArithError::NotPrime);
+        }
+        let a = a.into_u256() % self.modulus;
+        if a == U256::zero() {
+            return Err(ArithError::NotInvertible);
+        }
+        Ok(self.exp(a, self.modulus - U256::from(2)))
     }
 
+    // For an odd prime modulus the Legendre symbol is the Jacobi symbol, which takes a few
+    // divisions instead of the exponentiation of Euler's criterion.
     fn legendre_symbol(&self, a: U256) -> i32 {
-        let exponent = (self.modulus - U256::one()) / U256::from(2);
-        let result = self.exp(a, exponent);
-        
+        if self.modulus.bit(0) {
+            return if self.reduce(a).is_zero() { 0 } else { Self::jacobi(a, self.modulus) };
+        }
+        let result = self.euler_criterion(a);
+
         if result == U256::one() {
             1
         } else if result == U256::zero() {
@@ -245,56 +2054,93 @@ impl ModMath {
         }
     }
 
-    fn tonelli_shanks(&self, a: U256) -> Option<U256> {
+    // The setup is shared by every clone of this `ModMath`, so a curve searches for the
+    // non-residue once however many points it decompresses.
+    //
+    // Under GRH the least non-residue of a prime p is below 2 ln^2 p (Bach), which is
+    // 63,000 for 256 bits, so a search that passes the bound, with ln p taken as
+    // bits * ln 2, has a composite modulus, e.g. from `new_prime`. The candidates are
+    // tested by the Legendre symbol, which is the Jacobi symbol and needs no
+    // exponentiation. Under a composite modulus a Jacobi symbol of -1 does not give
+    // `q^((p-1)/2) = -1`, which the setup relies on, and the Carmichael number 561 has
+    // no such element at all, so Euler's criterion is checked once for the `q` found.
+    fn tonelli_shanks_params(&self) -> Result<&TonelliShanksParams, SqrtError> {
+        self.params
+            .tonelli_shanks
+            .get_or_init(|| {
+                #[cfg(test)]
+                NON_RESIDUE_SEARCHES.with(|count| count.set(count.get() + 1));
+
+                let ln_p = self.modulus.bits() as f64 * std::f64::consts::LN_2;
+                let bound = U256::from((2.0 * ln_p * ln_p).ceil() as u64).min(self.modulus - 1);
+                let mut q = U256::from(2);
+                while self.legendre_symbol(q) != -1 {
+                    if q >= bound {
+                        return None;
+                    }
+                    q += U256::one();
+                }
+                if self.euler_criterion(q) != self.modulus - U256::one() {
+                    return None;
+                }
+                let (s, e) = Self::convertx2e(self.modulus - U256::one());
+                Some(TonelliShanksParams {
+                    s,
+                    e,
+                    g: self.exp(q, s),
+                    #[cfg(test)]
+                    q,
+                })
+            })
+            .as_ref()
+            .ok_or(SqrtError::NotPrime)
+    }
+
+    // The non-residue the Tonelli-Shanks setup found
+    #[cfg(test)]
+    pub(crate) fn tonelli_shanks_non_residue(&self) -> Result<U256, SqrtError> {
+        self.tonelli_shanks_params().map(|params| params.q)
+    }
+
+    pub(crate) fn tonelli_shanks(&self, a: U256) -> Result<U256, SqrtError> {
         
-        if self.modulus == U256::from(2) {
-            return Some(a)
+        if self.modulus == U256::from(2) || a == U256::zero() {
+            return Ok(a)
         }
 
-        if Self::gcd(a, self.modulus) != U256::one() {
-            return None
+        let factor = Self::gcd(a, self.modulus);
+        if factor != U256::one() {
+            return Err(SqrtError::NotCoprime(factor))
         }
 
         match self.legendre_symbol(a) {
-            -1 => return None,
-            0 => return Some(U256::zero()),
+            -1 => return Err(SqrtError::NonResidue),
+            0 => return Ok(U256::zero()),
             _ => (),
         }
 
-        let (s, e) = Self::convertx2e(self.modulus - U256::one());
-        let mut q = U256::from(2);
-
-        loop {
-            let exponent = (self.modulus - U256::one()) / U256::from(2);
-            if self.exp(q, exponent) == self.modulus - U256::one() {
-                break;
-            }
-            q += U256::one();
-        }
+        let &TonelliShanksParams { s, e, g, .. } = self.tonelli_shanks_params()?;
+        let mut g = g;
 
         let exp_a = (s + U256::one()) / U256::from(2);
         let mut x = self.exp(a, exp_a);
         let mut b = self.exp(a, s);
-        let mut g = self.exp(q, s);
 
         let mut r = e;
 
         loop {
             let mut m = U256::zero();
 
-            while (m < r) {
-                if self.order(b).is_none() {
-                    return None
-                }
-
-                if self.order(b).unwrap() == U256::from(2).pow(m) {
+            while m < r {
+                // b is a power of a, so it is coprime to the modulus and has an order
+                if self.order(b).ok_or(SqrtError::NonResidue)? == U256::from(2).pow(m) {
                     break;
                 }
                 m += U256::one();
             }
 
             if m == U256::zero() {
-                return Some(x);
+                return Ok(x);
             }
 
             let exp_x = self.exp(U256::from(2), r - m - U256::one());
@@ -305,7 +2151,7 @@ impl ModMath {
             b = self.mul(b, g);
 
             if b == U256::one() {
-                return Some(x);
+                return Ok(x);
             }
             r = m;
         }
@@ -316,11 +2162,159 @@ impl ModMath {
     
 }
 
+/// Errors returned by the fallible arithmetic operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArithError {
+    /// The result does not fit in a `U256`
+    Overflow,
+    /// The modulus is zero
+    ZeroModulus,
+    /// An encoding does not have the expected number of bytes
+    InvalidLength,
+    /// The operation needs a prime modulus
+    NotPrime,
+    /// The value has no inverse under the modulus
+    NotInvertible,
+    /// The operands do not have the same length
+    LengthMismatch,
+    /// An encoded value is not below the modulus
+    NotCanonical,
+    /// The operation needs an odd modulus, e.g. halving in characteristic 2
+    EvenModulus,
+    /// The modulus is above the bound the operation accepts, e.g. for a Cayley table
+    ModulusTooLarge,
+}
+
+impl std::fmt::Display for ArithError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArithError::Overflow => write!(f, "the result does not fit in a U256"),
+            ArithError::ZeroModulus => write!(f, "the modulus is zero"),
+            ArithError::InvalidLength => write!(f, "the encoding does not have the expected number of bytes"),
+            ArithError::NotPrime => write!(f, "the operation needs a prime modulus"),
+            ArithError::NotInvertible => write!(f, "the value has no inverse under the modulus"),
+            ArithError::LengthMismatch => write!(f, "the operands do not have the same length"),
+            ArithError::NotCanonical => write!(f, "the encoded value is not below the modulus"),
+            ArithError::EvenModulus => write!(f, "the operation needs an odd modulus"),
+            ArithError::ModulusTooLarge => write!(f, "the modulus is too large for the operation"),
+        }
+    }
+}
+
+impl std::error::Error for ArithError {}
+
+/// Which of the two square roots `ModMath::sqrt_normalized` returns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RootConvention {
+    /// The even root, e.g. for an even `y` coordinate
+    EvenY,
+    /// The odd root
+    OddY,
+    /// The root below `p / 2`
+    Smaller,
+    /// The root above `p / 2`
+    Larger,
+}
+
+/// Reasons why `ModMath::inv_detailed` finds no inverse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvError {
+    /// The value is zero under the modulus
+    Zero,
+    /// The value shares this factor with the modulus
+    NotCoprime(U256),
+}
+
+impl std::fmt::Display for InvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvError::Zero => write!(f, "zero has no inverse"),
+            InvError::NotCoprime(factor) => write!(f, "the value shares the factor {} with the modulus", factor),
+        }
+    }
+}
+
+impl std::error::Error for InvError {}
+
+/// Reasons why `ModMath::sqrt` finds no square root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SqrtError {
+    /// The value is not a square under the modulus
+    NonResidue,
+    /// The value shares this factor with the modulus
+    NotCoprime(U256),
+    /// The modulus is not prime, and the value may be a square whose root is not found
+    NotPrime,
+}
+
+impl std::fmt::Display for SqrtError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SqrtError::NonResidue => write!(f, "the value is not a square under the modulus"),
+            SqrtError::NotCoprime(factor) => write!(f, "the value shares the factor {} with the modulus", factor),
+            SqrtError::NotPrime => write!(f, "square roots need a prime modulus"),
+        }
+    }
+}
+
+impl std::error::Error for SqrtError {}
 
+/// Conversion of integers and numeric strings to `U256`.
+///
+/// The conversion panics for a value that is not a number in `[0, 2^256)`, such as a
+/// negative integer or a malformed string. `TryIntoU256` returns an error instead.
 pub trait IntoU256 {
     fn into_u256(self) -> U256;
 }
 
+/// Conversion from a reduced `U256` to a result type, given the arithmetic that produced
+/// it, for `ModMath::add_as` and `ModMath::mul_as`. Types that carry their modulus, such
+/// as `NumberUnderMod`, take it from `math`.
+pub trait FromU256 {
+    fn from_u256(value: U256, math: &ModMath) -> Self;
+}
+
+impl FromU256 for U256 {
+    fn from_u256(value: U256, _math: &ModMath) -> Self {
+        value
+    }
+}
+
+/// Fallible conversion to `U256`, for input that may not be a number, e.g. read from a
+/// configuration file. The error echoes the input.
+pub trait TryIntoU256 {
+    fn try_into_u256(self) -> Result<U256, IntoU256Error>;
+}
+
+/// Error returned by `TryIntoU256` for a value that is not a number in `[0, 2^256)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntoU256Error {
+    input: String,
+}
+
+// The longest string `TryIntoU256` parses, which leaves room for leading zeros. Longer
+// inputs are rejected without parsing, and errors keep at most this many characters.
+const MAX_NUMBER_LEN: usize = 128;
+
+impl IntoU256Error {
+    fn new(input: impl ToString) -> Self {
+        IntoU256Error { input: input.to_string().chars().take(MAX_NUMBER_LEN).collect() }
+    }
+
+    /// Returns the input that could not be converted, cut to its first 128 characters.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl std::fmt::Display for IntoU256Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} is not a number in [0, 2^256)", self.input)
+    }
+}
+
+impl std::error::Error for IntoU256Error {}
+
 impl IntoU256 for u32 {
     fn into_u256(self) -> U256 {
         U256::from(self)
@@ -351,9 +2345,17 @@ impl IntoU256 for i64 {
     }
 }
 
+/// Parses decimal, or hex with a `0x` prefix.
+///
+/// # Panics
+///
+/// Panics if the string is not a valid number or does not fit in a `U256`.
 impl IntoU256 for &str {
     fn into_u256(self) -> U256 {
-        U256::from_dec_str(self).unwrap()
+        match self.strip_prefix("0x").or_else(|| self.strip_prefix("0X")) {
+            Some(hex) => U256::from_str_radix(hex, 16).unwrap(),
+            None => U256::from_dec_str(self).unwrap(),
+        }
     }
 }
 
@@ -361,4 +2363,57 @@ impl IntoU256 for U256 {
     fn into_u256(self) -> U256 {
         self
     }
-}
\ No newline at end of file
+}
+
+impl TryIntoU256 for u32 {
+    fn try_into_u256(self) -> Result<U256, IntoU256Error> {
+        Ok(U256::from(self))
+    }
+}
+
+impl TryIntoU256 for i32 {
+    fn try_into_u256(self) -> Result<U256, IntoU256Error> {
+        u32::try_from(self).map(U256::from).map_err(|_| IntoU256Error::new(self))
+    }
+}
+
+impl TryIntoU256 for u64 {
+    fn try_into_u256(self) -> Result<U256, IntoU256Error> {
+        Ok(U256::from(self))
+    }
+}
+
+impl TryIntoU256 for i64 {
+    fn try_into_u256(self) -> Result<U256, IntoU256Error> {
+        u64::try_from(self).map(U256::from).map_err(|_| IntoU256Error::new(self))
+    }
+}
+
+/// Parses decimal, or hex with a `0x` prefix, like `IntoU256`.
+///
+/// Rejects strings without digits and strings longer than 128 bytes, so untrusted input
+/// is parsed in bounded time.
+impl TryIntoU256 for &str {
+    #[deny(clippy::unwrap_used, clippy::expect_used)]
+    fn try_into_u256(self) -> Result<U256, IntoU256Error> {
+        if self.len() > MAX_NUMBER_LEN {
+            return Err(IntoU256Error::new(self));
+        }
+        let parsed = match self.strip_prefix("0x").or_else(|| self.strip_prefix("0X")) {
+            // the parsers read an empty string as zero
+            Some("") => None,
+            // the hex parser also skips a second `0x` prefix
+            Some(hex) if !hex.bytes().all(|b| b.is_ascii_hexdigit()) => None,
+            Some(hex) => U256::from_str_radix(hex, 16).ok(),
+            None if self.is_empty() => None,
+            None => U256::from_dec_str(self).ok(),
+        };
+        parsed.ok_or_else(|| IntoU256Error::new(self))
+    }
+}
+
+impl TryIntoU256 for U256 {
+    fn try_into_u256(self) -> Result<U256, IntoU256Error> {
+        Ok(self)
+    }
+}
@@ -2,9 +2,13 @@
 
 #[cfg(test)]
 mod tests {
-    use primitive_types::U256;
+    use primitive_types::{U256, U512};
+    use rand::{rngs::StdRng, RngCore, SeedableRng};
+    use std::collections::HashMap;
 
-    use crate::mod_math::{ModMath, IntoU256};
+    use crate::mod_math::{ModMath, WideModMath, MAX_ROOTS_OF_UNITY, IntoU256, FromU256, ArithError, InvError, Montgomery, RootConvention, SqrtError};
+    use crate::number_mod::NumberUnderMod;
+    use crate::mod_math::mod_math::{Reduction, FIELD_PARAMS_BUILT, LAST_BLINDED_EXPONENT, WIDE_ADDITIONS};
 
 
     #[test]
@@ -17,6 +21,27 @@ mod tests {
         assert_eq!(math.add(U256::from(20), U256::from(75)), U256::from(95));
     }
 
+    #[test]
+    fn test_add_reduces_near_max_operands() {
+        let math = ModMath::new(100);
+        let before = WIDE_ADDITIONS.with(|count| count.get());
+
+        // U256::MAX = ...935 so U256::MAX % 100 = 35
+        assert_eq!(math.add(U256::max_value(), U256::max_value()), U256::from(70));
+        assert_eq!(math.add(U256::max_value() - U256::from(5), U256::from(80)), U256::from(10));
+
+        assert_eq!(WIDE_ADDITIONS.with(|count| count.get()), before);
+    }
+
+    #[test]
+    fn test_add_large_modulus_widens() {
+        let math = ModMath::new(U256::max_value() - U256::from(1));
+        let before = WIDE_ADDITIONS.with(|count| count.get());
+        let a = U256::max_value() - U256::from(2);
+        assert_eq!(math.add(a, a), U256::max_value() - U256::from(3));
+        assert_eq!(WIDE_ADDITIONS.with(|count| count.get()), before + 1);
+    }
+
     #[test]
     fn test_sub() {
         let modulus = U256::from(100);
@@ -25,6 +50,17 @@ mod tests {
         assert_eq!(math.sub(U256::from(30), U256::from(40)), U256::from(90));
     }
 
+    #[test]
+    fn test_sub_unreduced_operands() {
+        let math = ModMath::new(100);
+        assert_eq!(math.sub(150, 40), U256::from(10));
+        assert_eq!(math.sub(40, 150), U256::from(90));
+        assert_eq!(math.sub(250, 199), U256::from(51));
+        assert_eq!(math.sub(199, 250), U256::from(49));
+        assert_eq!(math.sub(U256::max_value(), U256::from(3)), U256::from(32));
+        assert_eq!(math.sub(U256::from(3), U256::max_value()), U256::from(68));
+    }
+
     #[test]
     fn test_multiply() {
         let modulus = U256::from(100);
@@ -55,6 +91,16 @@ mod tests {
         assert_eq!(math.mul(b, b_inv), U256::one());
     }
 
+    #[test]
+    fn test_inv_zero() {
+        for modulus in [U256::from(2), U256::from(15), U256::from(101), secp256k1_p(), U256::MAX] {
+            let math = ModMath::new(modulus);
+            assert_eq!(math.inv(U256::zero()), None, "{}", modulus);
+            // multiples of the modulus reduce to zero
+            assert_eq!(math.inv(modulus), None, "{}", modulus);
+        }
+    }
+
     #[test]
     fn test_div() {
         let modulus = U256::from(101);
@@ -74,7 +120,7 @@ mod tests {
         let p: U256 = U256::from_dec_str("115792089237316195423570985008687907852837564279074904382605163141518161494337").unwrap();
         let math = ModMath::new(p);
 
-        let num = U256::from_dec_str("32670510020758816978083085130507043184471273380659243275938904335757337482424").unwrap();
+        let _num = U256::from_dec_str("32670510020758816978083085130507043184471273380659243275938904335757337482424").unwrap();
         let den = U256::from_dec_str("55066263022277343669578718895168534326250603453777594175500187360389116729240").unwrap();
 
         let den_inv = math.inv(den).unwrap();
@@ -92,6 +138,8 @@ mod tests {
         assert_eq!(10_u64.into_u256(), U256::from(10));
         assert_eq!(10_i64.into_u256(), U256::from(10));
         assert_eq!("10".into_u256(), U256::from(10));
+        assert_eq!("0x1f".into_u256(), U256::from(31));
+        assert_eq!("0X1F".into_u256(), U256::from(31));
         assert_eq!(U256::from(10).into_u256(), U256::from(10));
     }
 
@@ -116,6 +164,1109 @@ mod tests {
         assert_eq!(math.exp(mod_sqrt, U256::from(2)), U256::from(num));
     }
 
+    #[test]
+    fn test_sqrt_p_5_mod_8() {
+        // 101 = 8 * 12 + 5
+        let math = ModMath::new(101);
+        for a in 1..101_u32 {
+            let a = U256::from(a);
+            match math.sqrt(a) {
+                Ok(root) => {
+                    assert_eq!(math.square(root), a);
+                    let general = math.tonelli_shanks(a).unwrap();
+                    assert!(root == general || root == math.add_inv(general));
+                }
+                Err(e) => assert_eq!(math.tonelli_shanks(a), Err(e)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_roots_of_unity_bn128_scalar_field() {
+        let r = U256::from_dec_str("21888242871839275222246405745257275088548364400416034343698204186575808495617").unwrap();
+        let math = ModMath::new(r);
+        let n = U256::from(2).pow(U256::from(28));
+
+        // 5^((r - 1) / 2^28), the standard 2^28-th root of unity of the BN128 scalar field
+        let w = U256::from_dec_str("19103219067921713944291392827692070036145651957329286315305642004821462161904").unwrap();
+        assert!(math.is_nth_root_of_unity(w, n));
+
+        let w_squared = math.square(w);
+        assert!(math.is_nth_root_of_unity(w_squared, n / 2));
+        assert!(!math.is_nth_root_of_unity(w_squared, n));
+
+        assert!(!math.is_nth_root_of_unity(U256::from(5), n));
+        assert!(!math.is_nth_root_of_unity(U256::one(), n));
+    }
+
+    #[test]
+    fn test_nth_roots_of_unity() {
+        let math = ModMath::new(13);
+        let roots = math.nth_roots_of_unity(4).unwrap();
+        assert_eq!(roots.len(), 4);
+        assert_eq!(roots[0], U256::one());
+        assert!(math.is_nth_root_of_unity(roots[1], U256::from(4)));
+        for root in &roots {
+            assert_eq!(math.exp(*root, U256::from(4)), U256::one());
+        }
+
+        // 5 does not divide 12
+        assert!(math.nth_roots_of_unity(5).is_none());
+
+        // 2^28 divides r - 1 for the BN128 scalar field, but is too many roots to list
+        let r = U256::from_dec_str("21888242871839275222246405745257275088548364400416034343698204186575808495617").unwrap();
+        let scalars = ModMath::new(r);
+        assert!(scalars.nth_roots_of_unity(1u64 << 28).is_none());
+        assert!(scalars.nth_roots_of_unity(r - 1).is_none());
+        assert!(scalars.nth_roots_of_unity(MAX_ROOTS_OF_UNITY * 2).is_none());
+        assert_eq!(scalars.nth_roots_of_unity(1u64 << 12).map(|roots| roots.len()), Some(1 << 12));
+
+        // 2 divides m - 1, but a composite modulus has no cyclic group of units to search
+        assert!(ModMath::new(15).nth_roots_of_unity(2).is_none());
+        let composite = U256::from_dec_str("340282366920938463463374607431768211457").unwrap(); // 2^128 + 1
+        assert!(ModMath::new(composite).nth_roots_of_unity(2).is_none());
+    }
+
+    #[test]
+    fn test_divisor_functions() {
+        let factors = ModMath::prime_factors(U256::from(28));
+        assert_eq!(ModMath::num_divisors(&factors), Ok(U256::from(6)));
+        // 28 is a perfect number
+        assert_eq!(ModMath::sum_divisors(&factors), Ok(U256::from(56)));
+
+        let one = ModMath::prime_factors(U256::one());
+        assert!(one.is_empty());
+        assert_eq!(ModMath::num_divisors(&one), Ok(U256::one()));
+        assert_eq!(ModMath::sum_divisors(&one), Ok(U256::one()));
+        assert_eq!(ModMath::mobius(&one), 1);
+    }
+
+    #[test]
+    fn test_prime_factors_zero_and_one() {
+        // 0 used to loop forever dividing out 2
+        assert!(ModMath::prime_factors(U256::zero()).is_empty());
+        assert!(ModMath::prime_factors(U256::one()).is_empty());
+        let factors = ModMath::prime_factors(U256::from(2 * 2 * 3 * 101));
+        assert_eq!(factors, HashMap::from([(U256::from(2), U256::from(2)), (U256::from(3), U256::one()), (U256::from(101), U256::one())]));
+    }
+
+    #[test]
+    fn test_sum_divisors_overflow() {
+        // sigma(2^255) = 2^256 - 1 fits exactly, sigma(2^256) does not
+        let fits = HashMap::from([(U256::from(2), U256::from(255))]);
+        assert_eq!(ModMath::sum_divisors(&fits), Ok(U256::max_value()));
+
+        let overflows = HashMap::from([(U256::from(2), U256::from(256))]);
+        assert_eq!(ModMath::sum_divisors(&overflows), Err(ArithError::Overflow));
+    }
+
+    #[test]
+    fn test_num_divisors_overflow() {
+        // two primes with exponent 2^128 - 1 have 2^128 * 2^128 = 2^256 divisor combinations
+        let exponent = U256::one() << 128;
+        let fits = HashMap::from([(U256::from(2), exponent - 1), (U256::from(3), exponent - 2)]);
+        assert_eq!(ModMath::num_divisors(&fits), Ok(exponent * (exponent - 1)));
+
+        let overflows = HashMap::from([(U256::from(2), exponent - 1), (U256::from(3), exponent - 1)]);
+        assert_eq!(ModMath::num_divisors(&overflows), Err(ArithError::Overflow));
+        let huge = HashMap::from([(U256::from(2), U256::max_value())]);
+        assert_eq!(ModMath::num_divisors(&huge), Err(ArithError::Overflow));
+    }
+
+    #[test]
+    fn test_mobius() {
+        let mobius = |n: u32| ModMath::mobius(&ModMath::prime_factors(U256::from(n)));
+        assert_eq!(mobius(2), -1);
+        assert_eq!(mobius(6), 1);
+        assert_eq!(mobius(30), -1);
+        assert_eq!(mobius(4), 0);
+        assert_eq!(mobius(12), 0);
+        assert_eq!(mobius(49), 0);
+    }
+
+    fn secp256k1_p() -> U256 {
+        U256::from_str_radix("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F", 16).unwrap()
+    }
+
+    fn secp256k1_n() -> U256 {
+        U256::from_str_radix("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141", 16).unwrap()
+    }
+
+    fn bn128_p() -> U256 {
+        U256::from_dec_str("21888242871839275222246405745257275088696311157297823662689037894645226208583").unwrap()
+    }
+
+    fn bn128_r() -> U256 {
+        U256::from_dec_str("21888242871839275222246405745257275088548364400416034343698204186575808495617").unwrap()
+    }
+
+    fn p256_p() -> U256 {
+        U256::from_str_radix("FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFF", 16).unwrap()
+    }
+
+    #[test]
+    fn test_special_form_reduction_selection() {
+        assert!(matches!(ModMath::new(secp256k1_p()).params().reduction, Reduction::PseudoMersenne { k: 256, .. }));
+        assert!(matches!(ModMath::new(secp256k1_n()).params().reduction, Reduction::PseudoMersenne { k: 256, .. }));
+
+        assert!(matches!(ModMath::new(bn128_p()).params().reduction, Reduction::Barrett { .. }));
+        assert!(matches!(ModMath::new(bn128_r()).params().reduction, Reduction::Barrett { .. }));
+        assert!(matches!(ModMath::new(p256_p()).params().reduction, Reduction::Barrett { .. }));
+
+        // 2^127 - 1 is too small to bother, 2^192 - 2^64 - 1 qualifies
+        assert_eq!(ModMath::new(U256::from(2).pow(U256::from(127)) - U256::one()).params().reduction, Reduction::Generic);
+        let p192 = U256::from(2).pow(U256::from(192)) - U256::from(2).pow(U256::from(64)) - U256::one();
+        assert!(matches!(ModMath::new(p192).params().reduction, Reduction::PseudoMersenne { k: 192, .. }));
+        // 2^160 + 7, the generic modulus of `self_test`, is neither
+        let p161 = U256::from(2).pow(U256::from(160)) + 7;
+        assert_eq!(ModMath::new(p161).params().reduction, Reduction::Generic);
+        // Barrett needs an odd modulus above 2^192
+        assert_eq!(ModMath::new(bn128_p() - 1).params().reduction, Reduction::Generic);
+        assert_eq!(ModMath::new(U256::from(2).pow(U256::from(180)) + 1).params().reduction, Reduction::Generic);
+    }
+
+    fn check_special_form_reduction(iterations: usize) {
+        let p192 = U256::from(2).pow(U256::from(192)) - U256::from(2).pow(U256::from(64)) - U256::one();
+        let mut rng = StdRng::seed_from_u64(0x5eed);
+
+        for modulus in [secp256k1_p(), secp256k1_n(), p192, bn128_p(), bn128_r(), p256_p(), U256::max_value()] {
+            let math = ModMath::new(modulus);
+            let m = U512::from(modulus);
+            let edge_cases = [
+                U512::zero(),
+                m - U512::one(),
+                m,
+                m + U512::one(),
+                (m - U512::one()) * (m - U512::one()),
+                U512::one() << 256,
+                U512::max_value(),
+            ];
+            for x in edge_cases {
+                assert_eq!(math.reduce_wide(x), math.reduce_wide_generic(x));
+            }
+
+            for _ in 0..iterations {
+                let mut bytes = [0_u8; 64];
+                rng.fill_bytes(&mut bytes);
+                let x = U512::from_little_endian(&bytes);
+                assert_eq!(math.reduce_wide(x), math.reduce_wide_generic(x), "{}", x);
+            }
+        }
+    }
+
+    #[test]
+    fn test_special_form_reduction_matches_generic() {
+        check_special_form_reduction(20_000);
+    }
+
+    // 7 million inputs over the 7 moduli: cargo test --release -- --ignored
+    #[test]
+    #[ignore]
+    fn test_special_form_reduction_matches_generic_millions() {
+        check_special_form_reduction(1_000_000);
+    }
+
+    #[test]
+    fn test_exp_bytes() {
+        let p = secp256k1_p();
+        let math = ModMath::new(p);
+        let mut rng = StdRng::seed_from_u64(9);
+        for _ in 0..20 {
+            let mut bytes = [0u8; 64];
+            rng.fill_bytes(&mut bytes);
+            let base = U256::from_big_endian(&bytes[..32]) % p;
+
+            // a 32-byte exponent behaves like exp
+            assert_eq!(math.exp_bytes(base, &bytes[32..]), math.exp(base, U256::from_big_endian(&bytes[32..])));
+
+            // a 64-byte exponent can be reduced mod p - 1 first
+            let exponent = U512::from_big_endian(&bytes);
+            let reduced = u512_low(exponent % U512::from(p - U256::one()));
+            assert_eq!(math.exp_bytes(base, &bytes), math.exp(base, reduced));
+            assert_eq!(math.exp_u512(base, exponent), math.exp(base, reduced));
+        }
+
+        assert_eq!(math.exp_bytes(U256::from(5), &[0u8; 40]), U256::one());
+        assert_eq!(math.exp_bytes(U256::from(5), &[]), U256::one());
+        assert_eq!(math.exp_bytes(U256::from(5), &[0, 0, 3]), U256::from(125));
+    }
+
+    #[test]
+    fn test_is_primitive_root() {
+        let math = ModMath::new(23);
+        let roots: Vec<u32> = (0..23).filter(|&g| math.is_primitive_root(g)).collect();
+        assert_eq!(roots, vec![5, 7, 10, 11, 14, 15, 17, 19, 20, 21]);
+
+        let math = ModMath::new(11);
+        assert!(math.is_primitive_root(2));
+        assert!(!math.is_primitive_root(3));
+        assert!(math.is_primitive_root(13)); // 13 = 2 mod 11
+        assert!(!math.is_primitive_root(1));
+    }
+
+    #[test]
+    fn test_batch_inv() {
+        let p = secp256k1_p();
+        let math = ModMath::new(p);
+        let mut rng = StdRng::seed_from_u64(13);
+        let values: Vec<U256> = (0..10).map(|_| {
+            let mut bytes = [0u8; 32];
+            rng.fill_bytes(&mut bytes);
+            U256::from_big_endian(&bytes) % p
+        }).collect();
+        let inverses = math.batch_inv(&values).unwrap();
+        for (value, inverse) in values.iter().zip(&inverses) {
+            assert_eq!(Some(*inverse), math.inv(*value));
+        }
+
+        assert_eq!(math.batch_inv(&[]), Some(vec![]));
+        assert_eq!(ModMath::new(12).batch_inv(&[U256::from(5), U256::from(4)]), None);
+    }
+
+    #[test]
+    fn test_mul_secp256k1_sized_operands() {
+        let bn128_p = U256::from_dec_str("21888242871839275222246405745257298275088548364400416591296202207496468631093").unwrap();
+        let mut rng = StdRng::seed_from_u64(0x3a1);
+        for modulus in [secp256k1_p(), bn128_p, U256::from(u128::MAX), U256::from(u128::MAX) + U256::from(2)] {
+            let math = ModMath::new(modulus);
+            let m = U512::from(modulus);
+            assert_eq!(math.mul(modulus - U256::one(), modulus - U256::one()), U256::one());
+
+            for _ in 0..2_000 {
+                let mut bytes = [0_u8; 64];
+                rng.fill_bytes(&mut bytes);
+                let a = U256::from_big_endian(&bytes[..32]);
+                let b = U256::from_big_endian(&bytes[32..]);
+                let expected = u512_low((U512::from(a) % m) * (U512::from(b) % m) % m);
+                assert_eq!(math.mul(a, b), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_square_matches_mul() {
+        let bn128_p = U256::from_dec_str("21888242871839275222246405745257298275088548364400416591296202207496468631093").unwrap();
+        let mut rng = StdRng::seed_from_u64(0x5a);
+        for modulus in [U256::from(100), U256::from(u64::MAX), secp256k1_p(), bn128_p, U256::max_value()] {
+            let math = ModMath::new(modulus);
+            let edge_cases = [
+                U256::zero(),
+                U256::one(),
+                U256::from(u128::MAX),
+                U256::from(u128::MAX) + U256::one(),
+                modulus - U256::one(),
+                modulus,
+                U256::max_value(),
+            ];
+            for a in edge_cases {
+                assert_eq!(math.square(a), math.mul(a, a), "{}", a);
+            }
+            for _ in 0..1_000 {
+                let mut bytes = [0_u8; 32];
+                rng.fill_bytes(&mut bytes);
+                let a = U256::from_big_endian(&bytes);
+                assert_eq!(math.square(a), math.mul(a, a));
+                // also below 2^128
+                let a = a >> 128;
+                assert_eq!(math.square(a), math.mul(a, a));
+            }
+        }
+    }
+
+    #[test]
+    fn test_exp_bit_patterns() {
+        let math = ModMath::new(secp256k1_p());
+        let base = U256::from(7);
+        let patterns = [
+            U256::zero(),
+            U256::one(),
+            U256::from(0b1011), // leading zeros
+            U256::one() << 255,
+            U256::max_value(),
+        ];
+        for exponent in patterns {
+            let mut exponent_be = [0u8; 32];
+            exponent.to_big_endian(&mut exponent_be);
+            assert_eq!(math.exp(base, exponent), math.exp_bytes(base, &exponent_be), "{}", exponent);
+        }
+        assert_eq!(math.exp(base, U256::from(0b1011)), U256::from(7_u64.pow(11)));
+    }
+
+    #[test]
+    fn test_string_helpers() {
+        let max = U256::max_value();
+        assert_eq!(ModMath::to_dec_string(U256::zero()), "0");
+        assert_eq!(ModMath::to_dec_string(U256::one()), "1");
+        assert_eq!(ModMath::to_dec_string(U256::from(255)), "255");
+        assert_eq!(
+            ModMath::to_dec_string(max),
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
+
+        assert_eq!(ModMath::to_hex_string(U256::zero(), false), "0x0");
+        assert_eq!(ModMath::to_hex_string(U256::one(), false), "0x1");
+        assert_eq!(ModMath::to_hex_string(U256::from(255), false), "0xff");
+        assert_eq!(ModMath::to_hex_string(max, false), format!("0x{}", "f".repeat(64)));
+
+        assert_eq!(ModMath::to_hex_string(U256::zero(), true), format!("0x{}", "0".repeat(64)));
+        assert_eq!(ModMath::to_hex_string(U256::one(), true), format!("0x{}1", "0".repeat(63)));
+        assert_eq!(ModMath::to_hex_string(U256::from(255), true), format!("0x{}ff", "0".repeat(62)));
+        assert_eq!(ModMath::to_hex_string(max, true), ModMath::to_hex_string(max, false));
+    }
+
+    #[test]
+    fn test_string_round_trip() {
+        let mut rng = StdRng::seed_from_u64(21);
+        for i in 0..200 {
+            let mut bytes = [0_u8; 32];
+            rng.fill_bytes(&mut bytes);
+            // vary the width so short values are covered too
+            let a = U256::from_big_endian(&bytes) >> (i % 256);
+            assert_eq!(ModMath::to_dec_string(a).as_str().into_u256(), a);
+            assert_eq!(ModMath::to_hex_string(a, false).as_str().into_u256(), a);
+            assert_eq!(ModMath::to_hex_string(a, true).as_str().into_u256(), a);
+        }
+    }
+
+    #[test]
+    fn test_exp_shared_exponent() {
+        let math = ModMath::new(secp256k1_p());
+        let mut rng = StdRng::seed_from_u64(23);
+        let mut random = || {
+            let mut bytes = [0_u8; 32];
+            rng.fill_bytes(&mut bytes);
+            U256::from_big_endian(&bytes)
+        };
+        let mut bases: Vec<U256> = (0..20).map(|_| random()).collect();
+        bases.extend([U256::zero(), U256::one(), secp256k1_p()]);
+
+        for exponent in [random(), U256::zero(), U256::one(), U256::max_value()] {
+            let expected: Vec<U256> = bases.iter().map(|&base| math.exp(base, exponent)).collect();
+            assert_eq!(math.exp_shared_exponent(&bases, exponent), expected);
+            #[cfg(feature = "rayon")]
+            assert_eq!(math.par_exp_shared_exponent(&bases, exponent), expected);
+        }
+        assert!(math.exp_shared_exponent(&[], U256::from(3)).is_empty());
+    }
+
+    #[test]
+    fn test_exp_many() {
+        let mut rng = StdRng::seed_from_u64(966);
+        let mut random = || {
+            let mut bytes = [0_u8; 32];
+            rng.fill_bytes(&mut bytes);
+            U256::from_big_endian(&bytes)
+        };
+        for modulus in [secp256k1_p(), U256::from(101), U256::one()] {
+            let math = ModMath::new(modulus);
+            let mut bases: Vec<U256> = (0..20).map(|_| random()).collect();
+            bases.extend([U256::zero(), U256::one(), modulus]);
+
+            for exponent in [random(), U256::zero(), U256::one(), U256::from(16), U256::max_value()] {
+                let expected: Vec<U256> = bases.iter().map(|&base| math.exp(base, exponent)).collect();
+                assert_eq!(math.exp_many(&bases, exponent), expected);
+                #[cfg(feature = "rayon")]
+                assert_eq!(math.par_exp_many(&bases, exponent), expected);
+                assert_eq!(math.exp_many(&bases[..1], exponent), vec![expected[0]]);
+            }
+            assert!(math.exp_many(&[], random()).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_exp_signed() {
+        let p = secp256k1_p();
+        let math = ModMath::new(p);
+        let mut rng = StdRng::seed_from_u64(27);
+        let mut random = || {
+            let mut bytes = [0_u8; 32];
+            rng.fill_bytes(&mut bytes);
+            U256::from_big_endian(&bytes)
+        };
+        for _ in 0..10 {
+            let (g, x) = (random() % p, random());
+            let positive = math.exp_signed(g, false, x).unwrap();
+            let negative = math.exp_signed(g, true, x).unwrap();
+            assert_eq!(positive, math.exp(g, x));
+            assert_eq!(math.mul(positive, negative), U256::one());
+        }
+
+        // 6 shares a factor with 15, so only non-negative powers exist
+        let composite = ModMath::new(15);
+        assert_eq!(composite.exp_signed(U256::from(6), true, U256::from(2)), None);
+        assert_eq!(composite.exp_signed(U256::from(6), false, U256::from(2)), Some(U256::from(6)));
+        assert_eq!(composite.exp_signed(U256::from(2), true, U256::from(2)), Some(U256::from(4)));
+        assert_eq!(composite.exp_signed(U256::from(6), true, U256::zero()), Some(U256::one()));
+    }
+
+    #[test]
+    fn test_random_residues() {
+        let mut rng = StdRng::seed_from_u64(28);
+        // p = 3, 5 and 1 (mod 8), so every square root algorithm gets exercised
+        for p in [U256::from(103), U256::from(101), U256::from(97), secp256k1_p()] {
+            let math = ModMath::new(p);
+            for _ in 0..20 {
+                let residue = math.random_residue(&mut rng);
+                assert!(residue != U256::zero() && residue < p);
+                assert!(math.is_quadratic_residue(residue));
+                assert!(math.sqrt(residue).is_ok());
+
+                let non_residue = math.random_non_residue(&mut rng);
+                assert!(non_residue != U256::zero() && non_residue < p);
+                assert!(!math.is_quadratic_residue(non_residue));
+                assert_eq!(math.sqrt(non_residue), Err(SqrtError::NonResidue));
+            }
+        }
+        assert!(!ModMath::new(101).is_quadratic_residue(0));
+        assert_eq!(ModMath::new(1).random_residue(&mut rng), U256::zero());
+    }
+
+    #[test]
+    fn test_inv_detailed() {
+        // 360 = 2^3 * 3^2 * 5
+        let math = ModMath::new(360);
+        assert_eq!(math.inv_detailed(0), Err(InvError::Zero));
+        assert_eq!(math.inv_detailed(720), Err(InvError::Zero));
+        assert_eq!(math.inv_detailed(6), Err(InvError::NotCoprime(U256::from(6))));
+        assert_eq!(math.inv_detailed(45), Err(InvError::NotCoprime(U256::from(45))));
+        assert_eq!(math.inv_detailed(365), Err(InvError::NotCoprime(U256::from(5))));
+        for a in 1..360u64 {
+            match math.inv_detailed(a) {
+                Ok(inverse) => assert_eq!(Some(inverse), math.inv(a)),
+                Err(error) => {
+                    assert_eq!(math.inv(a), None);
+                    assert!(matches!(error, InvError::NotCoprime(g) if g > U256::one() && U256::from(360) % g == U256::zero()));
+                }
+            }
+        }
+        assert_eq!(ModMath::new(101).inv_detailed(1), Ok(U256::one()));
+    }
+
+    #[test]
+    fn test_is_probable_prime() {
+        for prime in [2u64, 3, 71, 73, 101, 7919, 18446744073709551557] {
+            assert!(ModMath::is_probable_prime(U256::from(prime)), "{}", prime);
+        }
+        assert!(ModMath::is_probable_prime(secp256k1_p()));
+        // Carmichael numbers and strong pseudoprimes to the first bases
+        for composite in [0u64, 1, 4, 15, 561, 1105, 2047, 3215031751, 3825123056546413051] {
+            assert!(!ModMath::is_probable_prime(U256::from(composite)), "{}", composite);
+        }
+        assert!(!ModMath::is_probable_prime(secp256k1_p() - U256::from(2)));
+        assert!(!ModMath::is_probable_prime(U256::from(18446744073709551557_u64) * U256::from(7919)));
+
+        assert!(ModMath::new(101).is_prime_modulus());
+        assert!(!ModMath::new(15).is_prime_modulus());
+        // new_prime takes the caller's word for it
+        assert!(ModMath::new_prime(101).is_prime_modulus());
+    }
+
+    #[test]
+    fn test_prime_only_operations_on_composite_moduli() {
+        let math = ModMath::new(15);
+        // 4 has the roots 2, 7, 8 and 13 mod 15, which the prime algorithms cannot find
+        let root = math.sqrt_opt(4);
+        assert!(root.is_none() || [2u64, 7, 8, 13].iter().any(|&r| root == Some(U256::from(r))));
+        for a in 0..15u64 {
+            if let Some(root) = math.sqrt_opt(a) {
+                assert_eq!(math.square(root), U256::from(a));
+            }
+        }
+        assert_eq!(math.legendre(4), Err(ArithError::NotPrime));
+        assert_eq!(math.inv_prime(2), Err(ArithError::NotPrime));
+        assert_eq!(math.inv(2), Some(U256::from(8)));
+
+        let prime = ModMath::new(101);
+        assert_eq!(prime.legendre(0), Ok(0));
+        assert_eq!(prime.legendre(4), Ok(1));
+        assert_eq!(prime.legendre(2), Ok(-1));
+        assert_eq!(prime.inv_prime(0), Err(ArithError::NotInvertible));
+        for a in 1..101u64 {
+            assert_eq!(prime.inv_prime(a).ok(), prime.inv(a));
+        }
+    }
+
+    #[test]
+    fn test_montgomery_batch_round_trip() {
+        let mut rng = StdRng::seed_from_u64(34);
+        // BN128's p, and moduli just below 2^256 where the reduction carries out of a U512
+        let bn128_p = U256::from_dec_str("21888242871839275222246405745257275088696311157297823662689037894645226208583").unwrap();
+        for modulus in [U256::from(101), secp256k1_p(), bn128_p, U256::max_value(), U256::max_value() - U256::from(188)] {
+            let mont = Montgomery::new(modulus).unwrap();
+            let math = ModMath::new(modulus);
+            let mut values: Vec<U256> = (0..16)
+                .map(|_| {
+                    let mut bytes = [0_u8; 32];
+                    rng.fill_bytes(&mut bytes);
+                    U256::from_big_endian(&bytes)
+                })
+                .collect();
+            values.extend([U256::zero(), U256::one(), modulus - U256::one(), modulus]);
+
+            let converted = mont.to_batch(&values);
+            let reduced: Vec<U256> = values.iter().map(|&x| math.reduce(x)).collect();
+            assert_eq!(mont.from_batch(&converted), reduced);
+            for (i, (&a, &b)) in converted.iter().zip(converted.iter().rev()).enumerate() {
+                let expected = math.mul(reduced[i], reduced[reduced.len() - 1 - i]);
+                assert_eq!(mont.from_montgomery(mont.mul(a, b)), expected);
+            }
+        }
+        assert!(Montgomery::new(U256::from(100)).is_none());
+        assert!(Montgomery::new(U256::one()).is_none());
+        assert!(Montgomery::new(U256::from(7)).unwrap().to_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_sqrt_normalized() {
+        let bn128_p = U256::from_dec_str("21888242871839275222246405745257275088696311157297823662689037894645226208583").unwrap();
+        for p in [secp256k1_p(), bn128_p] {
+            let math = ModMath::new_prime(p);
+            let half = p / 2;
+            for r in [2u64, 3, 12345, 987654321] {
+                let a = math.square(U256::from(r));
+                let roots = [U256::from(r), p - U256::from(r)];
+                let even = math.sqrt_normalized(a, RootConvention::EvenY).unwrap();
+                let odd = math.sqrt_normalized(a, RootConvention::OddY).unwrap();
+                let smaller = math.sqrt_normalized(a, RootConvention::Smaller).unwrap();
+                let larger = math.sqrt_normalized(a, RootConvention::Larger).unwrap();
+                for root in [even, odd, smaller, larger] {
+                    assert!(roots.contains(&root));
+                    assert_eq!(math.square(root), a);
+                }
+                assert!(!even.bit(0) && odd.bit(0));
+                assert!(smaller <= half && larger > half);
+            }
+            assert_eq!(math.sqrt_normalized(0, RootConvention::OddY), Some(U256::zero()));
+        }
+        assert_eq!(ModMath::new(13).sqrt_normalized(5, RootConvention::EvenY), None);
+    }
+
+    #[test]
+    fn test_is_square() {
+        // 2 mod 15 has Jacobi symbol 1 but is a square neither mod 3 nor mod 5
+        assert!(!ModMath::new(15).is_square(2));
+        assert!(!ModMath::new(15).is_square(8));
+        // brute force every residue under composite moduli, including powers of two and p^2
+        for n in [15u64, 16, 45, 63, 72, 96, 98, 121, 360] {
+            let math = ModMath::new(n);
+            let squares: Vec<u64> = (0..n).map(|x| x * x % n).collect();
+            for a in 0..n {
+                assert_eq!(math.is_square(a), squares.contains(&a), "{} mod {}", a, n);
+            }
+        }
+        // a prime modulus agrees with the Legendre symbol
+        let math = ModMath::new(101);
+        for a in 1..101u64 {
+            assert_eq!(math.is_square(a), math.is_quadratic_residue(a));
+        }
+    }
+
+    #[test]
+    fn test_is_quadratic_residue_composite() {
+        let gcd = |mut a: u64, mut b: u64| {
+            while b != 0 {
+                (a, b) = (b, a % b);
+            }
+            a
+        };
+        // the squares of the units, against Euler's criterion, which is wrong for 561
+        for n in [15u64, 16, 45, 63, 72, 98, 121, 360, 561] {
+            let math = ModMath::new(n);
+            let residues: Vec<u64> = (1..n).filter(|&x| gcd(x, n) == 1).map(|x| x * x % n).collect();
+            for a in 0..n {
+                assert_eq!(math.is_quadratic_residue(a), residues.contains(&a), "{} mod {}", a, n);
+            }
+        }
+        // 2^280 = 1 (mod 561), but 2 is not a square mod 3
+        assert_eq!(ModMath::new(561).euler_criterion(2), U256::one());
+        assert!(!ModMath::new(561).is_quadratic_residue(2));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "15 is not prime")]
+    fn test_random_non_residue_composite() {
+        ModMath::new(15).random_non_residue(&mut StdRng::seed_from_u64(945));
+    }
+
+    #[test]
+    #[cfg(feature = "instrumentation")]
+    fn test_exp_operation_counts() {
+        let math = ModMath::new(secp256k1_p());
+        let exponent = secp256k1_p() - U256::from(2);
+        math.exp(U256::from(3), exponent);
+        let counters = math.counters();
+        assert_eq!(counters.squares, 256);
+        assert!(counters.muls <= 256);
+        assert_eq!(counters.muls, exponent.0.iter().map(|limb| limb.count_ones() as u64).sum::<u64>());
+        assert_eq!(counters.inversions, 0);
+
+        math.reset_counters();
+        math.div(U256::from(3), U256::from(5));
+        assert_eq!(math.counters().inversions, 1);
+        math.reset_counters();
+        math.add(U256::from(3), U256::from(5));
+        math.sub(U256::from(3), U256::from(5));
+        assert_eq!(math.counters(), crate::mod_math::OpCounters { additions: 2, ..Default::default() });
+    }
+
+    #[test]
+    fn test_gcd_lcm_many() {
+        let values = |v: &[u64]| v.iter().map(|&x| U256::from(x)).collect::<Vec<_>>();
+        assert_eq!(ModMath::gcd_many(&values(&[12, 18, 24])), U256::from(6));
+        assert_eq!(ModMath::gcd_many(&values(&[0, 9, 0])), U256::from(9));
+        assert_eq!(ModMath::gcd_many(&[]), U256::zero());
+        assert_eq!(ModMath::lcm_many(&values(&[12, 18, 24])), U256::from(72));
+        assert_eq!(ModMath::lcm_many(&values(&[3, 0, 5])), U256::zero());
+        assert_eq!(ModMath::lcm_many(&[]), U256::one());
+    }
+
+    #[test]
+    fn test_pow_product() {
+        let p = secp256k1_p();
+        let math = ModMath::new(p);
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut random = || {
+            let mut bytes = [0u8; 32];
+            rng.fill_bytes(&mut bytes);
+            U256::from_big_endian(&bytes)
+        };
+        let pairs: Vec<(U256, U256)> = (0..4).map(|_| (random(), random())).collect();
+        let naive = pairs.iter().fold(U256::one(), |acc, &(base, exponent)| math.mul(acc, math.exp(base, exponent)));
+        assert_eq!(math.pow_product(&pairs), naive);
+
+        // exponents of different lengths, and zero exponents
+        let pairs = [(U256::from(5), U256::from(3)), (random(), U256::zero()), (U256::from(7), p - U256::from(2))];
+        let naive = math.mul(U256::from(125), math.inv(U256::from(7)).unwrap());
+        assert_eq!(math.pow_product(&pairs), naive);
+        assert_eq!(math.pow_product(&[]), U256::one());
+    }
+
+    #[test]
+    fn test_fp_operators() {
+        let p = secp256k1_p();
+        let math = ModMath::new(p);
+        let (a, b, c) = (math.elem(p - U256::one()), math.elem(2), math.elem(p + U256::from(3)));
+        assert_eq!(c.value(), U256::from(3));
+
+        // (a + b) * c - a / b, step by step with ModMath
+        let expected = math.sub(math.mul(math.add(p - U256::one(), U256::from(2)), U256::from(3)), math.div(p - U256::one(), U256::from(2)));
+        assert_eq!(((a + b) * c - a / b).value(), expected);
+        assert_eq!(-a, math.elem(1));
+        assert_eq!(a / b * b, a);
+        assert_eq!(a - a, math.elem(0));
+
+        // a second ModMath for the same modulus is compatible
+        let same = ModMath::new(p);
+        assert_eq!(a + same.elem(1), math.elem(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot combine values under different moduli")]
+    fn test_fp_different_moduli() {
+        let (m1, m2) = (ModMath::new(13), ModMath::new(17));
+        let _ = m1.elem(1) + m2.elem(1);
+    }
+
+    #[test]
+    fn test_int_divmod() {
+        assert_eq!(ModMath::int_divmod(U256::from(17), U256::from(5)), (U256::from(3), U256::from(2)));
+        assert_eq!(ModMath::int_divmod(U256::from(4), U256::from(5)), (U256::zero(), U256::from(4)));
+        assert_eq!(ModMath::int_divmod(U256::MAX, U256::one()), (U256::MAX, U256::zero()));
+        assert_eq!(ModMath::int_divmod(U256::MAX, U256::MAX), (U256::one(), U256::zero()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Division by zero error")]
+    fn test_int_divmod_by_zero() {
+        ModMath::int_divmod(U256::one(), U256::zero());
+    }
+
+    #[test]
+    fn test_mixed_operand_types() {
+        let math = ModMath::new(101);
+        assert_eq!(math.add(U256::from(100), "5"), U256::from(4));
+        assert_eq!(math.sub(3u64, "0x05"), U256::from(99));
+        assert_eq!(math.mul("10", 11u32), U256::from(9));
+        assert!(math.eq(U256::from(12345), "12345"));
+        assert!(math.eq("102", 1u64));
+    }
+
+    #[test]
+    fn test_try_operations() {
+        let math = ModMath::new(101);
+        assert_eq!(math.try_add("100", 5u64), Ok(U256::from(4)));
+        assert_eq!(math.try_sub(3u64, "0x05"), Ok(U256::from(99)));
+        assert_eq!(math.try_mul("10", U256::from(11)), Ok(U256::from(9)));
+        assert_eq!(math.try_eq(U256::from(12345), "12345"), Ok(true));
+
+        let err = math.try_eq(U256::from(5), "12a45").unwrap_err();
+        assert_eq!(err.input(), "12a45");
+        assert_eq!(err.to_string(), "\"12a45\" is not a number in [0, 2^256)");
+        assert_eq!(math.try_add(-1i64, 1u64).unwrap_err().input(), "-1");
+        assert_eq!(math.try_mul("0xzz", 1u64).unwrap_err().input(), "0xzz");
+        let too_big = "1".repeat(80);
+        assert_eq!(math.try_sub(too_big.as_str(), 1u64).unwrap_err().input(), too_big);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_malformed_string_panics() {
+        ModMath::new(101).eq(U256::from(5), "12a45");
+    }
+
+    #[test]
+    fn test_clone_shares_field_params() {
+        let p = U256::from_dec_str("21888242871839275222246405745257275088696311157297823662689037894645226208583").unwrap();
+        let before = FIELD_PARAMS_BUILT.with(|count| count.get());
+        let math = ModMath::new(p);
+        let copies: Vec<ModMath> = (0..10).map(|_| math.clone()).collect();
+        assert_eq!(FIELD_PARAMS_BUILT.with(|count| count.get()), before + 1);
+        assert!(copies.iter().all(|copy| std::sync::Arc::ptr_eq(copy.params(), math.params())));
+        assert_eq!(math.params().modulus(), p);
+    }
+
+    #[test]
+    fn test_with_modulus_matches_new() {
+        let secp256k1_p = secp256k1_p();
+        let bn128_p = U256::from_dec_str("21888242871839275222246405745257275088696311157297823662689037894645226208583").unwrap();
+        let (a, b) = (U256::MAX - 12345, U256::from(0xdead_beef_u64) << 100);
+        let math = ModMath::new(101);
+        for modulus in [U256::from(2), U256::from(18446744073709551557_u64), secp256k1_p, bn128_p, U256::MAX] {
+            let derived = math.with_modulus(modulus);
+            let fresh = ModMath::new(modulus);
+            assert_eq!(derived.modulus, modulus);
+            assert_eq!(derived.params().reduction, fresh.params().reduction);
+            assert_eq!(derived.add(a, b), fresh.add(a, b));
+            assert_eq!(derived.sub(a, b), fresh.sub(a, b));
+            assert_eq!(derived.mul(a, b), fresh.mul(a, b));
+            assert_eq!(derived.exp(a, b), fresh.exp(a, b));
+            assert_eq!(derived.inv(a), fresh.inv(a));
+            assert_eq!(derived.is_prime_modulus(), fresh.is_prime_modulus());
+        }
+
+        // the derived instance does not keep anything cached for the old modulus
+        let prime = ModMath::new_prime(101);
+        assert!(!prime.with_modulus(91).is_prime_modulus());
+        let before = FIELD_PARAMS_BUILT.with(|count| count.get());
+        assert!(prime.with_modulus(101).is_prime_modulus());
+        assert_eq!(FIELD_PARAMS_BUILT.with(|count| count.get()), before);
+    }
+
+    #[test]
+    #[should_panic(expected = "Modulus Cannot be Zero")]
+    fn test_with_modulus_zero() {
+        ModMath::new(101).with_modulus(0);
+    }
+
+    #[test]
+    fn test_clones_agree_across_threads() {
+        let math = ModMath::new(U256::from_dec_str("115792089237316195423570985008687907853269984665640564039457584007908834671663").unwrap());
+        let (a, b) = (U256::from(0xdead_beef_u64), U256::MAX - 12345);
+        let expected = (math.mul(a, b), math.exp(a, b), math.inv(a));
+        let handles: Vec<_> = (0..4).map(|_| {
+            let math = math.clone();
+            std::thread::spawn(move || (math.mul(a, b), math.exp(a, b), math.inv(a)))
+        }).collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_exp_modulus_one() {
+        let math = ModMath::new(1);
+        assert_eq!(math.exp(5, 3), U256::zero());
+        assert_eq!(math.exp(5, 0), U256::zero());
+        assert_eq!(math.exp_bytes(5, &[]), U256::zero());
+        assert_eq!(math.exp_u512(5, U512::zero()), U256::zero());
+    }
+
+    #[test]
+    fn test_exp_blinded_matches_exp() {
+        let p = U256::from_dec_str("21888242871839275222246405745257275088696311157297823662689037894645226208583").unwrap();
+        let math = ModMath::new(p);
+        let mut rng = StdRng::seed_from_u64(962);
+        let exponent = U256::from_dec_str("1234567890123456789012345678901234567890").unwrap();
+        let mut exponents = Vec::new();
+        for base in 2u64..22 {
+            let blinded = math.exp_blinded(base, exponent, p - 1, &mut rng);
+            assert_eq!(blinded, math.exp(U256::from(base), exponent));
+            exponents.push(LAST_BLINDED_EXPONENT.with(|last| last.get()));
+        }
+        // every call used a different exponent, all congruent to the secret one
+        for (i, e) in exponents.iter().enumerate() {
+            assert_eq!(*e % U512::from(p - 1), U512::from(exponent));
+            assert!(exponents[i + 1..].iter().all(|other| other != e));
+        }
+    }
+
+    #[test]
+    fn test_euler_criterion_gives_legendre_symbol() {
+        for p in [3u64, 7, 13, 101, 257] {
+            let math = ModMath::new(p);
+            let squares: Vec<u64> = (1..p).map(|x| x * x % p).collect();
+            for a in 0..2 * p {
+                let expected = if a % p == 0 { 0 } else if squares.contains(&(a % p)) { 1 } else { -1 };
+                let symbol = match math.euler_criterion(a) {
+                    value if value == U256::zero() => 0,
+                    value if value == U256::one() => 1,
+                    value if value == U256::from(p - 1) => -1,
+                    value => panic!("{} is not a Legendre symbol value mod {}", value, p),
+                };
+                assert_eq!(symbol, expected, "a = {}, p = {}", a, p);
+            }
+        }
+        let secp256k1_p = U256::from_dec_str("115792089237316195423570985008687907853269984665640564039457584007908834671663").unwrap();
+        let math = ModMath::new(secp256k1_p);
+        assert_eq!(math.euler_criterion(math.square(U256::from(12345))), U256::one());
+        assert_eq!(math.euler_criterion(math.random_non_residue(&mut StdRng::seed_from_u64(963))), secp256k1_p - 1);
+    }
+
+    #[test]
+    fn test_exp_ct_matches_exp() {
+        let secp256k1_p = U256::from_dec_str("115792089237316195423570985008687907853269984665640564039457584007908834671663").unwrap();
+        let mut rng = StdRng::seed_from_u64(965);
+        for modulus in [U256::one(), U256::from(2), U256::from(101), U256::from(1u64 << 40), secp256k1_p] {
+            let math = ModMath::new(modulus);
+            for _ in 0..8 {
+                let (mut base, mut exponent) = ([0u8; 32], [0u8; 32]);
+                rng.fill_bytes(&mut base);
+                rng.fill_bytes(&mut exponent);
+                let (base, exponent) = (U256::from_big_endian(&base), U256::from_big_endian(&exponent));
+                assert_eq!(math.exp_ct(base, exponent), math.exp(base, exponent));
+            }
+            assert_eq!(math.exp_ct(U256::from(7), U256::zero()), math.exp(7, 0));
+            assert_eq!(math.exp_ct(U256::from(7), U256::MAX), math.exp(U256::from(7), U256::MAX));
+        }
+    }
+
+    #[test]
+    fn test_sqrt_ct_matches_sqrt() {
+        let secp256k1_p = U256::from_dec_str("115792089237316195423570985008687907853269984665640564039457584007908834671663").unwrap();
+        // 103 = 3 (mod 4) takes the constant-time path, 101 and 17 fall back to sqrt
+        for modulus in [103u64, 101, 17] {
+            let math = ModMath::new(modulus);
+            for a in 0..2 * modulus {
+                assert_eq!(math.sqrt_ct(a), math.sqrt_opt(a), "a = {}, p = {}", a, modulus);
+            }
+        }
+        let math = ModMath::new(secp256k1_p);
+        let mut rng = StdRng::seed_from_u64(9652);
+        for _ in 0..20 {
+            let a = U256::from(rng.next_u64()) * U256::from(rng.next_u64());
+            assert_eq!(math.sqrt_ct(a), math.sqrt_opt(a));
+        }
+        assert_eq!(ModMath::new(15).sqrt_ct(4), None);
+    }
+
+    #[test]
+    fn test_modulus_hex_round_trip() {
+        let math = ModMath::new(secp256k1_p());
+        let hex = math.modulus_hex();
+        assert_eq!(hex, "0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+        let reloaded = ModMath::from_modulus_hex(&hex).unwrap();
+        assert_eq!(reloaded.modulus_hex(), hex);
+        assert_eq!(reloaded.params().modulus(), secp256k1_p());
+        assert_eq!(reloaded.mul(U256::MAX, 3u64), math.mul(U256::MAX, 3u64));
+        // the prefix is optional and either case reads back the same field
+        assert_eq!(ModMath::from_modulus_hex(&hex[2..].to_uppercase()).unwrap().modulus_hex(), hex);
+
+        assert!(matches!(ModMath::from_modulus_hex("0x"), Err(crate::Error::Parse(_))));
+        assert!(matches!(ModMath::from_modulus_hex("0x12z"), Err(crate::Error::Parse(_))));
+        assert!(matches!(ModMath::from_modulus_hex(&format!("{}0", hex)), Err(crate::Error::Parse(_))));
+        assert!(matches!(ModMath::from_modulus_hex("0x000"), Err(crate::Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_slice_operations() {
+        let mut rng = StdRng::seed_from_u64(9682);
+        for modulus in [U256::from(13), U256::from(1u64 << 40), secp256k1_p()] {
+            let math = ModMath::new(modulus);
+            let mut random = || {
+                let mut bytes = [0_u8; 32];
+                rng.fill_bytes(&mut bytes);
+                U256::from_big_endian(&bytes)
+            };
+            let a: Vec<U256> = (0..17).map(|_| random()).collect();
+            let b: Vec<U256> = (0..17).map(|_| random()).collect();
+            let mut out = vec![U256::MAX; 17];
+
+            math.add_slices(&a, &b, &mut out);
+            assert!(out.iter().zip(a.iter().zip(&b)).all(|(&o, (&x, &y))| o == math.add(x, y)));
+            math.sub_slices(&a, &b, &mut out);
+            assert!(out.iter().zip(a.iter().zip(&b)).all(|(&o, (&x, &y))| o == math.sub(x, y)));
+            math.mul_slices(&a, &b, &mut out);
+            assert!(out.iter().zip(a.iter().zip(&b)).all(|(&o, (&x, &y))| o == math.mul(x, y)));
+            math.mul_slices(&[], &[], &mut []);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot combine slices of different lengths: 2, 3 and 2")]
+    fn test_slice_operations_length_mismatch() {
+        let math = ModMath::new(13);
+        math.add_slices(&[U256::one(); 2], &[U256::one(); 3], &mut [U256::zero(); 2]);
+    }
+
+    #[test]
+    fn test_exp_fermat_reduced() {
+        let p = secp256k1_p();
+        let math = ModMath::new(p);
+        let base = U256::from_dec_str("55066263022277343669578718895168534326250603453777594175500187360389116729240").unwrap();
+        let mut rng = StdRng::seed_from_u64(970);
+        // a 4096-bit exponent
+        let mut exponent = [0u8; 512];
+        rng.fill_bytes(&mut exponent);
+        assert_eq!(math.exp_fermat_reduced(base, &exponent), math.exp_bytes(base, &exponent));
+
+        // exponents that are multiples of p - 1, plus one
+        let mut multiple = [0u8; 64];
+        (U512::from(p - 1) * U512::from(u128::MAX) + U512::one()).to_big_endian(&mut multiple);
+        assert_eq!(math.exp_fermat_reduced(base, &multiple), base);
+        assert_eq!(math.exp_fermat_reduced(base, &[]), U256::one());
+
+        // falls back to direct exponentiation for a base divisible by p and a composite modulus
+        assert_eq!(math.exp_fermat_reduced(p, &exponent), U256::zero());
+        assert_eq!(math.exp_fermat_reduced(p, &[0]), U256::one());
+        let composite = ModMath::new(91);
+        assert_eq!(composite.exp_fermat_reduced(5, &exponent), composite.exp_bytes(5, &exponent));
+        assert_eq!(ModMath::new(1).exp_fermat_reduced(5, &exponent), U256::zero());
+        assert_eq!(ModMath::new(2).exp_fermat_reduced(3, &exponent), U256::one());
+    }
+
+    #[test]
+    fn test_cube_and_cube_root() {
+        // primes p with p = 2 (mod 3), and p = 3
+        let mut rng = StdRng::seed_from_u64(9722);
+        let p256 = U256::MAX - 586; // 2^256 - 587
+        for p in [U256::from(2), U256::from(3), U256::from(11), U256::from(18446744073709551557_u64), p256] {
+            let math = ModMath::new(p);
+            for _ in 0..20 {
+                let mut bytes = [0u8; 32];
+                rng.fill_bytes(&mut bytes);
+                let a = U256::from_big_endian(&bytes);
+                assert_eq!(math.cube(a), math.mul(math.square(a), a));
+                assert_eq!(math.cube_root(math.cube(a)), Some(a % p));
+                assert_eq!(math.cube(math.cube_root(a).unwrap()), a % p);
+            }
+        }
+
+        // p = 1 (mod 3) and composite moduli
+        assert_eq!(secp256k1_p() % 3, U256::one());
+        assert_eq!(ModMath::new(secp256k1_p()).cube_root(8), None);
+        assert_eq!(ModMath::new(35).cube_root(8), None);
+    }
+
+    #[test]
+    fn test_sub_signed() {
+        let math = ModMath::new(secp256k1_p());
+        let (small, large) = (U256::from(5), secp256k1_p() - 1);
+        assert_eq!(math.sub_signed(large, small), (large - small, false));
+        assert_eq!(math.sub_signed(small, large), (large - small, true));
+        assert_eq!(math.sub_signed(small, small), (U256::zero(), false));
+
+        // operands are reduced first, and the sign agrees with `sub`
+        let math = ModMath::new(13);
+        assert_eq!(math.sub_signed(16, 10), (U256::from(7), true));
+        for a in 0..30u32 {
+            for b in 0..30u32 {
+                let (magnitude, negative) = math.sub_signed(a, b);
+                let expected = if negative { math.add_inv(magnitude) } else { magnitude };
+                assert_eq!(math.sub(a, b), expected);
+                assert_eq!(negative, a % 13 < b % 13);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sqrt_errors() {
+        // 7 = 3 (mod 4), 101 = 5 (mod 8) and 17 = 1 (mod 8) cover the three algorithms
+        for (p, non_residue) in [(7u64, 3u64), (101, 2), (17, 3)] {
+            let math = ModMath::new(p);
+            assert_eq!(math.sqrt(non_residue), Err(SqrtError::NonResidue));
+            assert_eq!(math.sqrt_opt(non_residue), None);
+            assert_eq!(math.sqrt(0), Ok(U256::zero()));
+            // a multiple of a prime modulus is zero, whose root is zero
+            assert_eq!(math.sqrt(p), Ok(U256::zero()));
+        }
+
+        let math = ModMath::new(15);
+        assert_eq!(math.sqrt(6), Err(SqrtError::NotCoprime(U256::from(3))));
+        assert_eq!(math.sqrt(10), Err(SqrtError::NotCoprime(U256::from(5))));
+        // the Jacobi symbol (7/15) is -1
+        assert_eq!(math.sqrt(7), Err(SqrtError::NonResidue));
+        // 4 is a square mod 15, but the roots need the factors of 15
+        assert_eq!(math.sqrt(4), Err(SqrtError::NotPrime));
+        assert_eq!(math.sqrt(15), Ok(U256::zero()));
+        assert_eq!(ModMath::new(16).sqrt(3), Err(SqrtError::NotPrime));
+        assert_eq!(math.sqrt_opt(6), None);
+    }
+
+    #[test]
+    fn test_add_as_and_mul_as() {
+        // one call site, two output types
+        fn affine<O: FromU256>(math: &ModMath, x: U256) -> O {
+            let scaled: U256 = math.mul_as(x, 3);
+            math.add_as(scaled, 5)
+        }
+
+        let p = U256::from_dec_str("21888242871839275222246405745257275088548364400416034343698204186575808495617").unwrap();
+        let math = ModMath::new(p);
+        let x = p - 2;
+        let plain: U256 = affine(&math, x);
+        let number: NumberUnderMod = affine(&math, x);
+        assert_eq!(plain, p - 1);
+        assert_eq!(number, NumberUnderMod::new(p - 1, p));
+        assert_eq!(number.modulus(), p);
+
+        assert_eq!(math.add_as::<U256, _, _>(p, 1u64), math.add(p, 1u64));
+        assert_eq!(math.mul_as::<NumberUnderMod, _, _>(2u64, x).value(), math.mul(2u64, x));
+    }
+
+    #[test]
+    fn test_tonelli_shanks_non_residue() {
+        let bn128_r = U256::from_dec_str("21888242871839275222246405745257275088548364400416034343698204186575808495617").unwrap();
+        let primes = [U256::from(17), U256::from(41), U256::from(73), U256::from(97), U256::from(65537), U256::from(4294967311u64), bn128_r];
+        for p in primes {
+            let math = ModMath::new(p);
+            let q = math.tonelli_shanks_non_residue().unwrap();
+            assert_eq!(math.euler_criterion(q), p - 1, "q = {} mod {}", q, p);
+            assert_eq!(math.legendre(q), Ok(-1));
+            // the search starts at 2, so q is the least non-residue
+            for smaller in 2..q.as_u64() {
+                assert_eq!(math.legendre(smaller), Ok(1));
+            }
+        }
+        // the least non-residue of the BN128 group order is 5
+        assert_eq!(ModMath::new(bn128_r).tonelli_shanks_non_residue(), Ok(U256::from(5)));
+
+        // composites claimed prime: no q has q^((n-1)/2) = -1 under 561, so the search gives up
+        for n in [561u64, 1105, 65] {
+            let math = ModMath::new_prime(n);
+            assert_eq!(math.tonelli_shanks_non_residue(), Err(SqrtError::NotPrime));
+            assert_eq!(math.sqrt(1), Err(SqrtError::NotPrime));
+        }
+    }
+
+    #[cfg(feature = "instrumentation")]
+    #[test]
+    fn test_tonelli_shanks_search_exponentiations() {
+        // the candidates 2, 3 and 4 are tested without exponentiating, so the setup takes
+        // Euler's criterion for q = 5 and g = q^s, two exponentiations of about 254 squarings
+        let bn128_r = U256::from_dec_str("21888242871839275222246405745257275088548364400416034343698204186575808495617").unwrap();
+        let math = ModMath::new(bn128_r);
+        assert_eq!(math.tonelli_shanks_non_residue(), Ok(U256::from(5)));
+        let squares = math.counters().squares;
+        assert!(squares > 254 && squares < 2 * 256, "{} squarings", squares);
+    }
+
+    fn u512_low(x: U512) -> U256 {
+        let mut bytes = [0u8; 64];
+        x.to_big_endian(&mut bytes);
+        U256::from_big_endian(&bytes[32..])
+    }
+
     // #[test]
     // fn test_big_number_addition() {
     //     let math = ModMath::new(U256::max_value());
@@ -147,10 +1298,25 @@ mod tests {
     #[test]
     fn test_big_number_modulus() {
         let math = ModMath::new(U256::max_value());
-        let result = math.modulus(U256::max_value() - U256::from(10));
+        let result = math.reduce(U256::max_value() - U256::from(10));
         assert_eq!(result, U256::max_value() - U256::from(10));
     }
 
+    #[test]
+    #[allow(deprecated)]
+    fn test_reduce_matches_modulus() {
+        let math = ModMath::new(secp256k1_p());
+        for a in [U256::zero(), U256::from(7), secp256k1_p(), secp256k1_p() + U256::one(), U256::MAX] {
+            assert_eq!(math.reduce(a), math.modulus(a));
+        }
+        assert_eq!(ModMath::new(7).reduce(20u32), U256::from(6));
+
+        let wide = WideModMath::new(U512::from(secp256k1_p()) << 200);
+        for a in [U512::zero(), U512::from(secp256k1_p()), U512::MAX] {
+            assert_eq!(wide.reduce(a), wide.modulus(a));
+        }
+    }
+
     // U256 Tests
     
 
@@ -0,0 +1,111 @@
+use primitive_types::{U256, U512};
+
+use super::ModMath;
+
+/// `Montgomery` does modular multiplication in Montgomery form, with `R = 2^256`.
+///
+/// A value `x` is represented as `x * R mod n`. Multiplying two such values with `mul`
+/// takes no division by the modulus, which pays off over many multiplications once the
+/// values have been converted with `to_montgomery` or `to_batch`.
+///
+/// # Examples
+///
+/// ```
+/// use modular_math::mod_math::Montgomery;
+/// use primitive_types::U256;
+///
+/// let mont = Montgomery::new(U256::from(101)).unwrap();
+/// let (a, b) = (mont.to_montgomery(U256::from(20)), mont.to_montgomery(U256::from(30)));
+/// assert_eq!(mont.from_montgomery(mont.mul(a, b)), U256::from(600 % 101));
+/// ```
+pub struct Montgomery {
+    modulus: U256,
+    // -modulus^-1 mod R
+    n_prime: U256,
+    // R^2 mod modulus
+    r_squared: U256,
+}
+
+impl Montgomery {
+    /// Creates a new `Montgomery` for the modulus.
+    ///
+    /// Returns `None` unless the modulus is odd and greater than one, since `R` must be
+    /// invertible modulo it.
+    pub fn new(modulus: U256) -> Option<Self> {
+        if modulus <= U256::one() || !modulus.bit(0) {
+            return None;
+        }
+        // Newton's iteration doubles the number of correct low bits of modulus^-1,
+        // starting from the 3 bits that n * n = 1 (mod 8) gives for odd n
+        let mut inverse = modulus;
+        for _ in 0..7 {
+            let correction = U256::from(2).overflowing_sub(modulus.overflowing_mul(inverse).0).0;
+            inverse = inverse.overflowing_mul(correction).0;
+        }
+        let n_prime = U256::zero().overflowing_sub(inverse).0;
+
+        let math = ModMath::new(modulus);
+        // R mod n = (R - 1) mod n + 1
+        let r = math.add(U256::max_value() % modulus, U256::one());
+        Some(Montgomery { modulus, n_prime, r_squared: math.mul(r, r) })
+    }
+
+    /// Returns the modulus.
+    pub fn modulus(&self) -> U256 {
+        self.modulus
+    }
+
+    /// Multiplies two values in Montgomery form, giving `a * b * R^-1 mod n`.
+    pub fn mul(&self, a: U256, b: U256) -> U256 {
+        self.redc(a.full_mul(b))
+    }
+
+    /// Converts a value to Montgomery form, `x * R mod n`.
+    pub fn to_montgomery(&self, x: U256) -> U256 {
+        self.mul(x % self.modulus, self.r_squared)
+    }
+
+    /// Converts a value from Montgomery form back to `[0, n)`.
+    pub fn from_montgomery(&self, x: U256) -> U256 {
+        self.redc(U512::from(x))
+    }
+
+    /// Converts every value to Montgomery form, see `to_montgomery`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::Montgomery;
+    /// use primitive_types::U256;
+    ///
+    /// let mont = Montgomery::new(U256::from(101)).unwrap();
+    /// let values = vec![U256::from(3), U256::from(50), U256::from(202)];
+    /// let converted = mont.to_batch(&values);
+    /// assert_eq!(mont.from_batch(&converted), vec![U256::from(3), U256::from(50), U256::zero()]);
+    /// ```
+    pub fn to_batch(&self, values: &[U256]) -> Vec<U256> {
+        values.iter().map(|&x| self.to_montgomery(x)).collect()
+    }
+
+    /// Converts every value back from Montgomery form, see `from_montgomery`.
+    pub fn from_batch(&self, values: &[U256]) -> Vec<U256> {
+        values.iter().map(|&x| self.from_montgomery(x)).collect()
+    }
+
+    // Montgomery reduction: t * R^-1 mod n for t < n * R
+    fn redc(&self, t: U512) -> U256 {
+        let m = low_u256(t).overflowing_mul(self.n_prime).0;
+        // t + m * n is divisible by R and below 2nR, which may not fit in a U512
+        let (sum, carry) = t.overflowing_add(m.full_mul(self.modulus));
+        let result = low_u256(sum >> 256);
+        if carry || result >= self.modulus {
+            result.overflowing_sub(self.modulus).0
+        } else {
+            result
+        }
+    }
+}
+
+fn low_u256(x: U512) -> U256 {
+    U256([x.0[0], x.0[1], x.0[2], x.0[3]])
+}
@@ -0,0 +1,105 @@
+//! Checks the exponentiation variants against `num-bigint` on edge-case bases and exponents.
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use primitive_types::{U256, U512};
+
+    use crate::mod_math::ModMath;
+
+    fn to_big(x: U256) -> BigUint {
+        let mut bytes = [0u8; 32];
+        x.to_big_endian(&mut bytes);
+        BigUint::from_bytes_be(&bytes)
+    }
+
+    fn from_big(x: &BigUint) -> U256 {
+        U256::from_big_endian(&x.to_bytes_be())
+    }
+
+    fn reference_exp(base: U256, exponent: U256, modulus: U256) -> U256 {
+        from_big(&to_big(base).modpow(&to_big(exponent), &to_big(modulus)))
+    }
+
+    fn moduli() -> Vec<U256> {
+        vec![
+            U256::from(2),
+            U256::from(3),
+            U256::from(100),
+            U256::from(18446744073709551557_u64),
+            U256::from_dec_str("115792089237316195423570985008687907853269984665640564039457584007908834671663").unwrap(),
+        ]
+    }
+
+    // 0, 1, m - 1, m, 2m + 3 (when it fits) and U256::MAX
+    fn edge_values(m: U256) -> Vec<U256> {
+        let mut values = vec![U256::zero(), U256::one(), m - 1, m];
+        values.extend(m.checked_mul(U256::from(2)).and_then(|x| x.checked_add(U256::from(3))));
+        values.push(U256::MAX);
+        values
+    }
+
+    fn edge_exponents(m: U256) -> Vec<U256> {
+        vec![U256::zero(), U256::one(), U256::from(2), m - 1, m, U256::MAX]
+    }
+
+    #[test]
+    fn test_exp_variants_match_reference() {
+        for m in moduli() {
+            let math = ModMath::new(m);
+            let bases = edge_values(m);
+            for e in edge_exponents(m) {
+                let mut exponent_be = [0u8; 32];
+                e.to_big_endian(&mut exponent_be);
+                let expected: Vec<U256> = bases.iter().map(|&b| reference_exp(b, e, m)).collect();
+
+                assert_eq!(math.exp_many(&bases, e), expected, "exp_many, m = {}, e = {}", m, e);
+                assert_eq!(math.exp_shared_exponent(&bases, e), expected, "exp_shared_exponent, m = {}, e = {}", m, e);
+                for (&b, &want) in bases.iter().zip(&expected) {
+                    let case = format!("m = {}, b = {}, e = {}", m, b, e);
+                    assert_eq!(math.exp(b, e), want, "exp, {}", case);
+                    assert_eq!(math.exp_bytes(b, &exponent_be), want, "exp_bytes, {}", case);
+                    assert_eq!(math.exp_u512(b, U512::from(e)), want, "exp_u512, {}", case);
+                    assert_eq!(math.exp_ct(b, e), want, "exp_ct, {}", case);
+                    assert_eq!(math.exp_signed(b, false, e), Some(want), "exp_signed, {}", case);
+                    assert_eq!(math.exp_fermat_reduced(b, &exponent_be), want, "exp_fermat_reduced, {}", case);
+                    assert_eq!(math.pow_product(&[(b, e)]), want, "pow_product, {}", case);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_exp_modulus_one_matches_reference() {
+        let math = ModMath::new(1);
+        for b in edge_values(U256::one()) {
+            for e in [U256::zero(), U256::one(), U256::MAX] {
+                assert_eq!(reference_exp(b, e, U256::one()), U256::zero());
+                assert_eq!(math.exp(b, e), U256::zero());
+                assert_eq!(math.exp_ct(b, e), U256::zero());
+                assert_eq!(math.exp_shared_exponent(&[b], e), vec![U256::zero()]);
+                assert_eq!(math.exp_many(&[b], e), vec![U256::zero()]);
+                assert_eq!(math.pow_product(&[(b, e)]), U256::zero());
+            }
+        }
+        assert_eq!(math.pow_product(&[]), U256::zero());
+    }
+
+    #[cfg(feature = "instrumentation")]
+    #[test]
+    fn test_exp_steps_match_bit_length() {
+        let math = ModMath::new(18446744073709551557_u64);
+        for e in [1u64, 2, 0xff, 0x100, 0x1_0000_0001] {
+            let exponent = U256::from(e);
+            math.reset_counters();
+            math.exp(U256::from(3), exponent);
+            assert_eq!(math.counters().squares, exponent.bits() as u64);
+
+            let mut exponent_be = [0u8; 32];
+            exponent.to_big_endian(&mut exponent_be);
+            math.reset_counters();
+            math.exp_bytes(U256::from(3), &exponent_be);
+            assert_eq!(math.counters().squares, exponent.bits() as u64);
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use primitive_types::U256;
+
+use super::{IntoU256, ModMath};
+
+impl ModMath {
+    /// Computes the geometric series `1 + r + r^2 + ... + r^(k-1)` under the modulus,
+    /// without looping over the `k` terms.
+    ///
+    /// Uses the closed form `(r^k - 1) / (r - 1)` when `r - 1` is invertible and gives
+    /// `k` for `r = 1`. Otherwise, which only happens under a composite modulus, the sum
+    /// is built by doubling the number of terms, `S(2n) = S(n) (1 + r^n)`, one step per
+    /// bit of `k`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// let math = ModMath::new(1000);
+    /// assert_eq!(math.geometric_series(2, U256::from(10)), U256::from(23)); // 1023
+    /// assert_eq!(math.geometric_series(1001, U256::from(1234)), U256::from(234)); // r = 1
+    /// ```
+    pub fn geometric_series<T: IntoU256>(&self, r: T, k: U256) -> U256 {
+        let r = self.reduce(r);
+        if r == self.reduce(U256::one()) {
+            return self.reduce(k);
+        }
+        if let Some(denominator) = self.inv(self.sub(r, U256::one())) {
+            return self.mul(self.sub(self.exp(r, k), U256::one()), denominator);
+        }
+
+        // (S(n), r^n) for the prefix n of the bits of k
+        let (mut sum, mut power) = (U256::zero(), self.reduce(U256::one()));
+        for i in (0..k.bits()).rev() {
+            sum = self.mul(sum, self.add(power, U256::one()));
+            power = self.square(power);
+            if k.bit(i) {
+                sum = self.add(self.mul(sum, r), U256::one());
+                power = self.mul(power, r);
+            }
+        }
+        sum
+    }
+
+    /// Computes the arithmetic series `a0 + (a0 + d) + ... + (a0 + (k-1) d)` under the
+    /// modulus, as `k a0 + d k (k - 1) / 2`.
+    ///
+    /// The halving is done on whichever of `k` and `k - 1` is even, before reducing, so
+    /// the result is right for even moduli too, where 2 has no inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// let math = ModMath::new(100);
+    /// // 3 + 5 + 7 + 9 = 24
+    /// assert_eq!(math.arithmetic_series(3, 2, U256::from(4)), U256::from(24));
+    /// ```
+    pub fn arithmetic_series<A: IntoU256, D: IntoU256>(&self, a0: A, d: D, k: U256) -> U256 {
+        if k.is_zero() {
+            return U256::zero();
+        }
+        let k_minus_one = k - 1;
+        let pairs = if k.bit(0) { self.mul(k, k_minus_one / 2) } else { self.mul(k / 2, k_minus_one) };
+        self.add(self.mul(k, a0), self.mul(d, pairs))
+    }
+}
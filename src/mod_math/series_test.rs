@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests {
+    use primitive_types::U256;
+
+    use crate::mod_math::ModMath;
+
+    fn looped_geometric(math: &ModMath, r: U256, k: u64) -> U256 {
+        let (mut sum, mut term) = (U256::zero(), math.reduce(U256::one()));
+        for _ in 0..k {
+            sum = math.add(sum, term);
+            term = math.mul(term, r);
+        }
+        sum
+    }
+
+    fn looped_arithmetic(math: &ModMath, a0: U256, d: U256, k: u64) -> U256 {
+        let (mut sum, mut term) = (U256::zero(), math.reduce(a0));
+        for _ in 0..k {
+            sum = math.add(sum, term);
+            term = math.add(term, d);
+        }
+        sum
+    }
+
+    fn secp256k1_p() -> U256 {
+        U256::from_dec_str("115792089237316195423570985008687907853269984665640564039457584007908834671663").unwrap()
+    }
+
+    #[test]
+    fn test_series_match_looped_sums() {
+        // prime, odd composite and even composite moduli, where r - 1 or 2 may not be invertible
+        for modulus in [U256::one(), U256::from(2), U256::from(101), U256::from(105), U256::from(1024), secp256k1_p()] {
+            let math = ModMath::new(modulus);
+            for r in [0u64, 1, 2, 3, 5, 6, 16, 22, 106] {
+                for k in 0..40 {
+                    let r = U256::from(r);
+                    assert_eq!(math.geometric_series(r, U256::from(k)), looped_geometric(&math, r, k), "m = {}, r = {}, k = {}", modulus, r, k);
+                }
+            }
+            for (a0, d) in [(0u64, 0u64), (3, 2), (7, 1000), (1, 1)] {
+                for k in 0..40 {
+                    let (a0, d) = (U256::from(a0), U256::from(d));
+                    assert_eq!(math.arithmetic_series(a0, d, U256::from(k)), looped_arithmetic(&math, a0, d, k), "m = {}, k = {}", modulus, k);
+                }
+            }
+            // the largest values
+            let (a0, d) = (U256::MAX, U256::MAX - 1);
+            assert_eq!(math.arithmetic_series(a0, d, U256::from(7)), looped_arithmetic(&math, a0, d, 7));
+        }
+    }
+
+    #[test]
+    fn test_series_huge_k() {
+        // k = 2^200 + 12345, with the expected values computed with exact integers
+        let k = (U256::one() << 200) + 12345;
+        let math = ModMath::new(secp256k1_p());
+        assert_eq!(
+            math.geometric_series(7, k),
+            U256::from_dec_str("76981610415500885691143067256256895924105094343098668168363493014352005085595").unwrap()
+        );
+        assert_eq!(
+            math.arithmetic_series(5, 11, k),
+            U256::from_dec_str("218213348251654250434088674967786990269826395359182373799807270321").unwrap()
+        );
+        assert_eq!(math.geometric_series(1, U256::MAX), U256::from(4294968272u64));
+
+        // an even modulus, where neither r - 1 = 4 nor 2 is invertible
+        let math = ModMath::new(1000000008);
+        assert_eq!(math.geometric_series(5, k), U256::from(648646489));
+        assert_eq!(math.arithmetic_series(5, 11, k), U256::from(201353297));
+        assert_eq!(math.arithmetic_series(5, 11, U256::MAX), U256::from(928639374));
+    }
+}
@@ -0,0 +1,236 @@
+#[cfg(test)]
+mod tests {
+    use primitive_types::U256;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::mod_math::{ArithError, InvError, ModMath, RootConvention};
+
+    // Every operation over GF(2) and GF(3), checked exhaustively against plain integer
+    // arithmetic. Inputs run past the modulus to cover the reduction as well.
+    const PRIMES: [u64; 2] = [2, 3];
+
+    fn inputs(p: u64) -> std::ops::Range<u64> {
+        0..2 * p + 1
+    }
+
+    fn naive_exp(a: u64, e: u64, p: u64) -> u64 {
+        (0..e).fold(1 % p, |acc, _| acc * a % p)
+    }
+
+    fn naive_sqrts(a: u64, p: u64) -> Vec<u64> {
+        (0..p).filter(|x| x * x % p == a % p).collect()
+    }
+
+    fn naive_inv(a: u64, p: u64) -> Option<u64> {
+        (1..p).find(|x| a * x % p == 1)
+    }
+
+    #[test]
+    fn test_ring_operations() {
+        for p in PRIMES {
+            let math = ModMath::new(p);
+            assert!(math.is_prime_modulus());
+            for a in inputs(p) {
+                assert_eq!(math.reduce(a), U256::from(a % p));
+                assert_eq!(math.add_inv(a), U256::from((p - a % p) % p));
+                assert_eq!(math.square(a), U256::from(a * a % p));
+                assert_eq!(math.cube(a), U256::from(a * a * a % p));
+                for b in inputs(p) {
+                    assert_eq!(math.add(a, b), U256::from((a + b) % p));
+                    assert_eq!(math.sub(a, b), U256::from((a % p + p - b % p) % p));
+                    assert_eq!(math.mul(a, b), U256::from(a * b % p));
+                    assert_eq!(math.eq(a, b), a % p == b % p);
+                    let (difference, negative) = math.sub_signed(a, b);
+                    assert_eq!(negative, a % p < b % p);
+                    assert_eq!(U256::from(a % p).abs_diff(U256::from(b % p)), difference);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_exponentiation() {
+        let mut rng = StdRng::seed_from_u64(976);
+        for p in PRIMES {
+            let math = ModMath::new(p);
+            for a in inputs(p) {
+                for e in 0..8u64 {
+                    let expected = U256::from(naive_exp(a, e, p));
+                    assert_eq!(math.exp(a, e), expected, "{}^{} mod {}", a, e, p);
+                    assert_eq!(math.exp_ct(U256::from(a), U256::from(e)), expected);
+                    assert_eq!(math.exp_bytes(a, &[e as u8]), expected);
+                    assert_eq!(math.exp_fermat_reduced(a, &[e as u8]), expected);
+                    if a % p != 0 {
+                        let group_order = U256::from(p - 1);
+                        assert_eq!(math.exp_blinded(a, U256::from(e), group_order, &mut rng), expected);
+                    }
+                    assert_eq!(math.exp_many(&[U256::from(a)], U256::from(e)), vec![expected]);
+                    assert_eq!(math.pow_product(&[(U256::from(a), U256::from(e))]), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_inversion_and_division() {
+        for p in PRIMES {
+            let math = ModMath::new(p);
+            for a in inputs(p) {
+                let expected = naive_inv(a, p).map(U256::from);
+                assert_eq!(math.inv(a), expected);
+                assert_eq!(math.is_invertible(a), expected.is_some());
+                assert_eq!(math.inv_prime(a).ok(), expected);
+                match expected {
+                    Some(inverse) => {
+                        assert_eq!(math.inv_detailed(a), Ok(inverse));
+                        assert_eq!(math.exp_signed(U256::from(a), true, U256::one()), Some(inverse));
+                        for b in inputs(p) {
+                            assert_eq!(math.div(b, a), math.mul(b, inverse));
+                            assert_eq!(math.checked_div(b, a), Ok(math.mul(b, inverse)));
+                        }
+                    }
+                    None => {
+                        assert_eq!(math.inv_detailed(a), Err(InvError::Zero));
+                        assert_eq!(math.inv_prime(a), Err(ArithError::NotInvertible));
+                        assert_eq!(math.checked_div(1, a), Err(InvError::Zero));
+                    }
+                }
+            }
+            let values: Vec<U256> = (1..p).map(U256::from).collect();
+            let inverses: Vec<U256> = (1..p).map(|a| U256::from(naive_inv(a, p).unwrap())).collect();
+            assert_eq!(math.batch_inv(&values), Some(inverses));
+        }
+    }
+
+    #[test]
+    fn test_halve() {
+        // 2 is zero in characteristic 2, so there is nothing to divide by
+        let gf2 = ModMath::new(2);
+        for a in inputs(2) {
+            assert_eq!(gf2.halve(a), Err(ArithError::EvenModulus));
+        }
+
+        let gf3 = ModMath::new(3);
+        for a in inputs(3) {
+            let half = gf3.halve(a).unwrap();
+            assert_eq!(gf3.add(half, half), gf3.reduce(a));
+        }
+    }
+
+    #[test]
+    fn test_square_roots() {
+        for p in PRIMES {
+            let math = ModMath::new(p);
+            for a in inputs(p) {
+                let roots = naive_sqrts(a, p);
+                let is_square = !roots.is_empty();
+                assert_eq!(math.is_square(a), is_square);
+                assert_eq!(math.is_quadratic_residue(a), is_square && a % p != 0);
+
+                let expected_legendre = if a % p == 0 { 0 } else if is_square { 1 } else { -1 };
+                assert_eq!(math.legendre(a), Ok(expected_legendre), "legendre({}) mod {}", a, p);
+                let expected_euler = match expected_legendre {
+                    0 => U256::zero(),
+                    1 => U256::one(),
+                    _ => U256::from(p - 1),
+                };
+                assert_eq!(math.euler_criterion(a), expected_euler);
+
+                for root in [math.sqrt_opt(a), math.sqrt_ct(a)] {
+                    match root {
+                        Some(root) => assert!(roots.contains(&root.as_u64())),
+                        None => assert!(!is_square),
+                    }
+                    assert_eq!(root.is_some(), is_square);
+                }
+                for convention in [RootConvention::EvenY, RootConvention::OddY, RootConvention::Smaller, RootConvention::Larger] {
+                    let root = math.sqrt_normalized(a, convention);
+                    assert_eq!(root.is_some(), is_square);
+                    if let Some(root) = root {
+                        assert!(roots.contains(&root.as_u64()));
+                    }
+                }
+            }
+        }
+        // under modulus 2 each element is its own only root, whatever the convention
+        let gf2 = ModMath::new(2);
+        assert_eq!(gf2.sqrt_normalized(1, RootConvention::EvenY), Some(U256::one()));
+        assert_eq!(gf2.sqrt_normalized(0, RootConvention::OddY), Some(U256::zero()));
+    }
+
+    #[test]
+    fn test_cube_roots() {
+        // cubing is a bijection under both moduli
+        for p in PRIMES {
+            let math = ModMath::new(p);
+            for a in inputs(p) {
+                let root = math.cube_root(a).unwrap();
+                assert_eq!(math.cube(root), math.reduce(a));
+            }
+        }
+    }
+
+    #[test]
+    fn test_roots_of_unity() {
+        let gf2 = ModMath::new(2);
+        assert!(gf2.is_primitive_root(1));
+        assert!(!gf2.is_primitive_root(0));
+        assert_eq!(gf2.nth_roots_of_unity(1), Some(vec![U256::one()]));
+        assert_eq!(gf2.nth_roots_of_unity(2), None);
+
+        let gf3 = ModMath::new(3);
+        assert!(gf3.is_primitive_root(2));
+        assert!(!gf3.is_primitive_root(1));
+        assert_eq!(gf3.nth_roots_of_unity(2), Some(vec![U256::one(), U256::from(2)]));
+        assert!(gf3.is_nth_root_of_unity(2, 2));
+    }
+
+    #[test]
+    fn test_random_residues() {
+        let mut rng = StdRng::seed_from_u64(976);
+        let gf3 = ModMath::new(3);
+        for _ in 0..20 {
+            assert_eq!(gf3.random_residue(&mut rng), U256::one());
+            assert_eq!(gf3.random_non_residue(&mut rng), U256::from(2));
+        }
+        let gf2 = ModMath::new(2);
+        assert_eq!(gf2.random_residue(&mut rng), U256::one());
+    }
+
+    #[test]
+    #[should_panic(expected = "every non-zero element is a square under modulus 2")]
+    fn test_random_non_residue_gf2() {
+        ModMath::new(2).random_non_residue(&mut StdRng::seed_from_u64(976));
+    }
+
+    #[test]
+    fn test_series_and_convolution() {
+        for p in PRIMES {
+            let math = ModMath::new(p);
+            for r in inputs(p) {
+                for k in 0..6u64 {
+                    let geometric = (0..k).map(|i| naive_exp(r, i, p)).sum::<u64>() % p;
+                    assert_eq!(math.geometric_series(r, U256::from(k)), U256::from(geometric));
+                    let arithmetic = (0..k).map(|i| r + i * 2).sum::<u64>() % p;
+                    assert_eq!(math.arithmetic_series(r, 2, U256::from(k)), U256::from(arithmetic));
+                }
+            }
+            let a: Vec<U256> = (0..p).map(U256::from).collect();
+            let b: Vec<U256> = (0..p).map(|i| U256::from(i + 1)).collect();
+            let n = p as usize;
+            let mut cyclic = vec![0u64; n];
+            let mut negacyclic = vec![0u64; n];
+            for i in 0..n {
+                for j in 0..n {
+                    let term = (i as u64) * (j as u64 + 1) % p;
+                    cyclic[(i + j) % n] = (cyclic[(i + j) % n] + term) % p;
+                    let slot = &mut negacyclic[(i + j) % n];
+                    *slot = if i + j >= n { (*slot + p - term) % p } else { (*slot + term) % p };
+                }
+            }
+            let to_u256 = |v: Vec<u64>| v.into_iter().map(U256::from).collect::<Vec<_>>();
+            assert_eq!(math.cyclic_convolution(&a, &b), Ok(to_u256(cyclic)));
+            assert_eq!(math.negacyclic_convolution(&a, &b), Ok(to_u256(negacyclic)));
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use primitive_types::U256;
+
+use super::{ArithError, ModMath};
+
+/// The largest modulus `addition_table`, `multiplication_table` and `is_field` accept.
+/// A table has `p^2` entries of 32 bytes, 16 million entries taking 512 MiB at this bound.
+pub const MAX_TABLE_MODULUS: u64 = 1 << 12;
+
+impl ModMath {
+    /// Returns the Cayley table of `op` on `{0, 1, ..., p - 1}`: row `a`, column `b` holds
+    /// `op(self, a, b)`.
+    ///
+    /// The table takes `32 * p^2` bytes. Returns `ArithError::ModulusTooLarge` if the
+    /// modulus is above `max_modulus`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// let math = ModMath::new(3);
+    /// let subtraction = math.operation_table(16, |math, a, b| math.sub(a, b)).unwrap();
+    /// assert_eq!(subtraction[0], vec![U256::from(0), U256::from(2), U256::from(1)]);
+    /// ```
+    pub fn operation_table<F>(&self, max_modulus: u64, op: F) -> Result<Vec<Vec<U256>>, ArithError>
+    where
+        F: Fn(&Self, U256, U256) -> U256,
+    {
+        let p = self.table_size(max_modulus)?;
+        Ok((0..p)
+            .map(|a| (0..p).map(|b| op(self, U256::from(a), U256::from(b))).collect())
+            .collect())
+    }
+
+    /// Returns the addition table of the integers modulo `p`, for `p` up to
+    /// `MAX_TABLE_MODULUS`, which takes up to 512 MiB.
+    ///
+    /// Returns `ArithError::ModulusTooLarge` for a larger modulus.
+    pub fn addition_table(&self) -> Result<Vec<Vec<U256>>, ArithError> {
+        self.operation_table(MAX_TABLE_MODULUS, |math, a, b| math.add(a, b))
+    }
+
+    /// Returns the multiplication table of the integers modulo `p`, for `p` up to
+    /// `MAX_TABLE_MODULUS`, which takes up to 512 MiB.
+    ///
+    /// Returns `ArithError::ModulusTooLarge` for a larger modulus.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// let table = ModMath::new(5).multiplication_table().unwrap();
+    /// assert_eq!(table[2], [0, 2, 4, 1, 3].map(U256::from));
+    /// ```
+    pub fn multiplication_table(&self) -> Result<Vec<Vec<U256>>, ArithError> {
+        self.operation_table(MAX_TABLE_MODULUS, |math, a, b| math.mul(a, b))
+    }
+
+    /// Checks that the integers modulo `p` form a field, by scanning the row of every
+    /// non-zero element of the multiplication table for a 1. The products are checked as
+    /// they are computed, without building the table. This is an exhaustive check of
+    /// `is_prime_modulus`, for `p` up to `MAX_TABLE_MODULUS`.
+    ///
+    /// Returns `ArithError::ModulusTooLarge` for a larger modulus.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    ///
+    /// assert_eq!(ModMath::new(7).is_field(), Ok(true));
+    /// assert_eq!(ModMath::new(6).is_field(), Ok(false)); // 2 * 3 = 0
+    /// ```
+    pub fn is_field(&self) -> Result<bool, ArithError> {
+        let p = self.table_size(MAX_TABLE_MODULUS)?;
+        // under modulus 1 the only element is 0 = 1
+        Ok(p > 1 && (1..p).all(|a| (1..p).any(|b| self.mul(a, b) == U256::one())))
+    }
+
+    /// Renders a Cayley table from `operation_table` with a header row and column, the
+    /// operator in the corner and the entries right-aligned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    ///
+    /// let table = ModMath::new(3).addition_table().unwrap();
+    /// let expected = "\
+    /// + | 0 1 2
+    /// --+------
+    /// 0 | 0 1 2
+    /// 1 | 1 2 0
+    /// 2 | 2 0 1
+    /// ";
+    /// assert_eq!(ModMath::format_table(&table, "+"), expected);
+    /// ```
+    pub fn format_table(table: &[Vec<U256>], operator: &str) -> String {
+        let width = table.len().saturating_sub(1).to_string().len().max(operator.len());
+        let row = |label: String, entries: &mut dyn Iterator<Item = String>| {
+            let cells: Vec<String> = entries.map(|entry| format!("{:>width$}", entry)).collect();
+            format!("{:>width$} | {}\n", label, cells.join(" "))
+        };
+
+        let mut out = row(operator.to_string(), &mut (0..table.len()).map(|b| b.to_string()));
+        out.push_str(&format!("{}+{}\n", "-".repeat(width + 1), "-".repeat(table.len() * (width + 1))));
+        for (a, entries) in table.iter().enumerate() {
+            out.push_str(&row(a.to_string(), &mut entries.iter().map(|entry| entry.to_string())));
+        }
+        out
+    }
+
+    // the modulus as a row count, if it is at most max_modulus
+    fn table_size(&self, max_modulus: u64) -> Result<u64, ArithError> {
+        let p = self.modulus;
+        if p > U256::from(max_modulus) {
+            return Err(ArithError::ModulusTooLarge);
+        }
+        Ok(p.low_u64())
+    }
+}
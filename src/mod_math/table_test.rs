@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod tests {
+    use primitive_types::U256;
+
+    use crate::mod_math::{ArithError, ModMath, Montgomery, MAX_TABLE_MODULUS};
+
+    fn rows(table: &[&[u64]]) -> Vec<Vec<U256>> {
+        table.iter().map(|row| row.iter().map(|&v| U256::from(v)).collect()).collect()
+    }
+
+    #[test]
+    fn test_gf7_multiplication_table() {
+        let expected = rows(&[
+            &[0, 0, 0, 0, 0, 0, 0],
+            &[0, 1, 2, 3, 4, 5, 6],
+            &[0, 2, 4, 6, 1, 3, 5],
+            &[0, 3, 6, 2, 5, 1, 4],
+            &[0, 4, 1, 5, 2, 6, 3],
+            &[0, 5, 3, 1, 6, 4, 2],
+            &[0, 6, 5, 4, 3, 2, 1],
+        ]);
+        assert_eq!(ModMath::new(7).multiplication_table(), Ok(expected));
+
+        let addition = ModMath::new(7).addition_table().unwrap();
+        for (a, row) in addition.iter().enumerate() {
+            let expected: Vec<U256> = (0..7).map(|b| U256::from((a + b) % 7)).collect();
+            assert_eq!(row, &expected);
+        }
+    }
+
+    #[test]
+    fn test_is_field() {
+        assert_eq!(ModMath::new(7).is_field(), Ok(true));
+        assert_eq!(ModMath::new(6).is_field(), Ok(false));
+        assert_eq!(ModMath::new(1).is_field(), Ok(false));
+        // the exhaustive check agrees with Miller-Rabin
+        for p in 2..200u64 {
+            let math = ModMath::new(p);
+            assert_eq!(math.is_field(), Ok(math.is_prime_modulus()), "modulus {}", p);
+        }
+    }
+
+    #[test]
+    fn test_table_cap() {
+        let large = ModMath::new(U256::from(u64::MAX));
+        assert_eq!(large.multiplication_table(), Err(ArithError::ModulusTooLarge));
+        assert_eq!(large.addition_table(), Err(ArithError::ModulusTooLarge));
+        assert_eq!(large.is_field(), Err(ArithError::ModulusTooLarge));
+
+        let at_cap = ModMath::new(MAX_TABLE_MODULUS);
+        assert!(at_cap.operation_table(MAX_TABLE_MODULUS, |_, a, _| a).is_ok());
+        assert_eq!(at_cap.operation_table(MAX_TABLE_MODULUS - 1, |_, a, _| a), Err(ArithError::ModulusTooLarge));
+    }
+
+    #[test]
+    fn test_montgomery_matches_table() {
+        for p in [3u64, 5, 9, 15, 17, 97] {
+            let math = ModMath::new(p);
+            let table = math.multiplication_table().unwrap();
+            let montgomery = Montgomery::new(U256::from(p)).unwrap();
+            for (a, row) in table.iter().enumerate() {
+                for (b, &product) in row.iter().enumerate() {
+                    let (a, b) = (montgomery.to_montgomery(U256::from(a)), montgomery.to_montgomery(U256::from(b)));
+                    assert_eq!(montgomery.from_montgomery(montgomery.mul(a, b)), product);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_format_table() {
+        let table = ModMath::new(11).multiplication_table().unwrap();
+        let formatted = ModMath::format_table(&table, "*");
+        let lines: Vec<&str> = formatted.lines().collect();
+        assert_eq!(lines.len(), 13);
+        assert_eq!(lines[0], " * |  0  1  2  3  4  5  6  7  8  9 10");
+        assert_eq!(lines[1], "---+---------------------------------");
+        assert_eq!(lines[4], " 2 |  0  2  4  6  8 10  1  3  5  7  9");
+        assert!(lines.iter().all(|line| line.len() == lines[0].len()));
+    }
+}
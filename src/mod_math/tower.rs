@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+use primitive_types::{U256, U512};
+
+use super::ModMath;
+
+impl ModMath {
+    /// Evaluates the power tower `a^(b^(c^...))` of `exponents = [a, b, c, ...]` under
+    /// the modulus, grouping from the top as exponentiation does.
+    ///
+    /// An exponent that fits in a `U256` is used as it is. A larger one is replaced by a
+    /// smaller exponent congruent to it modulo the Carmichael function `lambda(n)`, found
+    /// by evaluating the rest of the tower modulo `lambda(n)`. For a base that shares a
+    /// factor with `n`, `a^e = a^(e mod lambda)` can fail, so the replacement keeps at
+    /// least `log2(n)` above that residue by adding multiples of `lambda`, as
+    /// `a^e = a^(e + lambda)` holds for every `a` once `e` is past the largest exponent
+    /// in the factorization of `n`.
+    ///
+    /// The factorization of `n` is `p` itself for a prime modulus and found by trial
+    /// division otherwise, as are those of the moduli `lambda(n)`, `lambda(lambda(n))`, ...
+    /// below it; use `power_tower_with_factors` to supply the first. A tower of two
+    /// numbers is a plain `exp`, and an empty tower is 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ModMath;
+    /// use primitive_types::U256;
+    ///
+    /// let math = ModMath::new(1000);
+    /// // 2^81 = 2417851639229258349412352
+    /// assert_eq!(math.power_tower(&[2, 3, 4].map(U256::from)), U256::from(352));
+    /// // 7^(7^(7^7)) ends in 343
+    /// assert_eq!(math.power_tower(&[7, 7, 7, 7].map(U256::from)), U256::from(343));
+    /// ```
+    pub fn power_tower(&self, exponents: &[U256]) -> U256 {
+        self.tower(exponents, None)
+    }
+
+    /// Evaluates a power tower like `power_tower`, with the prime factorization of the
+    /// modulus given as by `prime_factors`, e.g. for a modulus with large prime factors.
+    /// The moduli further down are still factored by trial division.
+    pub fn power_tower_with_factors(&self, exponents: &[U256], factors: &HashMap<U256, U256>) -> U256 {
+        self.tower(exponents, Some(factors))
+    }
+
+    fn tower(&self, exponents: &[U256], factors: Option<&HashMap<U256, U256>>) -> U256 {
+        let (base, upper) = match exponents.split_first() {
+            Some(split) => split,
+            None => return self.reduce(U256::one()),
+        };
+        if self.modulus == U256::one() {
+            return U256::zero();
+        }
+        if let Some(exponent) = exact_tower(upper) {
+            return self.exp(*base, exponent);
+        }
+
+        let computed;
+        let factors = match factors {
+            Some(factors) => factors,
+            None => {
+                computed = if self.is_prime_modulus() {
+                    HashMap::from([(self.modulus, U256::one())])
+                } else {
+                    Self::prime_factors(self.modulus)
+                };
+                &computed
+            }
+        };
+        let lambda = Self::carmichael(factors);
+        let residue = ModMath::new(lambda).tower(upper, None);
+        // the smallest multiple of lambda that reaches log2(n), at most lambda + 256
+        let bits = U256::from(self.modulus.bits());
+        let lift = if lambda >= bits { lambda } else { (bits + lambda - 1) / lambda * lambda };
+        self.exp_u512(*base, U512::from(residue) + U512::from(lift))
+    }
+}
+
+// The value of the power tower over the integers, or None if it does not fit in a U256.
+// A tall tower can still come out small, e.g. with a base of 0 or 1 anywhere in it.
+fn exact_tower(exponents: &[U256]) -> Option<U256> {
+    let (top, below) = match exponents.split_last() {
+        Some(split) => split,
+        None => return Some(U256::one()),
+    };
+    // None stands for an exponent of at least 2^256, not an early exit
+    let mut exponent = Some(*top);
+    for &base in below.iter().rev() {
+        exponent = match exponent {
+            Some(e) if e.is_zero() => Some(U256::one()),
+            _ if base == U256::one() => Some(U256::one()),
+            _ if base.is_zero() => Some(U256::zero()),
+            Some(e) if e < U256::from(256) => base.checked_pow(e),
+            _ => None,
+        };
+    }
+    exponent
+}
@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use num_bigint::BigUint;
+    use primitive_types::U256;
+    use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+    use crate::mod_math::ModMath;
+
+    fn towers(length: usize, digits: u32) -> Vec<Vec<u32>> {
+        (0..length).fold(vec![vec![]], |towers, _| {
+            towers.iter().flat_map(|tower| (0..=digits).map(move |d| [tower.clone(), vec![d]].concat())).collect()
+        })
+    }
+
+    // a.modpow(b^(c^...)) with the upper tower as an exact integer
+    fn brute_force(tower: &[u32], modulus: u64) -> u64 {
+        let modulus = BigUint::from(modulus);
+        let Some((base, upper)) = tower.split_first() else {
+            return (BigUint::from(1u32) % &modulus).try_into().unwrap();
+        };
+        // from an implicit 1 on top, which also covers a tower of one number
+        let exponent = upper.iter().rev().fold(BigUint::from(1u32), |e, &b| {
+            let e: u32 = e.try_into().expect("tower too tall for the test");
+            BigUint::from(b).pow(e)
+        });
+        BigUint::from(*base).modpow(&exponent, &modulus).try_into().unwrap()
+    }
+
+    fn to_u256(tower: &[u32]) -> Vec<U256> {
+        tower.iter().map(|&d| U256::from(d)).collect()
+    }
+
+    #[test]
+    fn test_power_tower_brute_force() {
+        // 4^(4^4) = 2^512, so the 4-high towers over 0..4 go through the Carmichael reduction
+        let mut all = towers(3, 4);
+        all.extend(towers(4, 4));
+        for modulus in (1..=64u64).chain([100, 360, 1000, 1024]) {
+            let math = ModMath::new(modulus);
+            for tower in &all {
+                let expected = U256::from(brute_force(tower, modulus));
+                assert_eq!(math.power_tower(&to_u256(tower)), expected, "{:?} mod {}", tower, modulus);
+            }
+        }
+    }
+
+    #[test]
+    fn test_power_tower_examples() {
+        let math = ModMath::new(1000);
+        assert_eq!(math.power_tower(&to_u256(&[2, 3, 4])), U256::from(352));
+        assert_eq!(math.power_tower(&to_u256(&[7, 7, 7, 7])), U256::from(343));
+        // 2^(2^(2^(2^2))) = 2^65536, which ends in 736
+        assert_eq!(math.power_tower(&to_u256(&[2, 2, 2, 2, 2])), U256::from(736));
+        // a 0 or 1 high in the tower makes it small again
+        assert_eq!(math.power_tower(&to_u256(&[3, 1, 9, 9, 9])), U256::from(3));
+        assert_eq!(math.power_tower(&to_u256(&[3, 9, 2, 0, 9])), U256::from(683)); // 3^9
+
+        assert_eq!(math.power_tower(&[]), U256::one());
+        assert_eq!(math.power_tower(&to_u256(&[1234])), U256::from(234));
+        assert_eq!(ModMath::new(1).power_tower(&to_u256(&[2, 3])), U256::zero());
+    }
+
+    #[test]
+    fn test_power_tower_prime_fermat() {
+        let p = U256::from_dec_str("21888242871839275222246405745257275088696311157297823662689037894645226208583").unwrap();
+        let math = ModMath::new(p);
+        let exponents = ModMath::new(p - 1);
+        let mut rng = StdRng::seed_from_u64(981);
+        for _ in 0..10 {
+            let mut bytes = [0u8; 32];
+            rng.fill_bytes(&mut bytes);
+            let (a, b, c) = (U256::from_big_endian(&bytes), U256::from(rng.next_u64()), U256::from(rng.next_u64()));
+            // a^e = a^(e mod (p - 1)) for a coprime to p
+            let expected = math.exp(a, exponents.exp(b, c));
+            assert_eq!(math.power_tower(&[a, b, c]), expected);
+        }
+        assert_eq!(math.power_tower(&[p, U256::from(5), U256::from(1000)]), U256::zero());
+    }
+
+    #[test]
+    fn test_power_tower_with_factors() {
+        // 3 * r has a 254-bit prime factor, out of reach of trial division
+        let r = U256::from_dec_str("21888242871839275222246405745257275088548364400416034343698204186575808495617").unwrap();
+        let n = r * 3;
+        let factors = HashMap::from([(U256::from(3), U256::one()), (r, U256::one())]);
+        assert_eq!(ModMath::carmichael(&factors), r - 1);
+
+        let math = ModMath::new(n);
+        for tower in [[5u32, 7, 300], [3, 3, 3000], [6, 2, 1000]] {
+            let tower = to_u256(&tower);
+            let result = math.power_tower_with_factors(&tower, &factors);
+            assert_eq!(result % 3, ModMath::new(3).power_tower(&tower));
+            assert_eq!(result % r, ModMath::new(r).power_tower(&tower));
+        }
+    }
+
+    #[test]
+    fn test_carmichael() {
+        // lambda(n) for n = 1, 2, ..., 20
+        let expected = [1u64, 1, 2, 2, 4, 2, 6, 2, 6, 4, 10, 2, 12, 6, 4, 4, 16, 6, 18, 4];
+        for (n, &lambda) in (1..=20u64).zip(&expected) {
+            let factors = ModMath::prime_factors(U256::from(n));
+            assert_eq!(ModMath::carmichael(&factors), U256::from(lambda), "lambda({})", n);
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use crate::mod_math::{IntoU256, ModMath};
+use super::NumberUnderMod;
+
+use primitive_types::U256;
+use std::ops::{Add, Mul, Sub, Div, Neg};
+
+/// `FieldExpr` is the value the `field!` macro binds its `let` statements to.
+///
+/// Unlike `NumberUnderMod`, its operators return `FieldExpr` directly, so expressions such
+/// as `a * b + a` can be written without unwrapping. Integer operands are taken to be
+/// elements of the same field.
+///
+/// # Panics
+///
+/// The operators panic if the operands have different moduli, and division panics if
+/// the divisor is not invertible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldExpr {
+    value: U256,
+    modulus: U256,
+}
+
+impl FieldExpr {
+    /// Creates an element, reducing the value modulo the modulus.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is zero.
+    pub fn new<T: IntoU256>(value: T, modulus: U256) -> Self {
+        let number = NumberUnderMod::new(value.into_u256(), modulus);
+        FieldExpr { value: number.value(), modulus }
+    }
+
+    /// Converts the element to a `NumberUnderMod`.
+    pub fn into_number(self) -> NumberUnderMod {
+        NumberUnderMod::new(self.value, self.modulus)
+    }
+
+    fn math(&self, other: &Self) -> ModMath {
+        assert_eq!(self.modulus, other.modulus, "Cannot combine numbers with different moduli");
+        ModMath::new(self.modulus)
+    }
+
+    fn lift<T: IntoU256>(&self, other: T) -> Self {
+        FieldExpr::new(other, self.modulus)
+    }
+}
+
+impl From<FieldExpr> for NumberUnderMod {
+    fn from(value: FieldExpr) -> Self {
+        value.into_number()
+    }
+}
+
+impl Add for FieldExpr {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let value = self.math(&other).add(self.value, other.value);
+        FieldExpr { value, modulus: self.modulus }
+    }
+}
+
+impl Sub for FieldExpr {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        let value = self.math(&other).sub(self.value, other.value);
+        FieldExpr { value, modulus: self.modulus }
+    }
+}
+
+impl Mul for FieldExpr {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let value = self.math(&other).mul(self.value, other.value);
+        FieldExpr { value, modulus: self.modulus }
+    }
+}
+
+impl Div for FieldExpr {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        let math = self.math(&other);
+        let inverse = math.inv(other.value).expect("Cannot divide by a number without an inverse");
+        FieldExpr { value: math.mul(self.value, inverse), modulus: self.modulus }
+    }
+}
+
+impl Neg for FieldExpr {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        let value = ModMath::new(self.modulus).add_inv(self.value);
+        FieldExpr { value, modulus: self.modulus }
+    }
+}
+
+impl<T: IntoU256> Add<T> for FieldExpr {
+    type Output = Self;
+
+    fn add(self, other: T) -> Self {
+        self + self.lift(other)
+    }
+}
+
+impl<T: IntoU256> Sub<T> for FieldExpr {
+    type Output = Self;
+
+    fn sub(self, other: T) -> Self {
+        self - self.lift(other)
+    }
+}
+
+impl<T: IntoU256> Mul<T> for FieldExpr {
+    type Output = Self;
+
+    fn mul(self, other: T) -> Self {
+        self * self.lift(other)
+    }
+}
+
+impl<T: IntoU256> Div<T> for FieldExpr {
+    type Output = Self;
+
+    fn div(self, other: T) -> Self {
+        self / self.lift(other)
+    }
+}
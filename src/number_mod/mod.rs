@@ -1,4 +1,7 @@
+#[allow(clippy::module_inception)]
 mod number_mod;
+mod field_expr;
 mod number_mod_test;
 
-pub use number_mod::NumberUnderMod;
\ No newline at end of file
+pub use number_mod::{ConvertPolicy, NumModError, NumberUnderMod};
+pub use field_expr::FieldExpr;
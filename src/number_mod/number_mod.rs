@@ -1,5 +1,5 @@
 
-use crate::mod_math::{IntoU256, ModMath};
+use crate::mod_math::{ArithError, FromU256, IntoU256, ModMath};
 
 use primitive_types::U256;
 use std::ops::{Add, Mul, Sub, Div, Neg};
@@ -8,120 +8,438 @@ use std::cmp::PartialEq;
 ///
 /// This struct provides methods for performing arithmetic operations
 /// (addition, subtraction, multiplication, division, and negation)
-/// under the modulus.
+/// under the modulus. The value is always kept reduced modulo the modulus.
+///
+/// `==` compares the value and the modulus. As the value is always reduced, numbers
+/// built from congruent values are equal, matching `ModMath::eq`, while numbers under
+/// different moduli never are.
 ///
 /// # Examples
 ///
+/// ```
+/// use modular_math::number_mod::NumberUnderMod;
+/// use primitive_types::U256;
+///
 /// let num1 = NumberUnderMod::new(5, 7);
 /// let num2 = NumberUnderMod::new(3, 7);
 /// let result = num1 + num2;
-/// assert_eq!(result.unwrap().value, 1);
-#[derive(Debug)]
+/// assert_eq!(result.unwrap().value(), U256::from(1));
+/// ```
+#[derive(Clone)]
 pub struct NumberUnderMod {
     value: U256,
-    modulus: U256,
+    // shares its precomputed data with the numbers built from the same field
+    math: ModMath,
 }
 
-/// Creates a new `NumberUnderMod` with the given value and modulus.
+impl NumberUnderMod {
+    /// Creates a new `NumberUnderMod` with the given value and modulus.
+    ///
+    /// The value is automatically reduced modulo the modulus. Each call sets up the
+    /// arithmetic for the modulus anew, so numbers from separate calls do not share it;
+    /// `from_slice`, `with_modulus!` and `GaloisField::gf` create many numbers that do.
+    ///
+    /// # Panics
     ///
-    /// The value is automatically reduced modulo the modulus.
+    /// Panics if the modulus is zero.
     ///
     /// # Examples
     ///
     /// ```
+    /// use modular_math::number_mod::NumberUnderMod;
+    /// use primitive_types::U256;
+    ///
     /// let num = NumberUnderMod::new(10, 7);
-    /// assert_eq!(num.value, 3);
+    /// assert_eq!(num.value(), U256::from(3));
     /// ```
-impl NumberUnderMod {
     pub fn new<T: IntoU256>(value: T, modulus: T) -> Self {
       let value = value.into_u256();
       let modulus = modulus.into_u256();
+      if modulus == U256::zero() {
+        panic!("Modulus Cannot be Zero");
+      }
       Self {
         value: value % modulus,
-        modulus
+        math: ModMath::new(modulus),
+      }
+    }
+
+    // Creates a number sharing the arithmetic of `math`, reducing the value.
+    pub(crate) fn with_math<T: IntoU256>(value: T, math: &ModMath) -> Self {
+      Self {
+        value: math.reduce(value),
+        math: math.clone(),
       }
     }
+
+    /// Creates a new `NumberUnderMod` like `new`, but returns `ArithError::ZeroModulus`
+    /// instead of panicking if the modulus is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::mod_math::ArithError;
+    /// use modular_math::number_mod::NumberUnderMod;
+    ///
+    /// assert!(NumberUnderMod::try_new(10, 7).is_ok());
+    /// assert_eq!(NumberUnderMod::try_new(5, 0).err(), Some(ArithError::ZeroModulus));
+    /// ```
+    pub fn try_new<T: IntoU256>(value: T, modulus: T) -> Result<Self, ArithError> {
+      let modulus = modulus.into_u256();
+      if modulus == U256::zero() {
+        return Err(ArithError::ZeroModulus);
+      }
+      Ok(Self::new(value.into_u256(), modulus))
+    }
+
+    /// Creates a new `NumberUnderMod` from a signed value, mapping a negative value to its
+    /// representative in `[0, modulus)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::number_mod::NumberUnderMod;
+    /// use primitive_types::U256;
+    ///
+    /// assert_eq!(NumberUnderMod::from_signed(-3, 7).value(), U256::from(4));
+    /// assert_eq!(NumberUnderMod::from_signed(-14, 7).value(), U256::zero());
+    /// ```
+    pub fn from_signed<T: IntoU256>(value: i64, modulus: T) -> Self {
+      let mut num = Self::new(value.unsigned_abs().into_u256(), modulus.into_u256());
+      if value < 0 {
+        num.value = num.math.add_inv(num.value);
+      }
+      num
+    }
+
+    /// Creates a `NumberUnderMod` for every value, all under the same modulus.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::number_mod::NumberUnderMod;
+    ///
+    /// let coefficients = NumberUnderMod::from_slice(&[3, 9, 14], 7);
+    /// assert_eq!(coefficients, vec![NumberUnderMod::new(3, 7), NumberUnderMod::new(2, 7), NumberUnderMod::new(0, 7)]);
+    /// ```
+    pub fn from_slice<T: IntoU256 + Copy>(values: &[T], modulus: T) -> Vec<Self> {
+      let math = ModMath::new(modulus);
+      values.iter().map(|&value| Self::with_math(value, &math)).collect()
+    }
+
+    /// Returns the reduced value.
+    pub fn value(&self) -> U256 {
+      self.value
+    }
+
+    /// Returns the modulus.
+    pub fn modulus(&self) -> U256 {
+      self.math.modulus
+    }
+
+    /// Returns the multiplicative inverse, or `None` if the value is not invertible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::number_mod::NumberUnderMod;
+    ///
+    /// assert_eq!(NumberUnderMod::new(2, 13).inverse(), Some(NumberUnderMod::new(7, 13)));
+    /// assert_eq!(NumberUnderMod::new(4, 12).inverse(), None);
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+      let math = &self.math;
+      Some(NumberUnderMod {
+        value: math.inv(self.value)?,
+        math: self.math.clone(),
+      })
+    }
+
+    /// Raises the number to a signed exponent, see `ModMath::exp_signed`.
+    ///
+    /// Returns `None` if the exponent is negative and the number is not invertible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::number_mod::NumberUnderMod;
+    ///
+    /// let a = NumberUnderMod::new(2, 13);
+    /// assert_eq!(a.pow_signed(-1), a.inverse());
+    /// assert_eq!(a.pow_signed(3), Some(NumberUnderMod::new(8, 13)));
+    /// ```
+    pub fn pow_signed(&self, exp: i128) -> Option<Self> {
+      let math = &self.math;
+      let magnitude = U256::from(exp.unsigned_abs());
+      Some(NumberUnderMod {
+        value: math.exp_signed(self.value, exp < 0, magnitude)?,
+        math: self.math.clone(),
+      })
+    }
+
+    /// Formats the value in decimal, see `ModMath::to_dec_string`.
+    pub fn to_dec_string(&self) -> String {
+      ModMath::to_dec_string(self.value)
+    }
+
+    /// Formats the value in hex, see `ModMath::to_hex_string`.
+    pub fn to_hex_string(&self, fixed_width: bool) -> String {
+      ModMath::to_hex_string(self.value, fixed_width)
+    }
+
+    /// Encodes the number as 64 bytes: the value followed by the modulus, both big-endian.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::number_mod::NumberUnderMod;
+    ///
+    /// let num = NumberUnderMod::new(10, 7);
+    /// assert_eq!(NumberUnderMod::from_bytes(&num.to_bytes()), Ok(num));
+    /// ```
+    pub fn to_bytes(&self) -> [u8; 64] {
+      let mut bytes = [0u8; 64];
+      self.value.to_big_endian(&mut bytes[..32]);
+      self.math.modulus.to_big_endian(&mut bytes[32..]);
+      bytes
+    }
+
+    /// Decodes a number encoded by `to_bytes`. The input may be untrusted: every invalid
+    /// encoding is rejected with an error, and none is silently reduced.
+    ///
+    /// Returns `ArithError::InvalidLength` unless there are exactly 64 bytes,
+    /// `ArithError::ZeroModulus` if the modulus is zero and `ArithError::NotCanonical` if
+    /// the value is not below the modulus.
+    #[deny(clippy::unwrap_used, clippy::expect_used)]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ArithError> {
+      if bytes.len() != 64 {
+        return Err(ArithError::InvalidLength);
+      }
+      let (value, modulus) = (U256::from_big_endian(&bytes[..32]), U256::from_big_endian(&bytes[32..]));
+      if modulus == U256::zero() {
+        return Err(ArithError::ZeroModulus);
+      }
+      if value >= modulus {
+        return Err(ArithError::NotCanonical);
+      }
+      Self::try_new(value, modulus)
+    }
+
+    /// Returns the value and the modulus as little-endian `u64` limbs, least significant
+    /// limb first, as `primitive_types::U256` stores them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::number_mod::NumberUnderMod;
+    ///
+    /// let (value, modulus) = NumberUnderMod::new(10, 7).to_limbs();
+    /// assert_eq!((value, modulus), ([3, 0, 0, 0], [7, 0, 0, 0]));
+    /// assert_eq!(NumberUnderMod::from_limbs(value, modulus), Ok(NumberUnderMod::new(10, 7)));
+    /// ```
+    pub fn to_limbs(&self) -> ([u64; 4], [u64; 4]) {
+      (self.value.0, self.math.modulus.0)
+    }
+
+    /// Creates a number from the limbs returned by `to_limbs`. Like `from_bytes`, it
+    /// rejects every invalid input instead of reducing it.
+    ///
+    /// Returns `ArithError::ZeroModulus` if the modulus is zero and
+    /// `ArithError::NotCanonical` if the value is not below the modulus.
+    pub fn from_limbs(value: [u64; 4], modulus: [u64; 4]) -> Result<Self, ArithError> {
+      let (value, modulus) = (U256(value), U256(modulus));
+      if modulus == U256::zero() {
+        return Err(ArithError::ZeroModulus);
+      }
+      if value >= modulus {
+        return Err(ArithError::NotCanonical);
+      }
+      Self::try_new(value, modulus)
+    }
+
+    /// Moves the number to another modulus, e.g. to use a base-field element as a scalar.
+    ///
+    /// `ConvertPolicy::Reduce` reduces the value modulo the new modulus and
+    /// `ConvertPolicy::Strict` returns `NumModError::ValueOutOfRange` if the value is not
+    /// below it. Returns `NumModError::ZeroModulus` if the new modulus is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use modular_math::number_mod::{ConvertPolicy, NumModError, NumberUnderMod};
+    /// use primitive_types::U256;
+    ///
+    /// let num = NumberUnderMod::new(10, 13);
+    /// assert_eq!(num.convert_to(U256::from(7), ConvertPolicy::Reduce), Ok(NumberUnderMod::new(3, 7)));
+    /// assert_eq!(num.convert_to(U256::from(7), ConvertPolicy::Strict), Err(NumModError::ValueOutOfRange));
+    /// ```
+    pub fn convert_to(&self, new_modulus: U256, policy: ConvertPolicy) -> Result<NumberUnderMod, NumModError> {
+      if new_modulus == U256::zero() {
+        return Err(NumModError::ZeroModulus);
+      }
+      if policy == ConvertPolicy::Strict && self.value >= new_modulus {
+        return Err(NumModError::ValueOutOfRange);
+      }
+      Ok(NumberUnderMod::new(self.value, new_modulus))
+    }
+}
+
+/// How `NumberUnderMod::convert_to` handles a value that is not below the new modulus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConvertPolicy {
+    /// Reduce the value modulo the new modulus
+    Reduce,
+    /// Return `NumModError::ValueOutOfRange`
+    Strict,
+}
+
+/// Errors returned by the fallible `NumberUnderMod` operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NumModError {
+    /// The new modulus is zero
+    ZeroModulus,
+    /// The value is not below the new modulus
+    ValueOutOfRange,
+    /// The numbers are not under the same modulus
+    ModulusMismatch,
+    /// The divisor has no inverse under the modulus
+    NotInvertible,
 }
 
+impl std::fmt::Display for NumModError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NumModError::ZeroModulus => write!(f, "the modulus is zero"),
+            NumModError::ValueOutOfRange => write!(f, "the value is not below the modulus"),
+            NumModError::ModulusMismatch => write!(f, "the numbers are not under the same modulus"),
+            NumModError::NotInvertible => write!(f, "the divisor has no inverse under the modulus"),
+        }
+    }
+}
+
+impl std::error::Error for NumModError {}
+
 impl Add for NumberUnderMod {
-  type Output = Result<Self, &'static str>;
+  type Output = Result<Self, NumModError>;
 
   fn add(self, other: Self) -> Self::Output {
-      if self.modulus != other.modulus {
-          Err("Cannot add numbers with different moduli")
+      if self.modulus() != other.modulus() {
+          Err(NumModError::ModulusMismatch)
       } else {
-          let math = ModMath::new(self.modulus);
+          let math = &self.math;
           Ok(NumberUnderMod {
               value: math.add(self.value, other.value),
-              modulus: self.modulus,
+              math: self.math.clone(),
           })
       }
   }
 }
 
 impl Mul for NumberUnderMod {
-  type Output = Result<Self, &'static str>;
+  type Output = Result<Self, NumModError>;
 
   fn mul(self, other: Self) -> Self::Output {
-      if self.modulus != other.modulus {
-          Err("Cannot add numbers with different moduli")
+      if self.modulus() != other.modulus() {
+          Err(NumModError::ModulusMismatch)
       } else {
-          let math = ModMath::new(self.modulus);
+          let math = &self.math;
           Ok(NumberUnderMod {
               value: math.mul(self.value, other.value),
-              modulus: self.modulus,
+              math: self.math.clone(),
           })
       }
   }
 }
 
 impl Sub for NumberUnderMod {
-  type Output = Result<Self, &'static str>;
+  type Output = Result<Self, NumModError>;
 
   fn sub(self, other: Self) -> Self::Output {
-      if self.modulus != other.modulus {
-          Err("Cannot add numbers with different moduli")
+      if self.modulus() != other.modulus() {
+          Err(NumModError::ModulusMismatch)
       } else {
-          let math = ModMath::new(self.modulus);
+          let math = &self.math;
           Ok(NumberUnderMod {
               value: math.sub(self.value, other.value),
-              modulus: self.modulus,
+              math: self.math.clone(),
           })
       }
   }
 }
 
 impl Div for NumberUnderMod {
-  type Output = Result<Self, &'static str>;
+  type Output = Result<Self, NumModError>;
 
   fn div(self, other: Self) -> Self::Output {
-      if self.modulus != other.modulus {
-          Err("Cannot add numbers with different moduli")
+      if self.modulus() != other.modulus() {
+          Err(NumModError::ModulusMismatch)
       } else {
-          let math = ModMath::new(self.modulus);
+          let math = &self.math;
           Ok(NumberUnderMod {
               value: math.div(self.value, other.value),
-              modulus: self.modulus,
+              math: self.math.clone(),
           })
       }
   }
 }
 
+/// Divides by an integer under the modulus, by multiplying with its inverse.
+///
+/// # Examples
+///
+/// ```
+/// use modular_math::number_mod::{NumModError, NumberUnderMod};
+///
+/// assert_eq!(NumberUnderMod::new(6, 7) / 3, Ok(NumberUnderMod::new(2, 7)));
+/// assert_eq!(NumberUnderMod::new(6, 8) / 2, Err(NumModError::NotInvertible));
+/// ```
+impl<T: IntoU256> Div<T> for NumberUnderMod {
+  type Output = Result<Self, NumModError>;
+
+  fn div(self, divisor: T) -> Self::Output {
+      let math = &self.math;
+      let divisor_inv = math.inv(divisor).ok_or(NumModError::NotInvertible)?;
+      Ok(NumberUnderMod {
+          value: math.mul(self.value, divisor_inv),
+          math: self.math.clone(),
+      })
+  }
+}
+
 impl Neg for NumberUnderMod {
-  type Output = Result<Self, &'static str>;
+  type Output = Result<Self, NumModError>;
 
   fn neg(self) -> Self::Output {
-    let math = ModMath::new(self.modulus);
     Ok(NumberUnderMod {
-      value: math.add_inv(self.value),
-      modulus: self.modulus,
+      value: self.math.add_inv(self.value),
+      math: self.math,
   })
   }
 }
 
+impl FromU256 for NumberUnderMod {
+    fn from_u256(value: U256, math: &ModMath) -> Self {
+        NumberUnderMod::with_math(value, math)
+    }
+}
+
+impl std::fmt::Debug for NumberUnderMod {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("NumberUnderMod").field("value", &self.value).field("modulus", &self.math.modulus).finish()
+  }
+}
+
 impl PartialEq for NumberUnderMod {
   fn eq(&self, other: &NumberUnderMod) -> bool {
-    self.value == other.value && self.modulus == other.modulus
+    self.value == other.value && self.modulus() == other.modulus()
   }
 }
 
@@ -130,15 +448,91 @@ impl PartialEq for NumberUnderMod {
 /// * $value - The value of the number.
 /// * $modulus - The modulus under which the number is considered. 
 /// # Examples 
-/// let num = num_mod!(10, 6); 
-/// assert_eq!(num.value(), 4); 
+/// ```
+/// use modular_math::num_mod;
+/// use primitive_types::U256;
+///
+/// let num = num_mod!(10, 6);
+/// assert_eq!(num.value(), U256::from(4));
+/// ```
 /// This example creates a new NumberUnderMod with a value of 10 under modulus 6. 
 /// The value is automatically reduced modulo 6, so the resulting NumberUnderMod has a value of 4.
-
 #[macro_export]
 macro_rules! num_mod {
     ($value:expr, $modulus:expr) => {
-        NumberUnderMod::new($value, $modulus)
+        $crate::number_mod::NumberUnderMod::new($value, $modulus)
     };
 }
 
+/// field is a convenience macro for evaluating an expression in a prime field.
+///
+/// The block is a list of `let` statements followed by an expression. Every bound value
+/// is an element modulo the modulus, so the arithmetic in the block happens in the field,
+/// and the block evaluates to a `NumberUnderMod`.
+///
+/// # Expansion
+///
+/// `field!(101, { let a = 5; let b = 7; a * b + a })` expands to
+///
+/// ```text
+/// {
+///     let modulus = IntoU256::into_u256(101);
+///     let a = FieldExpr::new(5, modulus);
+///     let b = FieldExpr::new(7, modulus);
+///     NumberUnderMod::from(a * b + a)
+/// }
+/// ```
+///
+/// The modulus is evaluated once. The bound values may be any expression with an
+/// `IntoU256` type, such as the `value()` of another `field!` block; nested blocks each
+/// keep their own modulus. See `FieldExpr` for how the operators behave.
+///
+/// # Examples
+///
+/// ```
+/// use modular_math::{field, num_mod};
+///
+/// let result = field!(101, { let a = 5; let b = 7; a * b + a });
+/// assert_eq!(result, num_mod!(40, 101));
+///
+/// // integer operands are elements of the field too
+/// let halved = field!(101, { let a = 5; a / 2 });
+/// assert_eq!(halved, num_mod!(53, 101));
+/// ```
+#[macro_export]
+macro_rules! field {
+    ($modulus:expr, { $(let $name:ident = $value:expr;)* $result:expr }) => {{
+        let modulus = $crate::mod_math::IntoU256::into_u256($modulus);
+        $(let $name = $crate::number_mod::FieldExpr::new($value, modulus);)*
+        $crate::number_mod::NumberUnderMod::from($result)
+    }};
+}
+
+/// with_modulus is a convenience macro for creating several NumberUnderMod instances
+/// under the same modulus, as a tuple.
+///
+/// The numbers share one `ModMath` for the modulus: `with_modulus!(101; 5, 7)` expands to
+/// `{ let math = ModMath::new(IntoU256::into_u256(101)); (NumberUnderMod::from_u256(IntoU256::into_u256(5), &math), NumberUnderMod::from_u256(IntoU256::into_u256(7), &math)) }`.
+///
+/// # Panics
+///
+/// Panics if the modulus is zero.
+///
+/// # Examples
+///
+/// ```
+/// use modular_math::{num_mod, with_modulus};
+///
+/// let (a, b, c) = with_modulus!(101; 5, 7, 120);
+/// assert_eq!((a, b, c), (num_mod!(5, 101), num_mod!(7, 101), num_mod!(19, 101)));
+/// ```
+#[macro_export]
+macro_rules! with_modulus {
+    ($modulus:expr; $($value:expr),+ $(,)?) => {{
+        let math = $crate::mod_math::ModMath::new($crate::mod_math::IntoU256::into_u256($modulus));
+        ($(<$crate::number_mod::NumberUnderMod as $crate::mod_math::FromU256>::from_u256(
+            $crate::mod_math::IntoU256::into_u256($value),
+            &math,
+        ),)+)
+    }};
+}
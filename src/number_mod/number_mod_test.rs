@@ -1,8 +1,10 @@
 #[cfg(test)]
 mod tests {
-    use crate::number_mod::{NumberUnderMod};
+    use crate::number_mod::{ConvertPolicy, NumModError, NumberUnderMod};
+    use crate::curves::BN128;
+    use crate::mod_math::ArithError;
     use primitive_types::U256;
-    use crate::num_mod;
+    use crate::{field, num_mod, with_modulus};
 
     #[test]
     fn test_addition() {
@@ -12,6 +14,15 @@ mod tests {
         assert_eq!(result.unwrap(), NumberUnderMod::new(3, 13));
     }
 
+    #[test]
+    fn test_from_signed() {
+        assert_eq!(NumberUnderMod::from_signed(-3, 13), NumberUnderMod::new(10, 13));
+        assert_eq!(NumberUnderMod::from_signed(-29, 13), NumberUnderMod::new(10, 13));
+        assert_eq!(NumberUnderMod::from_signed(-26, 13).value(), U256::zero());
+        assert_eq!(NumberUnderMod::from_signed(16, 13), NumberUnderMod::new(3, 13));
+        assert_eq!(NumberUnderMod::from_signed(i64::MIN, 13).value(), U256::from(13 - (1u64 << 63) % 13));
+    }
+
     #[test]
     fn test_subtraction() {
         let num1 = NumberUnderMod::new(10, 13);
@@ -36,6 +47,19 @@ mod tests {
         assert_eq!(result.unwrap(), num_mod!(51, 101));
     }
 
+    #[test]
+    fn test_division_by_integer() {
+        assert_eq!(NumberUnderMod::new(6, 7) / 3, Ok(NumberUnderMod::new(2, 7)));
+        assert_eq!(NumberUnderMod::new(6, 7) / U256::from(3), Ok(NumberUnderMod::new(2, 7)));
+        assert_eq!(NumberUnderMod::new(6, 7) / 10u64, Ok(NumberUnderMod::new(2, 7)));
+        // agrees with dividing by a number under the same modulus
+        let quotient = NumberUnderMod::new(10, 101) / 20;
+        assert_eq!(quotient, NumberUnderMod::new(10, 101) / NumberUnderMod::new(20, 101));
+        // a divisor without an inverse is an error, not a panic
+        assert_eq!(NumberUnderMod::new(6, 7) / 14, Err(NumModError::NotInvertible));
+        assert_eq!(NumberUnderMod::new(3, 12) / 4u64, Err(NumModError::NotInvertible));
+    }
+
     #[test]
     fn test_negation() {
         let num = NumberUnderMod::new(10, 13);
@@ -43,6 +67,88 @@ mod tests {
         assert_eq!(result.unwrap(), num_mod!(3, 13));
     }
 
+    #[test]
+    fn test_accessors() {
+        let num = NumberUnderMod::new(20, 13);
+        assert_eq!(num.value(), U256::from(7));
+        assert_eq!(num.modulus(), U256::from(13));
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(NumberUnderMod::try_new(20, 13), Ok(NumberUnderMod::new(7, 13)));
+        assert_eq!(NumberUnderMod::try_new(5, 0), Err(ArithError::ZeroModulus));
+    }
+
+    #[test]
+    fn test_from_slice() {
+        let numbers = NumberUnderMod::from_slice(&[0u64, 12, 13, 27, u64::MAX], 13);
+        let values: Vec<U256> = numbers.iter().map(|n| n.value()).collect();
+        assert_eq!(values, vec![U256::zero(), U256::from(12), U256::zero(), U256::one(), U256::from(u64::MAX % 13)]);
+        assert!(numbers.iter().all(|n| n.modulus() == U256::from(13)));
+        assert!(NumberUnderMod::from_slice(&[] as &[u64], 13).is_empty());
+    }
+
+    #[test]
+    fn test_pow_signed() {
+        for value in 1..101 {
+            let a = NumberUnderMod::new(value, 101);
+            assert_eq!(a.pow_signed(-1), a.inverse());
+            assert_eq!(a.pow_signed(-3), a.inverse().unwrap().pow_signed(3));
+            assert_eq!(a.pow_signed(0), Some(NumberUnderMod::new(1, 101)));
+        }
+
+        let a = NumberUnderMod::new(6, 15);
+        assert_eq!(a.inverse(), None);
+        assert_eq!(a.pow_signed(-1), None);
+        assert_eq!(a.pow_signed(2), Some(NumberUnderMod::new(6, 15)));
+
+        // exponents beyond i64 go through the full i128 range
+        let a = NumberUnderMod::new(3, 101);
+        assert_eq!(a.pow_signed(i128::MAX), Some(NumberUnderMod::new(3, 101).pow_signed(i128::MAX % 100).unwrap()));
+        assert_eq!(a.pow_signed(i128::MIN), a.inverse().unwrap().pow_signed(i128::MAX % 100 + 1));
+    }
+
+    #[test]
+    fn test_string_helpers() {
+        let num = NumberUnderMod::new(300, 257);
+        assert_eq!(num.to_dec_string(), "43");
+        assert_eq!(num.to_hex_string(false), "0x2b");
+        assert_eq!(num.to_hex_string(true), format!("0x{}2b", "0".repeat(62)));
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let num = NumberUnderMod::new(U256::max_value() - U256::from(7), U256::max_value() - U256::from(4));
+        let bytes = num.to_bytes();
+        assert_eq!(NumberUnderMod::from_bytes(&bytes), Ok(num));
+
+        let mut expected = [0u8; 64];
+        expected[31] = 3;
+        expected[63] = 7;
+        assert_eq!(NumberUnderMod::new(10, 7).to_bytes(), expected);
+
+        assert_eq!(NumberUnderMod::from_bytes(&bytes[..63]), Err(ArithError::InvalidLength));
+        assert_eq!(NumberUnderMod::from_bytes(&[0u8; 64]), Err(ArithError::ZeroModulus));
+    }
+
+    #[test]
+    fn test_limbs_round_trip() {
+        let modulus = BN128().field_modulus;
+        let num = NumberUnderMod::new(modulus - U256::from(2), modulus);
+        let (value, modulus_limbs) = num.to_limbs();
+        assert_eq!(modulus_limbs, [0x3c208c16d87cfd47, 0x97816a916871ca8d, 0xb85045b68181585d, 0x30644e72e131a029]);
+        assert_eq!(value[0], 0x3c208c16d87cfd45);
+        assert_eq!(NumberUnderMod::from_limbs(value, modulus_limbs), Ok(num.clone()));
+        // the limbs agree with the byte encoding
+        let bytes = num.to_bytes();
+        assert_eq!(U256(value), U256::from_big_endian(&bytes[..32]));
+
+        assert_eq!(NumberUnderMod::from_limbs([1, 0, 0, 0], [0; 4]), Err(ArithError::ZeroModulus));
+        assert_eq!(NumberUnderMod::from_limbs(modulus_limbs, modulus_limbs), Err(ArithError::NotCanonical));
+        assert_eq!(NumberUnderMod::from_limbs([0, 0, 0, 1], [0, 0, 0, 2]).map(|n| n.value()), Ok(U256::one() << 192));
+    }
+
     #[test]
     fn test_equality() {
         let num1 = NumberUnderMod::new(10, 13);
@@ -56,4 +162,59 @@ mod tests {
         let num2 = NumberUnderMod::new(6, 13);
         assert_ne!(num1, num2);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_convert_to() {
+        let bn128 = BN128();
+        let (p, r) = (bn128.field_modulus, bn128.curve_order);
+        let largest = NumberUnderMod::new(p - U256::one(), p);
+        assert_eq!(largest.convert_to(r, ConvertPolicy::Reduce), Ok(NumberUnderMod::new((p - U256::one()) % r, r)));
+        assert_eq!(largest.convert_to(r, ConvertPolicy::Strict), Err(NumModError::ValueOutOfRange));
+
+        let small = NumberUnderMod::new(U256::from(12345), p);
+        let reduced = small.convert_to(r, ConvertPolicy::Reduce);
+        assert_eq!(reduced, Ok(NumberUnderMod::new(U256::from(12345), r)));
+        assert_eq!(small.convert_to(r, ConvertPolicy::Strict), reduced);
+
+        assert_eq!(small.convert_to(U256::zero(), ConvertPolicy::Reduce), Err(NumModError::ZeroModulus));
+    }
+
+    #[test]
+    fn test_field_block() {
+        let m = 101;
+        assert_eq!(field!(m, { let a = 5; let b = 7; a * b + a }), ((num_mod!(5, m) * num_mod!(7, m)).unwrap() + num_mod!(5, m)).unwrap());
+        assert_eq!(field!(m, { let a = 5; let b = 7; a - b }), (num_mod!(5, m) - num_mod!(7, m)).unwrap());
+        assert_eq!(field!(m, { let a = 5; let b = 7; a / b }), (num_mod!(5, m) / num_mod!(7, m)).unwrap());
+        assert_eq!(field!(m, { let a = 5; -a * 3 + 1 }), num_mod!(87, m));
+        assert_eq!(field!(U256::from(m), { let a = "99"; a * a }), num_mod!(4, 101));
+    }
+
+    #[test]
+    fn test_nested_field_blocks() {
+        let outer = field!(101, {
+            let a = field!(13, { let x = 5; x * x }).value();
+            let b = 100;
+            a * b
+        });
+        // 5 * 5 = 12 (mod 13), 12 * 100 = 89 (mod 101)
+        assert_eq!(outer, num_mod!(89, 101));
+        let modulus = 7;
+        assert_eq!(field!(13, { let a = modulus; a * 2 }), num_mod!(1, 13));
+    }
+
+    #[test]
+    fn test_with_modulus() {
+        let (a, b, c) = with_modulus!(101; 5, 7, U256::from(120));
+        assert_eq!(a, num_mod!(5, 101));
+        assert_eq!(b, num_mod!(7, 101));
+        assert_eq!(c, num_mod!(19, 101));
+        let (single,) = with_modulus!(13; 20);
+        assert_eq!(single, num_mod!(7, 13));
+
+        // one set of field parameters for the whole tuple
+        let built = || crate::mod_math::FIELD_PARAMS_BUILT.with(|count| count.get());
+        let before = built();
+        let _ = with_modulus!(101; 1, 2, 3, 4, 5, 6);
+        assert_eq!(built() - before, 1);
+    }
+}
@@ -0,0 +1,7 @@
+#[allow(clippy::module_inception)]
+mod random;
+mod random_test;
+
+pub use random::{OsRandom, RandomSource};
+#[cfg(feature = "rand_core_09")]
+pub use random::RandCore09;
@@ -0,0 +1,75 @@
+/// A source of random bytes, the one thing the sampling APIs of the crate need, such as
+/// `ModMath::random`, `Curve::random_scalar` and `ModMath::exp_blinded`.
+///
+/// Every `RngCore` of rand_core 0.6, which `rand` 0.8 re-exports, is a `RandomSource`,
+/// so `rand::thread_rng()` and the seedable generators work as they are. With the
+/// `rand_core_09` feature, wrap a rand_core 0.9 generator in `RandCore09`; a second
+/// blanket implementation would overlap the first. Any other generator can implement
+/// the trait directly.
+///
+/// # Examples
+///
+/// ```
+/// use modular_math::mod_math::ModMath;
+/// use modular_math::random::RandomSource;
+/// use primitive_types::U256;
+///
+/// // a counter, for reproducible tests only
+/// struct Counter(u8);
+///
+/// impl RandomSource for Counter {
+///     fn fill_bytes(&mut self, dest: &mut [u8]) {
+///         for byte in dest {
+///             self.0 = self.0.wrapping_add(1);
+///             *byte = self.0;
+///         }
+///     }
+/// }
+///
+/// let math = ModMath::new(1000003);
+/// // the first 20 bits of 01 02 03 ..., as the modulus has 20 bits
+/// assert_eq!(math.random(&mut Counter(0)), U256::from(0x01020));
+/// ```
+pub trait RandomSource {
+    /// Fills `dest` with random bytes.
+    fn fill_bytes(&mut self, dest: &mut [u8]);
+}
+
+impl<R: rand::RngCore + ?Sized> RandomSource for R {
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        rand::RngCore::fill_bytes(self, dest)
+    }
+}
+
+/// The random number generator of the operating system, e.g. `getrandom` on Linux.
+///
+/// # Examples
+///
+/// ```
+/// use modular_math::curves::BN128;
+/// use modular_math::random::OsRandom;
+///
+/// let bn128 = BN128();
+/// let k = bn128.random_scalar(&mut OsRandom);
+/// assert!(k < bn128.curve_order);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OsRandom;
+
+impl RandomSource for OsRandom {
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        rand::RngCore::fill_bytes(&mut rand::rngs::OsRng, dest)
+    }
+}
+
+/// A `RandomSource` from a rand_core 0.9 generator.
+#[cfg(feature = "rand_core_09")]
+#[derive(Debug, Clone)]
+pub struct RandCore09<R>(pub R);
+
+#[cfg(feature = "rand_core_09")]
+impl<R: rand_core_09::RngCore> RandomSource for RandCore09<R> {
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+}
@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod tests {
+    use primitive_types::U256;
+
+    use crate::curves::{Secp256k1, BN128};
+    use crate::galois_field::GaloisField;
+    use crate::group::{PrimeGroup, Schnorr};
+    use crate::mod_math::ModMath;
+    use crate::random::{OsRandom, RandomSource};
+
+    // SplitMix64, implemented against the trait alone
+    struct SplitMix(u64);
+
+    impl RandomSource for SplitMix {
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for chunk in dest.chunks_mut(8) {
+                self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+                let mut z = self.0;
+                z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+                z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+                z ^= z >> 31;
+                chunk.copy_from_slice(&z.to_le_bytes()[..chunk.len()]);
+            }
+        }
+    }
+
+    // 00 01 02 ... 255 00 01 ...
+    struct Counter(u8);
+
+    impl RandomSource for Counter {
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for byte in dest {
+                *byte = self.0;
+                self.0 = self.0.wrapping_add(1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mod_math_random() {
+        let math = ModMath::new(1000003);
+        // the top 20 bits of 00 01 02 ... are 0x00010, which is below the modulus
+        assert_eq!(math.random(&mut Counter(0)), U256::from(0x10));
+
+        let bn128 = BN128();
+        let scalars = ModMath::new(bn128.curve_order);
+        let (mut a, mut b) = (SplitMix(980), SplitMix(980));
+        for _ in 0..100 {
+            let element = scalars.random(&mut a);
+            assert!(element < bn128.curve_order);
+            assert_eq!(scalars.random(&mut b), element);
+        }
+        // the sources are used through a trait object as well
+        let source: &mut dyn RandomSource = &mut SplitMix(980);
+        assert_eq!(scalars.random(source), scalars.random(&mut SplitMix(980)));
+    }
+
+    #[test]
+    fn test_keys_and_blinding() {
+        let secp256k1 = Secp256k1();
+        let mut rng = SplitMix(980);
+        let secret = secp256k1.random_scalar(&mut rng);
+        assert!(secret > U256::zero() && secret < secp256k1.curve_order);
+        assert_eq!(PrimeGroup::random_scalar(&secp256k1, &mut SplitMix(980)), secret);
+
+        let schnorr = Schnorr::new(&secp256k1);
+        let public = schnorr.public_key(&secret);
+        let (nonce, commitment) = schnorr.commit(&mut rng);
+        let challenge = secp256k1.random_scalar(&mut rng);
+        let response = schnorr.respond(&secret, &nonce, &challenge);
+        assert!(schnorr.verify(&public, &commitment, &challenge, &response));
+
+        let math = ModMath::new(secp256k1.field_modulus);
+        let exponent = secp256k1.random_scalar(&mut rng);
+        let blinded = math.exp_blinded(3, exponent, secp256k1.field_modulus - 1, &mut rng);
+        assert_eq!(blinded, math.exp(U256::from(3), exponent));
+    }
+
+    #[test]
+    fn test_galois_field_random() {
+        let gf = GaloisField::new(4294967311u64).unwrap();
+        let mut rng = SplitMix(980);
+        for _ in 0..100 {
+            assert!(gf.random(&mut rng).value() < U256::from(4294967311u64));
+            assert_ne!(gf.random_nonzero(&mut rng).value(), U256::zero());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Modulus must be greater than 1")]
+    fn test_random_nonzero_modulus_one() {
+        ModMath::new(1).random_nonzero(&mut SplitMix(980));
+    }
+
+    #[test]
+    fn test_os_random() {
+        let (mut first, mut second) = ([0u8; 32], [0u8; 32]);
+        OsRandom.fill_bytes(&mut first);
+        OsRandom.fill_bytes(&mut second);
+        assert_ne!(first, second);
+        assert!(BN128().random_scalar(&mut OsRandom) < BN128().curve_order);
+    }
+
+    #[cfg(feature = "rand_core_09")]
+    #[test]
+    fn test_rand_core_09() {
+        use crate::random::RandCore09;
+
+        // the same counter as above, against the rand_core 0.9 traits
+        struct Counter09(u8);
+
+        impl rand_core_09::RngCore for Counter09 {
+            fn next_u32(&mut self) -> u32 {
+                rand_core_09::impls::next_u32_via_fill(self)
+            }
+
+            fn next_u64(&mut self) -> u64 {
+                rand_core_09::impls::next_u64_via_fill(self)
+            }
+
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                Counter(self.0).fill_bytes(dest);
+                self.0 = self.0.wrapping_add(dest.len() as u8);
+            }
+        }
+
+        let math = ModMath::new(BN128().curve_order);
+        let mut rng = RandCore09(Counter09(7));
+        let mut reference = Counter(7);
+        for _ in 0..10 {
+            assert_eq!(math.random(&mut rng), math.random(&mut reference));
+        }
+    }
+}
@@ -0,0 +1,150 @@
+//! A quick check of the arithmetic, meant to be run once at startup.
+//!
+//! `ModMath` picks its reduction of wide products from the modulus: the special-form
+//! reduction for moduli like the secp256k1 prime, Barrett reduction for the BN128 prime,
+//! and long division for others such as the 161-bit prime `2^160 + 7`. `self_test` runs
+//! known answers through all three, after checking the field axioms over a small field.
+
+use primitive_types::U256;
+
+use crate::curves::{ECPoint, BN128};
+use crate::mod_math::ModMath;
+
+/// The property that failed in `self_test`.
+///
+/// # Examples
+///
+/// ```
+/// let result = modular_math::self_test();
+/// if let Err(e) = &result {
+///     eprintln!("{}", e);
+/// }
+/// assert!(result.is_ok());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelfTestError {
+    property: &'static str,
+}
+
+impl SelfTestError {
+    /// Returns the name of the first property that did not hold, e.g. `"mul_inverse"`
+    /// or `"secp256k1_mul"`.
+    pub fn property(&self) -> &'static str {
+        self.property
+    }
+}
+
+impl std::fmt::Display for SelfTestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "arithmetic self-test failed: {}", self.property)
+    }
+}
+
+impl std::error::Error for SelfTestError {}
+
+/// Checks that the arithmetic gives correct results on this platform, returning the
+/// first property that fails.
+///
+/// In order, it checks:
+/// - the field axioms over GF(251): identities and inverses for every element, and
+///   commutativity, associativity and distributivity for every element paired with
+///   every fourth element (and a third element derived from the pair),
+/// - `mul`, `exp` and `inv` against known answers under the secp256k1 and BN128 primes
+///   and `2^160 + 7`, which use the three reductions,
+/// - a BN128 scalar multiplication against a known answer.
+///
+/// It takes a few tens of milliseconds in a release build.
+pub fn self_test() -> Result<(), SelfTestError> {
+    check_small_field()?;
+    check_known_answers()?;
+    check_scalar_multiplication()
+}
+
+fn check(property: &'static str, holds: bool) -> Result<(), SelfTestError> {
+    if holds {
+        Ok(())
+    } else {
+        Err(SelfTestError { property })
+    }
+}
+
+fn check_small_field() -> Result<(), SelfTestError> {
+    let math = ModMath::new(251);
+    for a in 0..251u32 {
+        check("add_identity", math.add(a, 0) == U256::from(a))?;
+        check("mul_identity", math.mul(a, 1) == U256::from(a))?;
+        check("add_inverse", math.add(a, math.add_inv(a)) == U256::zero())?;
+        if a != 0 {
+            let inverse = math.inv(a).unwrap_or_default();
+            check("mul_inverse", math.mul(a, inverse) == U256::one())?;
+        }
+    }
+    for a in 0..251u32 {
+        for b in (0..251u32).step_by(4) {
+            let c = (a * 7 + b * 13 + 1) % 251;
+            check("add_commutative", math.add(a, b) == math.add(b, a))?;
+            check("mul_commutative", math.mul(a, b) == math.mul(b, a))?;
+            check("add_associative", math.add(math.add(a, b), c) == math.add(a, math.add(b, c)))?;
+            check("mul_associative", math.mul(math.mul(a, b), c) == math.mul(a, math.mul(b, c)))?;
+            check("distributive", math.mul(a, math.add(b, c)) == math.add(math.mul(a, b), math.mul(a, c)))?;
+        }
+    }
+    Ok(())
+}
+
+// The modulus, then `a * b`, `a^b` and `a^-1` for a = G.x and b = G.y of secp256k1
+const KNOWN_ANSWERS: [(&str, [(&str, &str); 3]); 3] = [
+    (
+        "115792089237316195423570985008687907853269984665640564039457584007908834671663",
+        [
+            ("secp256k1_mul", "114544289132854671785371450145272078301207510924172161292488302719104112524699"),
+            ("secp256k1_exp", "38437386725028443365121050985088969697693988477027461057594429617438335659407"),
+            ("secp256k1_inv", "16048257703666452242803569546805946138055448571451565585555302070354637922038"),
+        ],
+    ),
+    (
+        "21888242871839275222246405745257275088696311157297823662689037894645226208583",
+        [
+            ("bn128_mul", "9381399062154369865475542430556052707572283263355747044581317016252614831533"),
+            ("bn128_exp", "21100790426012568954302556555858700048137377189592849619794621502602076579146"),
+            ("bn128_inv", "14755992743735204212483714459704058186603724587998100537805959875656408664933"),
+        ],
+    ),
+    (
+        "1461501637330902918203684832716283019655932542983",
+        [
+            ("generic_mul", "778653315259031999249279429486442584024863183767"),
+            ("generic_exp", "828526506105182395157074400695549602361432129717"),
+            ("generic_inv", "922605829560134506777235403910180572253599933725"),
+        ],
+    ),
+];
+
+// The constants are valid decimal, so parsing cannot fail
+fn number(s: &str) -> U256 {
+    U256::from_dec_str(s).unwrap_or_default()
+}
+
+fn check_known_answers() -> Result<(), SelfTestError> {
+    let a = number("55066263022277343669578718895168534326250603453777594175500187360389116729240");
+    let b = number("32670510020758816978083085130507043184471273380659243275938904335757337482424");
+
+    for (modulus, answers) in KNOWN_ANSWERS {
+        let math = ModMath::new(number(modulus));
+        let results = [math.mul(a, b), math.exp(a, b), math.inv(a).unwrap_or_default()];
+        for ((property, expected), result) in answers.into_iter().zip(results) {
+            check(property, result == number(expected))?;
+        }
+    }
+    Ok(())
+}
+
+fn check_scalar_multiplication() -> Result<(), SelfTestError> {
+    let bn128 = BN128();
+    let k = number("1311768467294899695");
+    let expected = ECPoint::new(
+        number("7937519569820912844113330374444454164310055281456073671403549050007087076600"),
+        number("12804341335595495802034722349306118345336669894914691938658439299591460059117"),
+    );
+    check("bn128_scalar_mul", bn128.scalar_multiply_generator(k) == expected)
+}
@@ -0,0 +1,23 @@
+#[cfg(test)]
+mod tests {
+    use crate::mod_math::BROKEN_REDUCTION;
+    use crate::{self_test, SelfTestError};
+
+    #[test]
+    fn test_self_test_passes() {
+        assert_eq!(self_test(), Ok(()));
+    }
+
+    #[test]
+    fn test_self_test_catches_broken_reduction() {
+        BROKEN_REDUCTION.with(|broken| broken.set(true));
+        let result = self_test();
+        BROKEN_REDUCTION.with(|broken| broken.set(false));
+
+        // GF(251) never needs a wide reduction, the first 256-bit product does
+        let error: SelfTestError = result.unwrap_err();
+        assert_eq!(error.property(), "secp256k1_mul");
+        assert_eq!(error.to_string(), "arithmetic self-test failed: secp256k1_mul");
+        assert_eq!(self_test(), Ok(()));
+    }
+}
@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+mod util;
+mod util_test;
+
+pub use util::{bit_length, is_power_of_two, log2_floor, trailing_zeros};
+pub(crate) use util::fingerprint;
@@ -0,0 +1,95 @@
+use primitive_types::U256;
+
+/// Returns the number of bits needed to write `a`, 0 for zero.
+///
+/// # Examples
+///
+/// ```
+/// use modular_math::util::bit_length;
+/// use primitive_types::U256;
+///
+/// assert_eq!(bit_length(U256::zero()), 0);
+/// assert_eq!(bit_length(U256::from(255)), 8);
+/// assert_eq!(bit_length(U256::from(256)), 9);
+/// ```
+pub fn bit_length(a: U256) -> usize {
+    a.bits()
+}
+
+/// Returns the largest `k` with `2^k <= a`, or `None` for zero.
+///
+/// # Examples
+///
+/// ```
+/// use modular_math::util::log2_floor;
+/// use primitive_types::U256;
+///
+/// assert_eq!(log2_floor(U256::zero()), None);
+/// assert_eq!(log2_floor(U256::one()), Some(0));
+/// assert_eq!(log2_floor(U256::from(1000)), Some(9));
+/// ```
+pub fn log2_floor(a: U256) -> Option<usize> {
+    a.bits().checked_sub(1)
+}
+
+/// Checks if `a` is a power of two. Zero is not.
+///
+/// # Examples
+///
+/// ```
+/// use modular_math::util::is_power_of_two;
+/// use primitive_types::U256;
+///
+/// assert!(is_power_of_two(U256::from(64)));
+/// assert!(!is_power_of_two(U256::from(65)));
+/// assert!(!is_power_of_two(U256::zero()));
+/// ```
+pub fn is_power_of_two(a: U256) -> bool {
+    !a.is_zero() && (a & (a - U256::one())).is_zero()
+}
+
+/// Returns the number of trailing zero bits of `a`, 256 for zero.
+///
+/// # Examples
+///
+/// ```
+/// use modular_math::util::trailing_zeros;
+/// use primitive_types::U256;
+///
+/// assert_eq!(trailing_zeros(U256::from(40)), 3);
+/// assert_eq!(trailing_zeros(U256::zero()), 256);
+/// ```
+pub fn trailing_zeros(a: U256) -> usize {
+    a.trailing_zeros() as usize
+}
+
+// Mixes a domain tag and a list of parameters into 32 bytes, for the `fingerprint`
+// methods. The parameters are serialized as 32-byte big-endian words after the tag and its
+// length, then hashed by four FNV-1a lanes with different offsets, each finished with the
+// SplitMix64 finalizer. This is stable across platforms and changes with every parameter
+// bit, but it is not a cryptographic hash: collisions can be found on purpose.
+pub(crate) fn fingerprint(tag: &str, parameters: &[U256]) -> [u8; 32] {
+    const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+    const FNV_PRIME: u64 = 0x100000001b3;
+
+    let mut bytes = (tag.len() as u64).to_be_bytes().to_vec();
+    bytes.extend_from_slice(tag.as_bytes());
+    for parameter in parameters {
+        let mut word = [0u8; 32];
+        parameter.to_big_endian(&mut word);
+        bytes.extend_from_slice(&word);
+    }
+
+    let mut out = [0u8; 32];
+    for (lane, chunk) in out.chunks_exact_mut(8).enumerate() {
+        let mut h = FNV_OFFSET ^ (lane as u64).wrapping_mul(0x9e3779b97f4a7c15);
+        for &byte in &bytes {
+            h = (h ^ byte as u64).wrapping_mul(FNV_PRIME);
+        }
+        h = (h ^ (h >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        h = (h ^ (h >> 27)).wrapping_mul(0x94d049bb133111eb);
+        h ^= h >> 31;
+        chunk.copy_from_slice(&h.to_be_bytes());
+    }
+    out
+}
@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod tests {
+    use primitive_types::U256;
+
+    use crate::util::{bit_length, is_power_of_two, log2_floor, trailing_zeros};
+
+    #[test]
+    fn test_zero_and_one() {
+        assert_eq!(bit_length(U256::zero()), 0);
+        assert_eq!(log2_floor(U256::zero()), None);
+        assert!(!is_power_of_two(U256::zero()));
+        assert_eq!(trailing_zeros(U256::zero()), 256);
+
+        assert_eq!(bit_length(U256::one()), 1);
+        assert_eq!(log2_floor(U256::one()), Some(0));
+        assert!(is_power_of_two(U256::one()));
+        assert_eq!(trailing_zeros(U256::one()), 0);
+    }
+
+    #[test]
+    fn test_powers_of_two() {
+        for k in 1..256 {
+            let power = U256::one() << k;
+            assert_eq!(bit_length(power), k + 1, "2^{}", k);
+            assert_eq!(log2_floor(power), Some(k), "2^{}", k);
+            assert!(is_power_of_two(power), "2^{}", k);
+            assert_eq!(trailing_zeros(power), k, "2^{}", k);
+
+            let below = power - U256::one();
+            assert_eq!(bit_length(below), k, "2^{} - 1", k);
+            assert_eq!(log2_floor(below), Some(k - 1), "2^{} - 1", k);
+            assert_eq!(is_power_of_two(below), k == 1, "2^{} - 1", k);
+            assert_eq!(trailing_zeros(below), 0, "2^{} - 1", k);
+        }
+    }
+
+    #[test]
+    fn test_max() {
+        assert_eq!(bit_length(U256::MAX), 256);
+        assert_eq!(log2_floor(U256::MAX), Some(255));
+        assert!(!is_power_of_two(U256::MAX));
+        assert_eq!(trailing_zeros(U256::MAX), 0);
+        assert_eq!(trailing_zeros(U256::MAX - U256::one()), 1);
+    }
+}